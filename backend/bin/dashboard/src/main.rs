@@ -1,13 +1,40 @@
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::Result;
-use axum::{routing::get, Router};
+use axum::{
+    extract::{Path, Query},
+    http::header,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use status_alerts::{AlertEngine, FiringAlert};
 use status_bridge::{
-    bridge_monitoring_task, get_bridge_status, run_withdrawal_indexer, BridgeMonitoringContext,
-    BridgeStatusDbSled, WithdrawalIndexerDbSled,
+    bridge_monitoring_task, encode_bridge_metrics, get_archived_deposits,
+    get_archived_reimbursements, get_archived_withdrawals, get_bridge_badge, get_bridge_deposit,
+    get_bridge_status, get_bridge_tx, get_operator_badge, run_withdrawal_indexer, ArchiveQuery,
+    BridgeMonitoringContext, BridgeStatusDbSled, WithdrawalIndexerDbSled,
+};
+use status_config::{Config, MaintenanceWindowConfig};
+use status_feed::{get_atom_feed, get_rss_feed, StatusEventDbSled, StatusEventLog, StatusFeed};
+use status_incidents::{
+    create_incident, get_incidents, resolve_incident, update_incident, IncidentDbSled, IncidentLog,
+    IncidentUpdateRequest, NewIncident, ResolveIncident,
+};
+use status_maintenance::{
+    cancel_maintenance, get_maintenance, schedule_maintenance, MaintenanceDbSled,
+    MaintenanceSchedule,
+};
+use status_network::{
+    encode_network_metrics, get_network_metrics, get_network_status, get_network_status_history,
+    get_service_badge, network_monitoring_task, HistoryQuery, NetworkHistoryDbSled,
+    NetworkMonitoringContext,
+};
+use status_statuspage::{get_statuspage_status, get_statuspage_summary, StatusPage};
+use status_utils::{
+    require_admin, EsploraClient, MetricKind, PrometheusEncoder, PROMETHEUS_CONTENT_TYPE,
 };
-use status_config::Config;
-use status_network::{get_network_status, network_monitoring_task, NetworkMonitoringContext};
 use strata_tasks::TaskManager;
 use tokio::{net::TcpListener, runtime};
 use tower_http::cors::{Any, CorsLayer};
@@ -15,6 +42,44 @@ use tracing::info;
 
 const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
 
+/// Handler to get the alerts currently firing
+async fn get_alerts(alert_engine: Arc<AlertEngine>) -> Json<Vec<FiringAlert>> {
+    Json(alert_engine.firing().await)
+}
+
+/// Handler to get network and bridge monitoring metrics in Prometheus text
+/// format
+async fn get_metrics(
+    network_context: Arc<NetworkMonitoringContext>,
+    bridge_context: Arc<BridgeMonitoringContext>,
+) -> impl IntoResponse {
+    let mut encoder = PrometheusEncoder::new();
+    encode_network_metrics(&network_context, &mut encoder).await;
+    encode_bridge_metrics(&bridge_context, &mut encoder).await;
+
+    // Both monitors own an Esplora client, so the family is written here once.
+    encoder.family(
+        "status_esplora_errors_total",
+        MetricKind::Counter,
+        "Number of failed Esplora requests.",
+    );
+    encoder.sample(
+        "status_esplora_errors_total",
+        &[("monitor", "network")],
+        network_context.esplora_error_count() as f64,
+    );
+    encoder.sample(
+        "status_esplora_errors_total",
+        &[("monitor", "bridge")],
+        bridge_context.esplora_error_count() as f64,
+    );
+
+    (
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        encoder.finish(),
+    )
+}
+
 fn main() -> Result<()> {
     tracing_subscriber::fmt::fmt()
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
@@ -33,13 +98,101 @@ fn main() -> Result<()> {
 
     let withdrawal_index_db = Arc::new(WithdrawalIndexerDbSled::open(config.datadir())?);
     let bridge_status_db = Arc::new(BridgeStatusDbSled::open(config.datadir())?);
-    let network_context = Arc::new(NetworkMonitoringContext::new(config.network().clone()));
+    let network_history_db = Arc::new(NetworkHistoryDbSled::open(config.datadir())?);
+    let maintenance = Arc::new(MaintenanceSchedule::load(
+        config.maintenance(),
+        config.components(),
+        MaintenanceDbSled::open(config.datadir())?,
+    )?);
+    let incidents = Arc::new(IncidentLog::new(
+        IncidentDbSled::open(config.datadir())?,
+        config.components(),
+    ));
+    let status_events = Arc::new(StatusEventLog::load(StatusEventDbSled::open(
+        config.datadir(),
+    )?)?);
+    let feed = Arc::new(StatusFeed::new(
+        config.feed().clone(),
+        Arc::clone(&status_events),
+        Arc::clone(&incidents),
+    ));
+    let (alert_engine, alert_dispatcher) =
+        AlertEngine::new(config.alerts().clone(), Arc::clone(&maintenance));
+    let alert_engine = Arc::new(alert_engine);
+    let network_context = Arc::new(NetworkMonitoringContext::new(
+        config.network().clone(),
+        config.network_probes(),
+        EsploraClient::new(
+            config.bridge().esplora_url(),
+            config.bridge().esplora_request_timeout_s(),
+        ),
+        network_history_db,
+        Arc::clone(&alert_engine),
+        Arc::clone(&maintenance),
+        Arc::clone(&status_events),
+    ));
     let bridge_context = Arc::new(BridgeMonitoringContext::new(
         config.bridge().clone(),
         Arc::clone(&withdrawal_index_db),
         Arc::clone(&bridge_status_db),
+        Arc::clone(&alert_engine),
+        Arc::clone(&maintenance),
+        Arc::clone(&status_events),
     )?);
 
+    let status_page = Arc::new(StatusPage::new(
+        &config,
+        Arc::clone(&network_context),
+        Arc::clone(&bridge_context),
+        Arc::clone(&incidents),
+        Arc::clone(&maintenance),
+    ));
+
+    let admin_token = config.server().admin_token().map(Arc::<str>::from);
+    let admin = Router::new()
+        .route(
+            "/api/admin/maintenance",
+            post({
+                let maintenance = Arc::clone(&maintenance);
+                move |window: Json<MaintenanceWindowConfig>| {
+                    schedule_maintenance(Arc::clone(&maintenance), window)
+                }
+            }),
+        )
+        .route(
+            "/api/admin/maintenance/{id}",
+            delete({
+                let maintenance = Arc::clone(&maintenance);
+                move |id: Path<u64>| cancel_maintenance(Arc::clone(&maintenance), id)
+            }),
+        )
+        .route(
+            "/api/admin/incidents",
+            post({
+                let incidents = Arc::clone(&incidents);
+                move |incident: Json<NewIncident>| create_incident(Arc::clone(&incidents), incident)
+            }),
+        )
+        .route(
+            "/api/admin/incidents/{id}/updates",
+            post({
+                let incidents = Arc::clone(&incidents);
+                move |id: Path<u64>, update: Json<IncidentUpdateRequest>| {
+                    update_incident(Arc::clone(&incidents), id, update)
+                }
+            }),
+        )
+        .route(
+            "/api/admin/incidents/{id}/resolve",
+            post({
+                let incidents = Arc::clone(&incidents);
+                move |id: Path<u64>, resolve: Json<ResolveIncident>| {
+                    resolve_incident(Arc::clone(&incidents), id, resolve)
+                }
+            }),
+        )
+        .route_layer(middleware::from_fn_with_state(admin_token, require_admin));
+
     let cors = CorsLayer::new().allow_origin(Any);
     let app = Router::new()
         .route(
@@ -49,6 +202,22 @@ fn main() -> Result<()> {
                 move || get_network_status(Arc::clone(&network_context))
             }),
         )
+        .route(
+            "/api/status/history",
+            get({
+                let network_context = Arc::clone(&network_context);
+                move |query: Query<HistoryQuery>| {
+                    get_network_status_history(Arc::clone(&network_context), query)
+                }
+            }),
+        )
+        .route(
+            "/api/network/metrics",
+            get({
+                let network_context = Arc::clone(&network_context);
+                move || get_network_metrics(Arc::clone(&network_context))
+            }),
+        )
         .route(
             "/api/bridge_status",
             get({
@@ -56,6 +225,132 @@ fn main() -> Result<()> {
                 move || get_bridge_status(Arc::clone(&bridge_context))
             }),
         )
+        .route(
+            "/api/bridge/deposits",
+            get({
+                let bridge_context = Arc::clone(&bridge_context);
+                move |query: Query<ArchiveQuery>| {
+                    get_archived_deposits(Arc::clone(&bridge_context), query)
+                }
+            }),
+        )
+        .route(
+            "/api/bridge/withdrawals",
+            get({
+                let bridge_context = Arc::clone(&bridge_context);
+                move |query: Query<ArchiveQuery>| {
+                    get_archived_withdrawals(Arc::clone(&bridge_context), query)
+                }
+            }),
+        )
+        .route(
+            "/api/bridge/reimbursements",
+            get({
+                let bridge_context = Arc::clone(&bridge_context);
+                move |query: Query<ArchiveQuery>| {
+                    get_archived_reimbursements(Arc::clone(&bridge_context), query)
+                }
+            }),
+        )
+        .route(
+            "/api/bridge/deposits/{deposit_idx}",
+            get({
+                let bridge_context = Arc::clone(&bridge_context);
+                move |deposit_idx: Path<u32>| {
+                    get_bridge_deposit(Arc::clone(&bridge_context), deposit_idx)
+                }
+            }),
+        )
+        .route(
+            "/api/bridge/tx/{txid}",
+            get({
+                let bridge_context = Arc::clone(&bridge_context);
+                move |txid: Path<String>| get_bridge_tx(Arc::clone(&bridge_context), txid)
+            }),
+        )
+        .route(
+            "/api/alerts",
+            get({
+                let alert_engine = Arc::clone(&alert_engine);
+                move || get_alerts(Arc::clone(&alert_engine))
+            }),
+        )
+        .route(
+            "/api/incidents",
+            get({
+                let incidents = Arc::clone(&incidents);
+                move || get_incidents(Arc::clone(&incidents))
+            }),
+        )
+        .route(
+            "/api/maintenance",
+            get({
+                let maintenance = Arc::clone(&maintenance);
+                move || get_maintenance(Arc::clone(&maintenance))
+            }),
+        )
+        .route(
+            "/api/v2/summary.json",
+            get({
+                let status_page = Arc::clone(&status_page);
+                move || get_statuspage_summary(Arc::clone(&status_page))
+            }),
+        )
+        .route(
+            "/api/v2/status.json",
+            get({
+                let status_page = Arc::clone(&status_page);
+                move || get_statuspage_status(Arc::clone(&status_page))
+            }),
+        )
+        .route(
+            "/badge/bridge.svg",
+            get({
+                let bridge_context = Arc::clone(&bridge_context);
+                move || get_bridge_badge(Arc::clone(&bridge_context))
+            }),
+        )
+        .route(
+            "/badge/operator/{file_name}",
+            get({
+                let bridge_context = Arc::clone(&bridge_context);
+                move |file_name: Path<String>| {
+                    get_operator_badge(Arc::clone(&bridge_context), file_name)
+                }
+            }),
+        )
+        .route(
+            "/badge/{file_name}",
+            get({
+                let network_context = Arc::clone(&network_context);
+                move |file_name: Path<String>| {
+                    get_service_badge(Arc::clone(&network_context), file_name)
+                }
+            }),
+        )
+        .route(
+            "/feed.atom",
+            get({
+                let feed = Arc::clone(&feed);
+                move || get_atom_feed(Arc::clone(&feed))
+            }),
+        )
+        .route(
+            "/feed.rss",
+            get({
+                let feed = Arc::clone(&feed);
+                move || get_rss_feed(Arc::clone(&feed))
+            }),
+        )
+        .route(
+            "/metrics",
+            get({
+                let network_context = Arc::clone(&network_context);
+                let bridge_context = Arc::clone(&bridge_context);
+                move || get_metrics(Arc::clone(&network_context), Arc::clone(&bridge_context))
+            }),
+        )
+        .merge(admin)
         .layer(cors);
 
     let addr = SocketAddr::from((
@@ -79,6 +374,10 @@ fn main() -> Result<()> {
         move |shutdown| async move { bridge_monitoring_task(bridge_context, shutdown).await }
     });
 
+    executor.spawn_critical_async_with_shutdown("alert-dispatcher", move |shutdown| {
+        alert_dispatcher.run(shutdown)
+    });
+
     executor.spawn_critical_async_with_shutdown("http-server", {
         move |shutdown| async move {
             let listener = TcpListener::bind(addr).await?;
@@ -0,0 +1,484 @@
+//! Turns the alerts monitors report on each evaluation into firing and
+//! resolved notifications.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+use serde::Serialize;
+use status_config::AlertsConfig;
+use status_maintenance::MaintenanceSchedule;
+use status_utils::unix_time_s;
+use tokio::sync::{mpsc, Mutex};
+use tracing::{debug, info};
+
+use super::{
+    types::{Alert, AlertState, Notification},
+    webhook::WebhookDispatcher,
+};
+
+/// Alert a monitor has reported on its latest evaluation.
+#[derive(Debug)]
+struct ActiveAlert {
+    source: String,
+    alert: Alert,
+    /// Unix time in seconds since which the condition has been reported.
+    since: u64,
+    /// Unix time in seconds the alert started firing, once it has held for
+    /// its hold time.
+    firing_since: Option<u64>,
+    /// Whether a firing notification was sent, so the resolution is too.
+    notified: bool,
+}
+
+/// Alert that has held for its hold time, as listed on `/api/alerts`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct FiringAlert {
+    /// Monitor that reported the alert.
+    source: String,
+    #[serde(flatten)]
+    alert: Alert,
+    /// Unix time in seconds since which the condition has held.
+    since: u64,
+    /// Unix time in seconds the alert started firing.
+    firing_since: u64,
+}
+
+impl FiringAlert {
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn alert(&self) -> &Alert {
+        &self.alert
+    }
+}
+
+/// Alert transitions across evaluations.
+#[derive(Debug, Default)]
+struct AlertTracker {
+    active: BTreeMap<String, ActiveAlert>,
+    /// Unix time in seconds of each alert's latest firing notification.
+    last_fired: HashMap<String, u64>,
+}
+
+impl AlertTracker {
+    /// Replaces the alerts `source` reported before with `alerts`.
+    ///
+    /// An alert fires once it has held for its hold time, unless the same
+    /// alert fired less than `cooldown_s` ago. Alerts that fired and are no
+    /// longer reported resolve, unless they are terminal. Alerts reported
+    /// again while active are not notified again.
+    ///
+    /// Alerts for which `suppressed` holds are tracked, but do not fire until
+    /// it no longer holds.
+    fn reconcile(
+        &mut self,
+        source: &str,
+        alerts: Vec<Alert>,
+        now: u64,
+        cooldown_s: u64,
+        suppressed: impl Fn(&Alert) -> bool,
+    ) -> Vec<Notification> {
+        let mut notifications = Vec::new();
+        let reported = alerts
+            .iter()
+            .map(|alert| alert.key().to_owned())
+            .collect::<BTreeSet<_>>();
+
+        let cleared = self
+            .active
+            .iter()
+            .filter(|(key, active)| active.source == source && !reported.contains(*key))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        for key in cleared {
+            let Some(active) = self.active.remove(&key) else {
+                continue;
+            };
+            if active.notified && !active.alert.is_terminal() {
+                notifications.push(Notification::new(
+                    AlertState::Resolved,
+                    source,
+                    active.alert,
+                    active.since,
+                    now,
+                ));
+            }
+        }
+
+        self.last_fired
+            .retain(|_, fired_at| now.saturating_sub(*fired_at) < cooldown_s);
+
+        for alert in alerts {
+            let active = self
+                .active
+                .entry(alert.key().to_owned())
+                .or_insert_with(|| ActiveAlert {
+                    source: source.to_owned(),
+                    alert: alert.clone(),
+                    since: alert.since().unwrap_or(now),
+                    firing_since: None,
+                    notified: false,
+                });
+            active.alert = alert;
+            if now.saturating_sub(active.since) < active.alert.hold_for_s() {
+                continue;
+            }
+            active.firing_since.get_or_insert(now);
+            if active.notified
+                || self.last_fired.contains_key(active.alert.key())
+                || suppressed(&active.alert)
+            {
+                continue;
+            }
+
+            active.notified = true;
+            self.last_fired.insert(active.alert.key().to_owned(), now);
+            notifications.push(Notification::new(
+                AlertState::Firing,
+                source,
+                active.alert.clone(),
+                active.since,
+                now,
+            ));
+        }
+
+        notifications
+    }
+
+    /// Alerts that have held for their hold time, most severe first.
+    fn firing(&self) -> Vec<FiringAlert> {
+        let mut firing = self
+            .active
+            .values()
+            .filter_map(|active| {
+                Some(FiringAlert {
+                    source: active.source.clone(),
+                    alert: active.alert.clone(),
+                    since: active.since,
+                    firing_since: active.firing_since?,
+                })
+            })
+            .collect::<Vec<_>>();
+        firing.sort_by_key(|firing| Reverse(firing.alert.severity()));
+        firing
+    }
+}
+
+/// Tracks the alerts reported by the monitors and queues a notification for
+/// each transition.
+pub struct AlertEngine {
+    config: AlertsConfig,
+    maintenance: Arc<MaintenanceSchedule>,
+    tracker: Mutex<AlertTracker>,
+    notifications: mpsc::UnboundedSender<Notification>,
+}
+
+impl AlertEngine {
+    /// Creates the engine and the dispatcher delivering its notifications.
+    pub fn new(
+        config: AlertsConfig,
+        maintenance: Arc<MaintenanceSchedule>,
+    ) -> (Self, WebhookDispatcher) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let dispatcher = WebhookDispatcher::new(&config, receiver);
+        let engine = Self {
+            config,
+            maintenance,
+            tracker: Mutex::new(AlertTracker::default()),
+            notifications: sender,
+        };
+        (engine, dispatcher)
+    }
+
+    pub fn config(&self) -> &AlertsConfig {
+        &self.config
+    }
+
+    /// Replaces the alerts `source` reported on its previous evaluation with
+    /// `alerts` and queues the resulting notifications.
+    ///
+    /// Alerts of components under maintenance are still tracked, but do not
+    /// fire until the maintenance ends. One that fired before the maintenance
+    /// started stays firing and resolves once it is no longer reported.
+    pub async fn reconcile(&self, source: &str, alerts: Vec<Alert>) {
+        let now = unix_time_s();
+        let under_maintenance = |alert: &Alert| {
+            alert
+                .component()
+                .is_some_and(|component| self.maintenance.covers(component, now))
+        };
+        let notifications = self.tracker.lock().await.reconcile(
+            source,
+            alerts,
+            now,
+            self.config.cooldown_s(),
+            under_maintenance,
+        );
+        for notification in notifications {
+            info!(
+                source,
+                key = notification.alert().key(),
+                state = ?notification.state(),
+                "alert transition"
+            );
+            if self.notifications.send(notification).is_err() {
+                debug!("alert dispatcher is not running; dropping notification");
+            }
+        }
+    }
+
+    /// Alerts currently firing across all monitors, most severe first.
+    ///
+    /// Alerts held back by the cooldown are listed even though no
+    /// notification was sent for them.
+    pub async fn firing(&self) -> Vec<FiringAlert> {
+        self.tracker.lock().await.firing()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Severity;
+
+    fn offline(service: &str) -> Alert {
+        Alert::new(
+            format!("service_offline:{service}"),
+            Severity::Critical,
+            format!("Service {service} is offline"),
+        )
+    }
+
+    fn states(notifications: &[Notification]) -> Vec<(AlertState, &str)> {
+        notifications
+            .iter()
+            .map(|notification| (notification.state(), notification.alert().key()))
+            .collect()
+    }
+
+    #[test]
+    fn alert_fires_once_and_resolves() {
+        let mut tracker = AlertTracker::default();
+
+        let fired = tracker.reconcile("network", vec![offline("rpc")], 100, 0, |_| false);
+        assert_eq!(
+            states(&fired),
+            vec![(AlertState::Firing, "service_offline:rpc")]
+        );
+        assert!(tracker
+            .reconcile("network", vec![offline("rpc")], 110, 0, |_| false)
+            .is_empty());
+
+        let resolved = tracker.reconcile("network", vec![], 120, 0, |_| false);
+        assert_eq!(
+            states(&resolved),
+            vec![(AlertState::Resolved, "service_offline:rpc")]
+        );
+        assert!(tracker
+            .reconcile("network", vec![], 130, 0, |_| false)
+            .is_empty());
+    }
+
+    #[test]
+    fn alert_fires_after_hold_time() {
+        let mut tracker = AlertTracker::default();
+        let stuck = || Alert::new("withdrawal_stuck:aa", Severity::Warning, "stuck").held_for(60);
+
+        assert!(tracker
+            .reconcile("bridge", vec![stuck()], 100, 0, |_| false)
+            .is_empty());
+        assert!(tracker
+            .reconcile("bridge", vec![stuck()], 159, 0, |_| false)
+            .is_empty());
+        assert_eq!(
+            tracker
+                .reconcile("bridge", vec![stuck()], 160, 0, |_| false)
+                .len(),
+            1
+        );
+
+        assert_eq!(
+            tracker.reconcile("bridge", vec![], 170, 0, |_| false).len(),
+            1
+        );
+
+        // A condition that clears before firing resolves silently.
+        assert!(tracker
+            .reconcile("bridge", vec![stuck()], 180, 0, |_| false)
+            .is_empty());
+        assert!(tracker
+            .reconcile("bridge", vec![], 190, 0, |_| false)
+            .is_empty());
+    }
+
+    #[test]
+    fn hold_time_counts_from_the_reported_start() {
+        let mut tracker = AlertTracker::default();
+        let stuck = Alert::new("withdrawal_stuck:aa", Severity::Warning, "stuck")
+            .held_for(60)
+            .held_since(50);
+
+        assert_eq!(
+            states(&tracker.reconcile("bridge", vec![stuck], 110, 0, |_| false)),
+            vec![(AlertState::Firing, "withdrawal_stuck:aa")]
+        );
+    }
+
+    #[test]
+    fn rule_hold_time_survives_a_rebuilt_tracker() {
+        let rule = toml::from_str::<status_config::AlertRule>(
+            r#"
+            name = "deposit_stuck"
+            metric = "deposit_in_progress"
+            for_s = 21600
+            "#,
+        )
+        .expect("parse rule");
+        let alerts = || {
+            crate::evaluate_rules(std::slice::from_ref(&rule), |_| {
+                Some(vec![
+                    crate::MetricSample::for_subject("aa", 1.0).held_since(1_000)
+                ])
+            })
+        };
+
+        let mut tracker = AlertTracker::default();
+        assert!(tracker
+            .reconcile("bridge_rules", alerts(), 20_000, 0, |_| false)
+            .is_empty());
+
+        // A restart rebuilds the tracker, but the persisted start still
+        // counts toward the hold time.
+        let mut tracker = AlertTracker::default();
+        assert_eq!(
+            states(&tracker.reconcile("bridge_rules", alerts(), 22_600, 0, |_| false)),
+            vec![(AlertState::Firing, "rule:deposit_stuck:aa")]
+        );
+    }
+
+    #[test]
+    fn terminal_alerts_do_not_resolve() {
+        let mut tracker = AlertTracker::default();
+        let slashed =
+            Alert::new("reimbursement_slashed:aa", Severity::Critical, "slashed").terminal();
+
+        assert_eq!(
+            tracker
+                .reconcile("bridge", vec![slashed], 100, 0, |_| false)
+                .len(),
+            1
+        );
+        assert!(tracker
+            .reconcile("bridge", vec![], 110, 0, |_| false)
+            .is_empty());
+        assert!(tracker.firing().is_empty());
+    }
+
+    #[test]
+    fn cooldown_suppresses_flapping_alert() {
+        let mut tracker = AlertTracker::default();
+
+        assert_eq!(
+            tracker
+                .reconcile("network", vec![offline("rpc")], 100, 300, |_| false)
+                .len(),
+            1
+        );
+        assert_eq!(
+            tracker
+                .reconcile("network", vec![], 110, 300, |_| false)
+                .len(),
+            1
+        );
+
+        // Fires again only once the cooldown has passed.
+        assert!(tracker
+            .reconcile("network", vec![offline("rpc")], 120, 300, |_| false)
+            .is_empty());
+        assert!(tracker
+            .reconcile("network", vec![], 130, 300, |_| false)
+            .is_empty());
+        assert!(tracker
+            .reconcile("network", vec![offline("rpc")], 350, 300, |_| false)
+            .is_empty());
+        assert_eq!(
+            states(&tracker.reconcile("network", vec![offline("rpc")], 400, 300, |_| false)),
+            vec![(AlertState::Firing, "service_offline:rpc")]
+        );
+    }
+
+    #[test]
+    fn sources_resolve_only_their_own_alerts() {
+        let mut tracker = AlertTracker::default();
+        tracker.reconcile("network", vec![offline("rpc")], 100, 0, |_| false);
+
+        assert!(tracker
+            .reconcile("bridge", vec![], 110, 0, |_| false)
+            .is_empty());
+        assert_eq!(
+            tracker
+                .reconcile("network", vec![], 120, 0, |_| false)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn firing_lists_held_alerts_by_severity() {
+        let mut tracker = AlertTracker::default();
+        let queue = Alert::new("rule:queue", Severity::Warning, "queue").held_for(60);
+
+        tracker.reconcile("bridge", vec![queue.clone()], 100, 0, |_| false);
+        tracker.reconcile("network", vec![offline("rpc")], 100, 0, |_| false);
+        let firing = tracker.firing();
+        assert_eq!(firing.len(), 1);
+        assert_eq!(firing[0].alert().key(), "service_offline:rpc");
+
+        // Listed while the cooldown suppresses its notification.
+        tracker.reconcile("network", vec![], 110, 300, |_| false);
+        assert!(tracker
+            .reconcile("network", vec![offline("rpc")], 120, 300, |_| false)
+            .is_empty());
+        tracker.reconcile("bridge", vec![queue], 170, 300, |_| false);
+        let keys = tracker
+            .firing()
+            .iter()
+            .map(|firing| firing.alert().key().to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec!["service_offline:rpc", "rule:queue"]);
+    }
+
+    #[test]
+    fn suppressed_alerts_fire_once_no_longer_suppressed() {
+        let mut tracker = AlertTracker::default();
+
+        assert!(tracker
+            .reconcile("network", vec![offline("rpc")], 100, 0, |_| true)
+            .is_empty());
+        assert!(tracker
+            .reconcile("network", vec![offline("rpc")], 110, 0, |_| true)
+            .is_empty());
+        assert_eq!(tracker.firing().len(), 1);
+        assert_eq!(
+            states(&tracker.reconcile("network", vec![offline("rpc")], 120, 0, |_| false)),
+            vec![(AlertState::Firing, "service_offline:rpc")]
+        );
+
+        // Firing alerts stay firing while suppressed, without resolving.
+        assert!(tracker
+            .reconcile("network", vec![offline("rpc")], 130, 0, |_| true)
+            .is_empty());
+        assert_eq!(
+            states(&tracker.reconcile("network", vec![], 140, 0, |_| true)),
+            vec![(AlertState::Resolved, "service_offline:rpc")]
+        );
+
+        // Alerts that clear while suppressed resolve silently.
+        tracker.reconcile("network", vec![offline("rpc")], 150, 0, |_| true);
+        assert!(tracker
+            .reconcile("network", vec![], 160, 0, |_| true)
+            .is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+mod engine;
+mod rules;
+mod types;
+mod webhook;
+
+pub use engine::{AlertEngine, FiringAlert};
+pub use rules::{evaluate_rules, MetricSample};
+pub use types::{Alert, AlertState, Notification, Severity};
+pub use webhook::WebhookDispatcher;
@@ -0,0 +1,192 @@
+//! Evaluates the configured alert rules against monitor metrics.
+
+use status_config::{AlertMetric, AlertRule};
+use status_utils::Component;
+
+use super::types::Alert;
+
+/// Value of a rule metric, for one subject if the metric is per subject.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricSample {
+    /// Service, operator or transfer the value belongs to.
+    subject: Option<String>,
+    value: f64,
+    /// Component whose maintenance suppresses the rule's alerts.
+    component: Option<Component>,
+    /// Unix time in seconds the subject entered its current state, if the
+    /// monitor keeps it across restarts.
+    since: Option<u64>,
+}
+
+impl MetricSample {
+    pub fn new(value: f64) -> Self {
+        Self {
+            subject: None,
+            value,
+            component: None,
+            since: None,
+        }
+    }
+
+    pub fn for_subject(subject: impl Into<String>, value: f64) -> Self {
+        Self {
+            subject: Some(subject.into()),
+            value,
+            component: None,
+            since: None,
+        }
+    }
+
+    /// Suppresses the alerts of the sample while `component` is under
+    /// maintenance.
+    pub fn for_component(mut self, component: Component) -> Self {
+        self.component = Some(component);
+        self
+    }
+
+    /// Measures the rule's `for_s` from `since` rather than from the first
+    /// evaluation that breached it.
+    pub fn held_since(mut self, since: u64) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// Whether `value` crosses the rule's thresholds. Without thresholds, any
+/// non-zero value does.
+fn breaches(rule: &AlertRule, value: f64) -> bool {
+    if rule.above().is_none() && rule.below().is_none() {
+        return value != 0.0;
+    }
+    rule.above().is_none_or(|above| value > above) && rule.below().is_none_or(|below| value < below)
+}
+
+fn rule_alert(rule: &AlertRule, sample: &MetricSample) -> Alert {
+    let description = rule.description().unwrap_or(rule.name());
+    let (key, summary) = match &sample.subject {
+        Some(subject) => (
+            format!("rule:{}:{subject}", rule.name()),
+            format!("{description}: {subject} ({})", sample.value),
+        ),
+        None => (
+            format!("rule:{}", rule.name()),
+            format!("{description} ({})", sample.value),
+        ),
+    };
+    let mut alert = Alert::new(key, rule.severity(), summary).held_for(rule.for_s());
+    if let Some(component) = &sample.component {
+        alert = alert.for_component(component.clone());
+    }
+    if let Some(since) = sample.since {
+        alert = alert.held_since(since);
+    }
+    alert
+}
+
+/// Returns an alert for each sample breaching a rule.
+///
+/// `samples` returns the current samples of a metric, or [`None`] if the
+/// caller does not monitor it, in which case its rules are skipped.
+pub fn evaluate_rules(
+    rules: &[AlertRule],
+    mut samples: impl FnMut(AlertMetric) -> Option<Vec<MetricSample>>,
+) -> Vec<Alert> {
+    let mut alerts = Vec::new();
+    for rule in rules {
+        let Some(samples) = samples(rule.metric()) else {
+            continue;
+        };
+        alerts.extend(
+            samples
+                .iter()
+                .filter(|sample| breaches(rule, sample.value))
+                .map(|sample| rule_alert(rule, sample)),
+        );
+    }
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(toml: &str) -> AlertRule {
+        toml::from_str(toml).expect("parse rule")
+    }
+
+    #[test]
+    fn thresholds_are_exclusive() {
+        let above = rule(
+            r#"
+            name = "queue"
+            metric = "withdrawal_queue_depth"
+            above = 20
+            "#,
+        );
+        let band = rule(
+            r#"
+            name = "band"
+            metric = "withdrawal_queue_depth"
+            above = 1
+            below = 5
+            "#,
+        );
+        let flag = rule(
+            r#"
+            name = "offline"
+            metric = "operator_offline"
+            "#,
+        );
+
+        assert!(!breaches(&above, 20.0));
+        assert!(breaches(&above, 21.0));
+        assert!(breaches(&band, 3.0));
+        assert!(!breaches(&band, 5.0));
+        assert!(!breaches(&flag, 0.0));
+        assert!(breaches(&flag, 1.0));
+    }
+
+    #[test]
+    fn rules_alert_per_subject_and_skip_unmonitored_metrics() {
+        let rules = [
+            rule(
+                r#"
+                name = "operator_down"
+                metric = "operator_offline"
+                for_s = 600
+                severity = "critical"
+                description = "Operator offline for 10 minutes"
+                "#,
+            ),
+            rule(
+                r#"
+                name = "indexer_lagging"
+                metric = "indexer_lag_blocks"
+                above = 500
+                "#,
+            ),
+        ];
+
+        let alerts = evaluate_rules(&rules, |metric| match metric {
+            AlertMetric::OperatorOffline => Some(vec![
+                MetricSample::for_subject("Alpen Labs #1", 1.0),
+                MetricSample::for_subject("Alpen Labs #2", 0.0),
+            ]),
+            _ => None,
+        });
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].key(), "rule:operator_down:Alpen Labs #1");
+        assert_eq!(
+            alerts[0].summary(),
+            "Operator offline for 10 minutes: Alpen Labs #1 (1)"
+        );
+        assert_eq!(alerts[0].hold_for_s(), 600);
+
+        let alerts = evaluate_rules(&rules, |metric| {
+            (metric == AlertMetric::IndexerLagBlocks).then(|| vec![MetricSample::new(501.0)])
+        });
+        assert_eq!(alerts[0].key(), "rule:indexer_lagging");
+        assert_eq!(alerts[0].summary(), "indexer_lagging (501)");
+    }
+}
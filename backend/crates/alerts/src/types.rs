@@ -0,0 +1,151 @@
+use serde::Serialize;
+use status_utils::Component;
+
+pub use status_config::AlertSeverity as Severity;
+
+/// Condition a monitor currently reports as alerting.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Alert {
+    /// Identifies the condition across evaluations, e.g. `service_offline:rpc`.
+    key: String,
+    severity: Severity,
+    summary: String,
+    /// Component the condition is about, whose maintenance suppresses it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    component: Option<Component>,
+    /// Seconds the condition must hold before the alert fires.
+    #[serde(skip)]
+    hold_for_s: u64,
+    /// Unix time in seconds the condition started, if the monitor keeps it
+    /// across restarts.
+    #[serde(skip)]
+    since: Option<u64>,
+    /// Whether the condition is final, so the alert does not resolve once it
+    /// is no longer reported.
+    #[serde(skip)]
+    terminal: bool,
+}
+
+impl Alert {
+    pub fn new(key: impl Into<String>, severity: Severity, summary: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            severity,
+            summary: summary.into(),
+            component: None,
+            hold_for_s: 0,
+            since: None,
+            terminal: false,
+        }
+    }
+
+    /// Suppresses the alert while `component` is under maintenance.
+    pub fn for_component(mut self, component: Component) -> Self {
+        self.component = Some(component);
+        self
+    }
+
+    /// Fires the alert only once the condition has held for `hold_for_s`
+    /// seconds.
+    pub fn held_for(mut self, hold_for_s: u64) -> Self {
+        self.hold_for_s = hold_for_s;
+        self
+    }
+
+    /// Measures the hold time from `since` rather than from the first
+    /// evaluation that reported the alert.
+    pub fn held_since(mut self, since: u64) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Sends no resolution once the alert is no longer reported, e.g. when the
+    /// row it is about is purged.
+    pub fn terminal(mut self) -> Self {
+        self.terminal = true;
+        self
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn component(&self) -> Option<&Component> {
+        self.component.as_ref()
+    }
+
+    pub(crate) fn hold_for_s(&self) -> u64 {
+        self.hold_for_s
+    }
+
+    pub(crate) fn since(&self) -> Option<u64> {
+        self.since
+    }
+
+    pub(crate) fn is_terminal(&self) -> bool {
+        self.terminal
+    }
+}
+
+/// Transition an alert notification reports.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertState {
+    Firing,
+    Resolved,
+}
+
+/// Alert transition sent to the webhooks.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Notification {
+    state: AlertState,
+    /// Monitor that reported the alert.
+    source: String,
+    #[serde(flatten)]
+    alert: Alert,
+    /// Unix time in seconds since which the condition has held.
+    since: u64,
+    /// Unix time in seconds of the transition.
+    at: u64,
+}
+
+impl Notification {
+    pub(crate) fn new(state: AlertState, source: &str, alert: Alert, since: u64, at: u64) -> Self {
+        Self {
+            state,
+            source: source.to_owned(),
+            alert,
+            since,
+            at,
+        }
+    }
+
+    pub fn state(&self) -> AlertState {
+        self.state
+    }
+
+    pub fn alert(&self) -> &Alert {
+        &self.alert
+    }
+
+    /// One-line description for chat messages.
+    pub(crate) fn text(&self) -> String {
+        let state = match self.state {
+            AlertState::Firing => "FIRING",
+            AlertState::Resolved => "RESOLVED",
+        };
+        format!(
+            "[{state}] [{}] {}",
+            self.alert.severity.as_str(),
+            self.alert.summary
+        )
+    }
+}
@@ -0,0 +1,188 @@
+//! Delivers alert notifications to the configured webhooks.
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use status_config::{AlertsConfig, WebhookConfig, WebhookFormat};
+use status_utils::execute_with_retries;
+use strata_tasks::ShutdownGuard;
+use tokio::{sync::mpsc, task::JoinSet};
+use tracing::{error, info, warn};
+
+use super::types::Notification;
+
+/// Request body of `notification` in the webhook's format.
+fn payload(format: WebhookFormat, notification: &Notification) -> Value {
+    match format {
+        WebhookFormat::Json => json!(notification),
+        WebhookFormat::Slack => json!({ "text": notification.text() }),
+        WebhookFormat::Discord => json!({ "content": notification.text() }),
+    }
+}
+
+/// Posts `notification` to `webhook`, logging the failure once retries are
+/// exhausted.
+async fn post(client: reqwest::Client, webhook: WebhookConfig, notification: Notification) {
+    let body = payload(webhook.format(), &notification);
+    let result = execute_with_retries(
+        || async {
+            client
+                .post(webhook.url())
+                .json(&body)
+                .send()
+                .await?
+                .error_for_status()
+        },
+        "alert webhook",
+    )
+    .await;
+    if let Err(e) = result {
+        warn!(
+            key = notification.alert().key(),
+            error = %e,
+            "failed to deliver alert notification"
+        );
+    }
+}
+
+/// Posts queued notifications to every configured webhook.
+pub struct WebhookDispatcher {
+    webhooks: Vec<WebhookConfig>,
+    client: reqwest::Client,
+    receiver: mpsc::UnboundedReceiver<Notification>,
+}
+
+impl WebhookDispatcher {
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be built.
+    pub(crate) fn new(
+        config: &AlertsConfig,
+        receiver: mpsc::UnboundedReceiver<Notification>,
+    ) -> Self {
+        Self {
+            webhooks: config.webhooks().to_vec(),
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(config.webhook_timeout_s()))
+                .build()
+                .expect("failed to create webhook HTTP client"),
+            receiver,
+        }
+    }
+
+    /// Delivers notifications in order until `shutdown` is signalled.
+    ///
+    /// Each webhook has its own queue and task, so a slow or failing webhook
+    /// does not delay later notifications to the others. Failed deliveries
+    /// are logged and dropped after retries.
+    pub async fn run(mut self, shutdown: ShutdownGuard) -> anyhow::Result<()> {
+        info!(
+            webhook_count = self.webhooks.len(),
+            "alert dispatcher starting"
+        );
+        let mut deliveries = JoinSet::new();
+        let queues = self
+            .webhooks
+            .iter()
+            .map(|webhook| {
+                let (sender, receiver) = mpsc::unbounded_channel();
+                deliveries.spawn(deliver(self.client.clone(), webhook.clone(), receiver));
+                sender
+            })
+            .collect::<Vec<_>>();
+
+        let shut_down = loop {
+            tokio::select! {
+                _ = shutdown.wait_for_shutdown() => break true,
+                notification = self.receiver.recv() => match notification {
+                    Some(notification) => {
+                        for queue in &queues {
+                            // The queue is closed only if its task failed,
+                            // which is logged below.
+                            let _ = queue.send(notification.clone());
+                        }
+                    }
+                    None => break false,
+                },
+                Some(result) = deliveries.join_next() => {
+                    if let Err(e) = result {
+                        error!(error = %e, "alert webhook delivery task failed");
+                    }
+                }
+            }
+        };
+
+        // Closing the queues lets the webhook tasks drain them, unless the
+        // dispatcher is shutting down.
+        drop(queues);
+        if shut_down {
+            deliveries.abort_all();
+        }
+        while let Some(result) = deliveries.join_next().await {
+            if let Err(e) = result {
+                if !e.is_cancelled() {
+                    error!(error = %e, "alert webhook delivery task failed");
+                }
+            }
+        }
+
+        info!("alert dispatcher shutting down");
+        Ok(())
+    }
+}
+
+/// Posts the notifications queued for `webhook` in order, until the queue is
+/// closed.
+async fn deliver(
+    client: reqwest::Client,
+    webhook: WebhookConfig,
+    mut queue: mpsc::UnboundedReceiver<Notification>,
+) {
+    while let Some(notification) = queue.recv().await {
+        post(client.clone(), webhook.clone(), notification).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Alert, AlertState, Severity};
+
+    fn notification() -> Notification {
+        Notification::new(
+            AlertState::Firing,
+            "network",
+            Alert::new(
+                "service_offline:rpc",
+                Severity::Critical,
+                "Service rpc is offline",
+            ),
+            100,
+            160,
+        )
+    }
+
+    #[test]
+    fn payloads_follow_webhook_format() {
+        assert_eq!(
+            payload(WebhookFormat::Json, &notification()),
+            json!({
+                "state": "firing",
+                "source": "network",
+                "key": "service_offline:rpc",
+                "severity": "critical",
+                "summary": "Service rpc is offline",
+                "since": 100,
+                "at": 160
+            })
+        );
+        assert_eq!(
+            payload(WebhookFormat::Slack, &notification()),
+            json!({ "text": "[FIRING] [critical] Service rpc is offline" })
+        );
+        assert_eq!(
+            payload(WebhookFormat::Discord, &notification()),
+            json!({ "content": "[FIRING] [critical] Service rpc is offline" })
+        );
+    }
+}
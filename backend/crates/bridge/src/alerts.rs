@@ -0,0 +1,344 @@
+//! Alerts raised from the bridge status.
+
+use std::collections::BTreeMap;
+
+use bitcoin::hashes::Hash;
+use status_alerts::{Alert, MetricSample, Severity};
+use status_config::AlertMetric;
+use status_utils::Component;
+use strata_bridge_rpc::types::RpcOperatorStatus;
+use strata_primitives::buf::Buf32;
+
+use super::types::{
+    BridgeStatus, ChallengeStep, DepositStatus, OperatorStatus, ReimbursementStatus,
+    WithdrawalStatus,
+};
+
+/// Source the operator alerts are reported under.
+pub(crate) const OPERATOR_ALERT_SOURCE: &str = "bridge_operators";
+
+/// Source the deposit, withdrawal and reimbursement alerts are reported
+/// under.
+pub(crate) const TRANSFER_ALERT_SOURCE: &str = "bridge_transfers";
+
+/// Source the alerts of the configured rules on operator and withdrawal
+/// indexer metrics are reported under.
+pub(crate) const OPERATOR_RULE_ALERT_SOURCE: &str = "bridge_operator_rules";
+
+/// Source the alerts of the configured rules on deposit and withdrawal
+/// metrics are reported under.
+pub(crate) const TRANSFER_RULE_ALERT_SOURCE: &str = "bridge_rules";
+
+/// Returns an alert for each offline operator.
+pub(crate) fn operator_alerts(operators: &[OperatorStatus]) -> Vec<Alert> {
+    operators
+        .iter()
+        .filter(|operator| matches!(operator.status(), RpcOperatorStatus::Offline))
+        .map(|operator| {
+            Alert::new(
+                format!("operator_offline:{}", operator.operator_pk()),
+                Severity::Critical,
+                format!("Bridge operator {} is offline", operator.operator_id()),
+            )
+            .for_component(Component::Operator(operator.operator_pk().to_string()))
+        })
+        .collect()
+}
+
+/// Returns an alert for each slashed or contested reimbursement, and for each
+/// withdrawal that stays in progress for `withdrawal_stuck_threshold_s`.
+///
+/// `in_progress_since` holds the persisted start of each withdrawal in
+/// progress, so the hold time survives restarts. Slashing is final, so its
+/// alert does not resolve once the row is purged.
+pub(crate) fn transfer_alerts(
+    status: &BridgeStatus,
+    withdrawal_stuck_threshold_s: u64,
+    in_progress_since: &BTreeMap<Buf32, u64>,
+) -> Vec<Alert> {
+    let reimbursements = status.reimbursements.iter().filter_map(|reimbursement| {
+        let claim_txid = reimbursement.claim_txid;
+        if reimbursement.status == ReimbursementStatus::Slashed {
+            Some(
+                Alert::new(
+                    format!("reimbursement_slashed:{claim_txid}"),
+                    Severity::Critical,
+                    format!("Operator slashed for claim {claim_txid}"),
+                )
+                .terminal(),
+            )
+        } else if reimbursement.challenge_step == ChallengeStep::Contested {
+            Some(Alert::new(
+                format!("reimbursement_contested:{claim_txid}"),
+                Severity::Warning,
+                format!("Claim {claim_txid} is contested"),
+            ))
+        } else {
+            None
+        }
+    });
+    let withdrawals = status
+        .withdrawals
+        .iter()
+        .filter(|withdrawal| matches!(withdrawal.status, WithdrawalStatus::InProgress))
+        .map(|withdrawal| {
+            let request_txid = withdrawal.withdrawal_request_txid;
+            let alert = Alert::new(
+                format!("withdrawal_stuck:{request_txid}"),
+                Severity::Warning,
+                format!(
+                    "Withdrawal {request_txid} has been in progress for over {withdrawal_stuck_threshold_s}s"
+                ),
+            )
+            .held_for(withdrawal_stuck_threshold_s);
+            match in_progress_since.get(&request_txid) {
+                Some(since) => alert.held_since(*since),
+                None => alert,
+            }
+        });
+
+    reimbursements.chain(withdrawals).collect()
+}
+
+/// Returns the samples of an operator or withdrawal indexer `metric`, or
+/// [`None`] if it is not one.
+///
+/// These are evaluated right after the operator poll, so a failing Esplora or
+/// bridge RPC call later in the tick cannot skip them. `indexer_lag_blocks` is
+/// the withdrawal indexer's lag behind the EVM chain head, if it has scanned.
+pub(crate) fn operator_rule_samples(
+    operators: &[OperatorStatus],
+    indexer_lag_blocks: Option<u64>,
+    metric: AlertMetric,
+) -> Option<Vec<MetricSample>> {
+    let samples = match metric {
+        AlertMetric::OperatorOffline => operators
+            .iter()
+            .map(|operator| {
+                let offline = matches!(operator.status(), RpcOperatorStatus::Offline);
+                MetricSample::for_subject(operator.operator_id(), f64::from(u8::from(offline)))
+                    .for_component(Component::Operator(operator.operator_pk().to_string()))
+            })
+            .collect(),
+        AlertMetric::IndexerLagBlocks => indexer_lag_blocks
+            .map(|lag_blocks| MetricSample::new(lag_blocks as f64))
+            .into_iter()
+            .collect(),
+        AlertMetric::ServiceOffline
+        | AlertMetric::ServiceLatencyP95Ms
+        | AlertMetric::L1TipAgeS
+        | AlertMetric::L1ReaderLagBlocks
+        | AlertMetric::CheckpointAgeS
+        | AlertMetric::DepositInProgress
+        | AlertMetric::WithdrawalInProgress
+        | AlertMetric::WithdrawalQueueDepth => return None,
+    };
+    Some(samples)
+}
+
+/// Sample of a transfer in progress, held since its persisted start if known.
+fn in_progress_sample(subject: String, since: Option<&u64>) -> MetricSample {
+    let sample = MetricSample::for_subject(subject, 1.0);
+    match since {
+        Some(since) => sample.held_since(*since),
+        None => sample,
+    }
+}
+
+/// Returns the samples of a deposit or withdrawal `metric` in `status`, or
+/// [`None`] if it is not one.
+///
+/// `deposits_since` and `withdrawals_since` hold the persisted start of each
+/// transfer in progress, so the rules' hold times survive restarts.
+pub(crate) fn transfer_rule_samples(
+    status: &BridgeStatus,
+    deposits_since: &BTreeMap<Buf32, u64>,
+    withdrawals_since: &BTreeMap<Buf32, u64>,
+    metric: AlertMetric,
+) -> Option<Vec<MetricSample>> {
+    let in_progress_withdrawals = status
+        .withdrawals
+        .iter()
+        .filter(|withdrawal| matches!(withdrawal.status, WithdrawalStatus::InProgress));
+    let samples = match metric {
+        AlertMetric::DepositInProgress => status
+            .deposits
+            .iter()
+            .filter(|deposit| matches!(deposit.status, DepositStatus::InProgress))
+            .map(|deposit| {
+                let request_txid = deposit.deposit_request_txid;
+                in_progress_sample(
+                    request_txid.to_string(),
+                    deposits_since.get(&Buf32(request_txid.to_byte_array())),
+                )
+            })
+            .collect(),
+        AlertMetric::WithdrawalInProgress => in_progress_withdrawals
+            .map(|withdrawal| {
+                let request_txid = withdrawal.withdrawal_request_txid;
+                in_progress_sample(
+                    request_txid.to_string(),
+                    withdrawals_since.get(&request_txid),
+                )
+            })
+            .collect(),
+        AlertMetric::WithdrawalQueueDepth => {
+            vec![MetricSample::new(in_progress_withdrawals.count() as f64)]
+        }
+        AlertMetric::ServiceOffline
+        | AlertMetric::ServiceLatencyP95Ms
+        | AlertMetric::L1TipAgeS
+        | AlertMetric::L1ReaderLagBlocks
+        | AlertMetric::CheckpointAgeS
+        | AlertMetric::OperatorOffline
+        | AlertMetric::IndexerLagBlocks => return None,
+    };
+    Some(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::{hashes::Hash, PublicKey, Txid};
+    use std::str::FromStr;
+    use strata_primitives::buf::Buf32;
+
+    use super::*;
+    use crate::types::{ReimbursementInfo, WithdrawalInfo};
+
+    fn reimbursement(
+        byte: u8,
+        challenge_step: ChallengeStep,
+        status: ReimbursementStatus,
+    ) -> ReimbursementInfo {
+        ReimbursementInfo {
+            claim_txid: Txid::from_byte_array([byte; 32]),
+            challenge_step,
+            payout_txid: None,
+            status,
+        }
+    }
+
+    #[test]
+    fn offline_operators_alert() {
+        let operator_pk = PublicKey::from_str(
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        )
+        .expect("valid public key");
+        let operators = [
+            OperatorStatus::new("Online".to_owned(), operator_pk, RpcOperatorStatus::Online),
+            OperatorStatus::new(
+                "Offline".to_owned(),
+                operator_pk,
+                RpcOperatorStatus::Offline,
+            ),
+        ];
+
+        let alerts = operator_alerts(&operators);
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].key(), format!("operator_offline:{operator_pk}"));
+        assert_eq!(alerts[0].summary(), "Bridge operator Offline is offline");
+    }
+
+    #[test]
+    fn slashed_contested_and_pending_transfers_alert() {
+        let status = BridgeStatus {
+            reimbursements: vec![
+                reimbursement(
+                    1,
+                    ChallengeStep::NotApplicable,
+                    ReimbursementStatus::Slashed,
+                ),
+                reimbursement(2, ChallengeStep::Contested, ReimbursementStatus::InProgress),
+                reimbursement(3, ChallengeStep::Claimed, ReimbursementStatus::InProgress),
+                reimbursement(
+                    4,
+                    ChallengeStep::NotApplicable,
+                    ReimbursementStatus::Complete,
+                ),
+            ],
+            withdrawals: vec![
+                WithdrawalInfo {
+                    withdrawal_request_txid: Buf32::from([5; 32]),
+                    fulfillment_txid: None,
+                    status: WithdrawalStatus::InProgress,
+                },
+                WithdrawalInfo {
+                    withdrawal_request_txid: Buf32::from([6; 32]),
+                    fulfillment_txid: Some(Txid::from_byte_array([7; 32])),
+                    status: WithdrawalStatus::Complete,
+                },
+            ],
+            ..BridgeStatus::default()
+        };
+
+        let alerts = transfer_alerts(&status, 3600, &BTreeMap::new());
+        let keys = alerts
+            .iter()
+            .map(|alert| alert.key().split(':').next().expect("key prefix"))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            keys,
+            [
+                "reimbursement_slashed",
+                "reimbursement_contested",
+                "withdrawal_stuck"
+            ]
+        );
+        assert_eq!(alerts[0].severity(), Severity::Critical);
+        assert_eq!(
+            alerts[2].summary(),
+            format!(
+                "Withdrawal {} has been in progress for over 3600s",
+                Buf32::from([5; 32])
+            )
+        );
+    }
+
+    #[test]
+    fn rule_samples_split_operator_and_transfer_metrics() {
+        let status = BridgeStatus {
+            withdrawals: vec![
+                WithdrawalInfo {
+                    withdrawal_request_txid: Buf32::from([5; 32]),
+                    fulfillment_txid: None,
+                    status: WithdrawalStatus::InProgress,
+                },
+                WithdrawalInfo {
+                    withdrawal_request_txid: Buf32::from([6; 32]),
+                    fulfillment_txid: Some(Txid::from_byte_array([7; 32])),
+                    status: WithdrawalStatus::Complete,
+                },
+            ],
+            ..BridgeStatus::default()
+        };
+
+        let withdrawals_since = BTreeMap::from([(Buf32::from([5; 32]), 100)]);
+        let samples =
+            |metric| transfer_rule_samples(&status, &BTreeMap::new(), &withdrawals_since, metric);
+
+        assert_eq!(
+            samples(AlertMetric::WithdrawalQueueDepth),
+            Some(vec![MetricSample::new(1.0)])
+        );
+        assert_eq!(
+            samples(AlertMetric::WithdrawalInProgress),
+            Some(vec![MetricSample::for_subject(
+                Buf32::from([5; 32]).to_string(),
+                1.0
+            )
+            .held_since(100)])
+        );
+        assert_eq!(samples(AlertMetric::IndexerLagBlocks), None);
+        assert_eq!(
+            operator_rule_samples(&[], Some(12), AlertMetric::IndexerLagBlocks),
+            Some(vec![MetricSample::new(12.0)])
+        );
+        assert_eq!(
+            operator_rule_samples(&[], None, AlertMetric::WithdrawalQueueDepth),
+            None
+        );
+        assert_eq!(samples(AlertMetric::ServiceOffline), None);
+    }
+}
@@ -0,0 +1,225 @@
+//! Paginated history of terminal bridge rows kept in the status DB archive.
+//!
+//! Rows are archived as they reach `max_tx_confirmations`, so the history
+//! starts when the archive was first deployed. Terminal withdrawal rows still
+//! persisted at startup are seeded into it; deposits and reimbursements purged
+//! before then are not recovered.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::Query,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use strata_bridge_primitives::types::DepositIdx;
+use tracing::error;
+
+use super::{
+    context::BridgeMonitoringContext,
+    db::{
+        error::DbResult,
+        traits::BridgeStatusDb,
+        types::{DbArchivePage, DbBridgeStatusSnapshot},
+    },
+    types::{ArchivedRow, WithdrawalStatus},
+};
+
+/// Number of rows returned when the query has no `limit`.
+const DEFAULT_PAGE_LIMIT: usize = 50;
+
+/// Largest `limit` honoured by the archive endpoints.
+const MAX_PAGE_LIMIT: usize = 500;
+
+/// Query parameters for `/api/bridge/deposits`, `/api/bridge/withdrawals` and
+/// `/api/bridge/reimbursements`.
+#[derive(Debug, Default, Deserialize)]
+pub struct ArchiveQuery {
+    /// Only rows with this status, case-insensitively, e.g. `complete`.
+    status: Option<String>,
+    /// Only rows after this deposit index, the `next_after` of the previous page.
+    after: Option<DepositIdx>,
+    /// Maximum number of rows returned.
+    limit: Option<usize>,
+}
+
+impl ArchiveQuery {
+    fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT)
+    }
+
+    /// Whether `status` is the one queried, comparing its API name.
+    fn matches(&self, status: &impl Serialize) -> bool {
+        let Some(queried) = self.status.as_deref() else {
+            return true;
+        };
+        serde_json::to_value(status)
+            .ok()
+            .and_then(|status| {
+                status
+                    .as_str()
+                    .map(|name| name.eq_ignore_ascii_case(queried))
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// One page of archived rows in ascending deposit order.
+#[derive(Serialize, Debug)]
+struct ArchivePage<T> {
+    rows: Vec<ArchivedRow<T>>,
+    /// Deposit index to pass as `after` for the next page, absent on the last page.
+    ///
+    /// A page may hold fewer than `limit` rows, or none, when the scan of a
+    /// filtered query stops before the end of the archive.
+    next_after: Option<DepositIdx>,
+}
+
+fn page_response<T: Serialize>(page: DbResult<DbArchivePage<T>>) -> Result<Response, StatusCode> {
+    let DbArchivePage { rows, next_after } = page.map_err(|e| {
+        error!(error = %e, "could not read bridge archive");
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    Ok(Json(ArchivePage { rows, next_after }).into_response())
+}
+
+/// Archives the complete withdrawal rows of `snapshot` the status cursor has
+/// moved past and that are not archived yet, returning how many were.
+///
+/// The cursor only moves past rows that reached `max_confirmations`, which is
+/// recorded as their confirmations.
+pub(crate) fn seed_withdrawal_archive(
+    status_db: &impl BridgeStatusDb,
+    snapshot: &DbBridgeStatusSnapshot,
+    max_confirmations: u64,
+) -> DbResult<usize> {
+    let cursor = snapshot.cursors.withdrawal_status.next_deposit_idx;
+    let mut rows = Vec::new();
+    for (deposit_idx, info) in &snapshot.withdrawals {
+        if *deposit_idx < cursor
+            && matches!(info.status, WithdrawalStatus::Complete)
+            && status_db.get_archived_withdrawal(*deposit_idx)?.is_none()
+        {
+            rows.push(ArchivedRow {
+                deposit_idx: *deposit_idx,
+                info: *info,
+                confirmations: max_confirmations,
+            });
+        }
+    }
+    status_db.put_archived_withdrawals(&rows)?;
+    Ok(rows.len())
+}
+
+/// Handler to get archived deposits, `/api/bridge/deposits`
+pub async fn get_archived_deposits(
+    context: Arc<BridgeMonitoringContext>,
+    Query(query): Query<ArchiveQuery>,
+) -> Result<Response, StatusCode> {
+    let page = context
+        .status_db()
+        .archived_deposits_after(query.after, query.limit(), |info| {
+            query.matches(&info.status)
+        });
+    page_response(page)
+}
+
+/// Handler to get archived withdrawals, `/api/bridge/withdrawals`
+pub async fn get_archived_withdrawals(
+    context: Arc<BridgeMonitoringContext>,
+    Query(query): Query<ArchiveQuery>,
+) -> Result<Response, StatusCode> {
+    let page = context
+        .status_db()
+        .archived_withdrawals_after(query.after, query.limit(), |info| {
+            query.matches(&info.status)
+        });
+    page_response(page)
+}
+
+/// Handler to get archived reimbursements, `/api/bridge/reimbursements`
+pub async fn get_archived_reimbursements(
+    context: Arc<BridgeMonitoringContext>,
+    Query(query): Query<ArchiveQuery>,
+) -> Result<Response, StatusCode> {
+    let page =
+        context
+            .status_db()
+            .archived_reimbursements_after(query.after, query.limit(), |info| {
+                query.matches(&info.status)
+            });
+    page_response(page)
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::{hashes::Hash, Txid};
+    use strata_primitives::buf::Buf32;
+
+    use super::*;
+    use crate::{
+        db::status::mock::MockBridgeStatusDb,
+        types::{DepositStatus, ReimbursementStatus, WithdrawalInfo, WithdrawalStatusCursor},
+    };
+
+    fn query(status: Option<&str>, limit: Option<usize>) -> ArchiveQuery {
+        ArchiveQuery {
+            status: status.map(str::to_owned),
+            after: None,
+            limit,
+        }
+    }
+
+    #[test]
+    fn query_matches_status_names_case_insensitively() {
+        assert!(query(Some("complete"), None).matches(&DepositStatus::Complete));
+        assert!(query(Some("FAILED"), None).matches(&DepositStatus::Failed));
+        assert!(!query(Some("complete"), None).matches(&ReimbursementStatus::Slashed));
+        assert!(query(None, None).matches(&ReimbursementStatus::Aborted));
+    }
+
+    #[test]
+    fn seeds_withdrawals_behind_the_status_cursor() {
+        let withdrawal = |byte: u8, status| WithdrawalInfo {
+            withdrawal_request_txid: Buf32([byte; 32]),
+            fulfillment_txid: Some(Txid::from_byte_array([byte; 32])),
+            status,
+        };
+        let status_db = MockBridgeStatusDb::default();
+        let mut snapshot = status_db.get_status_snapshot().expect("status snapshot");
+        snapshot.withdrawals = vec![
+            (1, withdrawal(1, WithdrawalStatus::Complete)),
+            (2, withdrawal(2, WithdrawalStatus::InProgress)),
+            (3, withdrawal(3, WithdrawalStatus::Complete)),
+        ];
+        snapshot.cursors.withdrawal_status = WithdrawalStatusCursor {
+            next_deposit_idx: 3,
+        };
+
+        assert_eq!(
+            seed_withdrawal_archive(&status_db, &snapshot, 6).expect("seed archive"),
+            1
+        );
+        let page = status_db
+            .archived_withdrawals_after(None, 10, |_| true)
+            .expect("archive page");
+        assert_eq!(page.rows.len(), 1);
+        assert_eq!(page.rows[0].deposit_idx, 1);
+        assert_eq!(page.rows[0].confirmations, 6);
+
+        // Rows archived already are not seeded again.
+        assert_eq!(
+            seed_withdrawal_archive(&status_db, &snapshot, 6).expect("seed archive"),
+            0
+        );
+    }
+
+    #[test]
+    fn query_limit_is_defaulted_and_capped() {
+        assert_eq!(query(None, None).limit(), DEFAULT_PAGE_LIMIT);
+        assert_eq!(query(None, Some(10)).limit(), 10);
+        assert_eq!(query(None, Some(100_000)).limit(), MAX_PAGE_LIMIT);
+    }
+}
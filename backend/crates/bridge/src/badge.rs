@@ -0,0 +1,141 @@
+//! SVG badges of the bridge and its operators.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use axum::{extract::Path, http::StatusCode, response::Response};
+use status_utils::{badge_name, unix_time_s, Badge, BadgeColor};
+use strata_bridge_rpc::types::RpcOperatorStatus;
+use tracing::warn;
+
+use super::{
+    context::BridgeMonitoringContext, types::OperatorStatus, uptime::operator_uptime_pcts,
+};
+
+fn is_online(operator: &OperatorStatus) -> bool {
+    matches!(operator.status(), RpcOperatorStatus::Online)
+}
+
+/// Badge of the bridge from the statuses of its `configured` operators.
+fn bridge_badge(operators: &[OperatorStatus], configured: usize, uptime_pct: Option<f64>) -> Badge {
+    if operators.is_empty() {
+        return Badge::new("bridge", "unknown", BadgeColor::Grey);
+    }
+    let online = operators
+        .iter()
+        .filter(|operator| is_online(operator))
+        .count();
+    let color = if online == configured {
+        BadgeColor::Green
+    } else if online == 0 {
+        BadgeColor::Red
+    } else {
+        BadgeColor::Yellow
+    };
+    Badge::new("bridge", format!("{online}/{configured} online"), color).with_uptime(uptime_pct)
+}
+
+/// Badge of an operator, or of an operator not polled yet.
+fn operator_badge(name: &str, operator: Option<&OperatorStatus>, uptime_pct: Option<f64>) -> Badge {
+    let (message, color) = match operator {
+        Some(operator) if is_online(operator) => ("online", BadgeColor::Green),
+        Some(_) => ("offline", BadgeColor::Red),
+        None => ("unknown", BadgeColor::Grey),
+    };
+    Badge::new(name, message, color).with_uptime(uptime_pct)
+}
+
+/// Uptime of each operator keyed by hex-encoded public key, empty if the
+/// history cannot be read.
+fn operator_uptime(context: &BridgeMonitoringContext) -> BTreeMap<String, f64> {
+    operator_uptime_pcts(context.status_db(), unix_time_s()).unwrap_or_else(|e| {
+        warn!(error = %e, "could not read operator uptime");
+        BTreeMap::new()
+    })
+}
+
+/// Handler to get the badge of the bridge, `/badge/bridge.svg`
+pub async fn get_bridge_badge(context: Arc<BridgeMonitoringContext>) -> Response {
+    let operators = context.state().operators().await;
+    let configured = context.config().operators();
+    let uptime = operator_uptime(&context);
+    let uptimes: Vec<_> = configured
+        .iter()
+        .filter_map(|operator| uptime.get(&operator.public_key().to_string()).copied())
+        .collect();
+    let uptime_pct =
+        (!uptimes.is_empty()).then(|| uptimes.iter().sum::<f64>() / uptimes.len() as f64);
+
+    bridge_badge(&operators, configured.len(), uptime_pct)
+        .into_response(context.config().status_refetch_interval())
+}
+
+/// Handler to get the badge of an operator, e.g. `/badge/operator/{pk}.svg`
+pub async fn get_operator_badge(
+    context: Arc<BridgeMonitoringContext>,
+    Path(file_name): Path<String>,
+) -> Result<Response, StatusCode> {
+    let operator_pk = badge_name(&file_name).ok_or(StatusCode::NOT_FOUND)?;
+    let configured = context
+        .config()
+        .operators()
+        .iter()
+        .find(|operator| operator.public_key().to_string() == operator_pk)
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let operators = context.state().operators().await;
+    let operator = operators
+        .iter()
+        .find(|operator| operator.operator_pk() == configured.public_key());
+    let uptime_pct = operator_uptime(&context).get(operator_pk).copied();
+
+    Ok(operator_badge(configured.name(), operator, uptime_pct)
+        .into_response(context.config().status_refetch_interval()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bitcoin::PublicKey;
+
+    use super::*;
+
+    fn operator(status: RpcOperatorStatus) -> OperatorStatus {
+        let operator_pk = PublicKey::from_str(
+            "02157d08fa2eb6071cef0750bcf1c0c9e94c2f05f629cdbdb3982851dce9472cd0",
+        )
+        .expect("valid public key");
+        OperatorStatus::new("Alpen Labs #1".to_owned(), operator_pk, status)
+    }
+
+    #[test]
+    fn bridge_badge_counts_online_operators() {
+        let operators = [
+            operator(RpcOperatorStatus::Online),
+            operator(RpcOperatorStatus::Offline),
+        ];
+
+        let badge = bridge_badge(&operators, 2, Some(97.0));
+        assert_eq!(badge.message(), "1/2 online | 97.00%");
+        assert_eq!(badge.color(), BadgeColor::Yellow);
+
+        assert_eq!(
+            bridge_badge(&operators[..1], 1, None).color(),
+            BadgeColor::Green
+        );
+        assert_eq!(bridge_badge(&[], 2, None).message(), "unknown");
+    }
+
+    #[test]
+    fn operator_badge_follows_status() {
+        let offline = operator(RpcOperatorStatus::Offline);
+
+        let badge = operator_badge("Alpen Labs #1", Some(&offline), Some(100.0));
+        assert_eq!(badge.message(), "offline | 100.00%");
+        assert_eq!(badge.color(), BadgeColor::Red);
+        assert_eq!(
+            operator_badge("Alpen Labs #1", None, None).color(),
+            BadgeColor::Grey
+        );
+    }
+}
@@ -1,7 +1,11 @@
 use anyhow::{anyhow, Result};
 use bitcoin::PublicKey;
 use jsonrpsee::http_client::HttpClient;
-use std::{collections::BTreeMap, future::Future};
+use std::{
+    collections::BTreeMap,
+    future::Future,
+    sync::atomic::{AtomicU64, Ordering},
+};
 use strata_bridge_primitives::types::DepositIdx;
 use strata_bridge_rpc::traits::{StrataBridgeControlApiClient, StrataBridgeMonitoringApiClient};
 use strata_bridge_rpc::types::{
@@ -38,6 +42,9 @@ pub(crate) struct RpcClientManager {
     ///
     /// [`BTreeMap`] ensures deterministic ordering.
     clients: BTreeMap<String, HttpClient>,
+
+    /// Number of requests that failed on a client, after retries.
+    errors: AtomicU64,
 }
 
 impl RpcClientManager {
@@ -61,7 +68,10 @@ impl RpcClientManager {
             }
         }
 
-        Self { clients }
+        Self {
+            clients,
+            errors: AtomicU64::new(0),
+        }
     }
 
     /// Execute an async operation across all available clients with retry logic
@@ -129,6 +139,7 @@ impl RpcClientManager {
                     return Some(result);
                 }
                 Err(e) => {
+                    self.record_error();
                     warn!(
                         client_key = %client_key,
                         error = %e,
@@ -146,6 +157,16 @@ impl RpcClientManager {
     pub(crate) fn client(&self, client_key: &str) -> Option<&HttpClient> {
         self.clients.get(client_key)
     }
+
+    /// Number of requests that failed on a client since the manager was
+    /// created, counting each client of a failover once.
+    pub(crate) fn error_count(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
 }
 
 /// Fetch operator status.
@@ -175,6 +196,7 @@ pub(crate) async fn get_operator_status(
     match client.get_uptime().await {
         Ok(_) => RpcOperatorStatus::Online,
         Err(e) => {
+            rpc_manager.record_error();
             warn!(
                 operator_pk = %operator_pk_str,
                 error = %e,
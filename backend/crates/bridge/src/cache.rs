@@ -65,6 +65,14 @@ impl BridgeStatusCache {
     pub(crate) fn from_status_snapshot(snapshot: DbBridgeStatusSnapshot) -> Self {
         let mut cache = Self::default();
 
+        cache.update_operators(snapshot.operators);
+        cache.apply_deposit_updates(
+            snapshot
+                .deposits
+                .into_iter()
+                .map(|(deposit_idx, info)| (deposit_idx, info, None))
+                .collect(),
+        );
         cache.apply_withdrawal_updates(
             snapshot
                 .withdrawals
@@ -72,6 +80,13 @@ impl BridgeStatusCache {
                 .map(|(deposit_idx, info)| (deposit_idx, info, None))
                 .collect(),
         );
+        cache.apply_reimbursement_updates(
+            snapshot
+                .reimbursements
+                .into_iter()
+                .map(|(deposit_idx, info)| (deposit_idx, info, None))
+                .collect(),
+        );
         cache.update_withdrawal_pairings(
             &snapshot.withdrawal_pairings,
             snapshot.cursors.withdrawal_pairing,
@@ -238,6 +253,32 @@ impl BridgeStatusCache {
         }
     }
 
+    /// Deposit entry of a deposit index
+    pub(crate) fn deposit(&self, deposit_idx: DepositIdx) -> Option<&CacheEntry<DepositInfo>> {
+        self.deposits.get(&deposit_idx)
+    }
+
+    /// Withdrawal entry of a deposit index
+    pub(crate) fn withdrawal(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> Option<&CacheEntry<WithdrawalInfo>> {
+        self.withdrawals.get(&deposit_idx)
+    }
+
+    /// Reimbursement entry of a deposit index
+    pub(crate) fn reimbursement(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> Option<&CacheEntry<ReimbursementInfo>> {
+        self.reimbursements.get(&deposit_idx)
+    }
+
+    /// Withdrawal sequence paired with a deposit index, until the pairing is purged
+    pub(crate) fn withdrawal_pairing(&self, deposit_idx: DepositIdx) -> Option<WithdrawalSeq> {
+        self.withdrawal_pairing.pairings.get(&deposit_idx).copied()
+    }
+
     /// Filter deposits based on deposit index, row value, and confirmations.
     pub(crate) fn filter_deposits<F>(&self, filter: F) -> Vec<(DepositIdx, DepositInfo)>
     where
@@ -1,19 +1,28 @@
-use std::sync::{
-    atomic::{AtomicBool, Ordering},
-    Arc,
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
 };
 
 use tokio::sync::Notify;
 use tokio::time::Duration;
+use tracing::{info, warn};
 
 use super::{
+    archive::seed_withdrawal_archive,
     bridge_rpc::RpcClientManager,
     db::{traits::BridgeStatusDb, BridgeStatusDbSled, WithdrawalIndexerDbSled},
-    esplora::EsploraClient,
     state::BridgeMonitoringState,
     types::BridgeStatus,
 };
+use status_alerts::AlertEngine;
 use status_config::BridgeMonitoringConfig;
+use status_feed::StatusEventLog;
+use status_maintenance::MaintenanceSchedule;
+use status_utils::{ComponentHealth, EsploraClient};
+use strata_bridge_rpc::types::RpcOperatorStatus;
 
 /// Bridge monitoring task context.
 pub struct BridgeMonitoringContext {
@@ -22,9 +31,14 @@ pub struct BridgeMonitoringContext {
     esplora_client: EsploraClient,
     withdrawal_index: Arc<WithdrawalIndexerDbSled>,
     status_db: Arc<BridgeStatusDbSled>,
+    alerts: Arc<AlertEngine>,
+    maintenance: Arc<MaintenanceSchedule>,
+    events: Arc<StatusEventLog>,
     state: BridgeMonitoringState,
     status_available: AtomicBool,
     initial_status_query_complete: Notify,
+    /// Unix timestamp in seconds of the latest completed poll, `0` if none.
+    last_successful_poll_at: AtomicU64,
 }
 
 impl BridgeMonitoringContext {
@@ -32,6 +46,9 @@ impl BridgeMonitoringContext {
         config: BridgeMonitoringConfig,
         withdrawal_index: Arc<WithdrawalIndexerDbSled>,
         status_db: Arc<BridgeStatusDbSled>,
+        alerts: Arc<AlertEngine>,
+        maintenance: Arc<MaintenanceSchedule>,
+        events: Arc<StatusEventLog>,
     ) -> anyhow::Result<Self> {
         let bridge_rpc = RpcClientManager::new(&config);
         let esplora_client =
@@ -39,6 +56,13 @@ impl BridgeMonitoringContext {
         let snapshot = status_db
             .get_status_snapshot()
             .map_err(|e| anyhow::anyhow!("hydrate bridge status state: {e}"))?;
+        match seed_withdrawal_archive(status_db.as_ref(), &snapshot, config.max_tx_confirmations())
+        {
+            Ok(0) => {}
+            Ok(seeded) => info!(seeded, "seeded the withdrawal archive"),
+            Err(e) => warn!(error = %e, "failed to seed the withdrawal archive"),
+        }
+        let last_successful_poll_at = snapshot.last_successful_poll_at.unwrap_or_default();
         let state = BridgeMonitoringState::from_snapshot(snapshot);
 
         Ok(Self {
@@ -47,9 +71,13 @@ impl BridgeMonitoringContext {
             esplora_client,
             withdrawal_index,
             status_db,
+            alerts,
+            maintenance,
+            events,
             state,
             status_available: AtomicBool::new(false),
             initial_status_query_complete: Notify::new(),
+            last_successful_poll_at: AtomicU64::new(last_successful_poll_at),
         })
     }
 
@@ -65,6 +93,11 @@ impl BridgeMonitoringContext {
         &self.esplora_client
     }
 
+    /// Number of failed Esplora requests made by the bridge monitor.
+    pub fn esplora_error_count(&self) -> u64 {
+        self.esplora_client.error_count()
+    }
+
     pub(crate) fn withdrawal_index(&self) -> &WithdrawalIndexerDbSled {
         self.withdrawal_index.as_ref()
     }
@@ -73,11 +106,40 @@ impl BridgeMonitoringContext {
         self.status_db.as_ref()
     }
 
+    pub(crate) fn alerts(&self) -> &AlertEngine {
+        self.alerts.as_ref()
+    }
+
+    pub(crate) fn maintenance(&self) -> &MaintenanceSchedule {
+        self.maintenance.as_ref()
+    }
+
+    pub(crate) fn events(&self) -> &StatusEventLog {
+        self.events.as_ref()
+    }
+
     pub(crate) fn state(&self) -> &BridgeMonitoringState {
         &self.state
     }
 
-    pub(crate) fn mark_status_available(&self) {
+    /// Records a poll that reached Esplora and the bridge RPC, persisting its
+    /// time so the staleness of the hydrated status survives restarts.
+    pub(crate) fn record_successful_poll(&self, polled_at: u64) {
+        self.last_successful_poll_at
+            .store(polled_at, Ordering::Release);
+        if let Err(e) = self.status_db.put_last_successful_poll_at(polled_at) {
+            warn!(error = %e, "failed to persist last successful bridge poll");
+        }
+        self.mark_status_available();
+    }
+
+    /// Unix timestamp in seconds of the latest completed poll, if any, including
+    /// polls made before a restart.
+    pub(crate) fn last_successful_poll_at(&self) -> Option<u64> {
+        Some(self.last_successful_poll_at.load(Ordering::Acquire)).filter(|at| *at > 0)
+    }
+
+    fn mark_status_available(&self) {
         if self
             .status_available
             .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
@@ -107,6 +169,29 @@ impl BridgeMonitoringContext {
         notified.await;
     }
 
+    /// Health of each bridge operator keyed by hex-encoded public key, or
+    /// [`None`] until the first status has been collected.
+    pub async fn operator_health(&self) -> Option<BTreeMap<String, ComponentHealth>> {
+        if !self.status_available.load(Ordering::Acquire) {
+            return None;
+        }
+        Some(
+            self.state
+                .operators()
+                .await
+                .iter()
+                .map(|operator| {
+                    let health = if matches!(operator.status(), RpcOperatorStatus::Offline) {
+                        ComponentHealth::MajorOutage
+                    } else {
+                        ComponentHealth::Operational
+                    };
+                    (operator.operator_pk().to_string(), health)
+                })
+                .collect(),
+        )
+    }
+
     pub(crate) async fn bridge_status(&self) -> BridgeStatus {
         self.state
             .bridge_status(self.config.max_tx_confirmations())
@@ -116,13 +201,18 @@ impl BridgeMonitoringContext {
 
 #[cfg(test)]
 mod tests {
-    use bitcoin::Txid;
+    use axum::Json;
+    use bitcoin::{PublicKey, Txid};
+    use status_config::AlertsConfig;
+    use status_feed::StatusEventDbSled;
+    use status_maintenance::MaintenanceDbSled;
     use std::str::FromStr;
 
     use super::*;
     use crate::{
         state::DepositInfoUpdate,
-        types::{DepositInfo, DepositStatus},
+        status::get_bridge_status,
+        types::{DepositInfo, DepositStatus, OperatorStatus},
     };
 
     fn test_config() -> BridgeMonitoringConfig {
@@ -138,11 +228,38 @@ mod tests {
     }
 
     fn test_context() -> BridgeMonitoringContext {
+        test_context_with(Arc::new(
+            BridgeStatusDbSled::open_temporary().expect("open status db"),
+        ))
+    }
+
+    fn test_context_with(status_db: Arc<BridgeStatusDbSled>) -> BridgeMonitoringContext {
         let withdrawal_index =
             Arc::new(WithdrawalIndexerDbSled::open_temporary().expect("open db"));
-        let status_db = Arc::new(BridgeStatusDbSled::open_temporary().expect("open status db"));
-        BridgeMonitoringContext::new(test_config(), withdrawal_index, status_db)
-            .expect("create bridge monitoring context")
+        let maintenance = Arc::new(
+            MaintenanceSchedule::load(
+                &[],
+                Vec::new(),
+                MaintenanceDbSled::open_temporary().expect("open maintenance db"),
+            )
+            .expect("load maintenance schedule"),
+        );
+        let alerts =
+            Arc::new(AlertEngine::new(AlertsConfig::default(), Arc::clone(&maintenance)).0);
+        BridgeMonitoringContext::new(
+            test_config(),
+            withdrawal_index,
+            status_db,
+            alerts,
+            maintenance,
+            Arc::new(
+                StatusEventLog::load(
+                    StatusEventDbSled::open_temporary().expect("open status event db"),
+                )
+                .expect("load status events"),
+            ),
+        )
+        .expect("create bridge monitoring context")
     }
 
     #[tokio::test]
@@ -208,4 +325,50 @@ mod tests {
         .await
         .is_ok());
     }
+
+    #[tokio::test]
+    async fn serves_persisted_status_before_first_poll() {
+        let status_db = Arc::new(BridgeStatusDbSled::open_temporary().expect("open status db"));
+        let operator_pk = PublicKey::from_str(
+            "02157d08fa2eb6071cef0750bcf1c0c9e94c2f05f629cdbdb3982851dce9472cd0",
+        )
+        .expect("valid public key");
+        status_db
+            .put_operators(&[OperatorStatus::new(
+                "Alpen Labs #1".to_owned(),
+                operator_pk,
+                RpcOperatorStatus::Online,
+            )])
+            .expect("put operators");
+        status_db
+            .put_last_successful_poll_at(1_000)
+            .expect("put last successful poll");
+        let context = Arc::new(test_context_with(status_db));
+
+        assert_eq!(context.last_successful_poll_at(), Some(1_000));
+        assert!(context.operator_health().await.is_none());
+
+        let Json(status) = tokio::time::timeout(
+            Duration::from_millis(100),
+            get_bridge_status(Arc::clone(&context)),
+        )
+        .await
+        .expect("persisted status is served without waiting");
+        let status = serde_json::to_value(status).expect("serialize bridge status");
+        assert_eq!(status["operators"].as_array().map(Vec::len), Some(1));
+        assert_eq!(status["last_successful_poll_at"], 1_000);
+        assert_eq!(status["stale"], true);
+
+        context.record_successful_poll(2_000);
+        assert_eq!(context.last_successful_poll_at(), Some(2_000));
+        assert_eq!(
+            context
+                .status_db()
+                .get_status_snapshot()
+                .expect("load status snapshot")
+                .last_successful_poll_at,
+            Some(2_000)
+        );
+        assert!(context.operator_health().await.is_some());
+    }
 }
@@ -1,75 +1,153 @@
-use std::path::Path;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
 
-use anyhow::Context;
+use status_utils::db::open_sled_db;
 use strata_bridge_primitives::types::DepositIdx;
-use typed_sled::{SledDb, SledTree};
+use strata_primitives::buf::Buf32;
+use typed_sled::{codec::ValueCodec, Schema, SledDb, SledTree};
 
 use crate::{
     db::{
         error::{DbError, DbResult},
         traits::BridgeStatusDb,
-        types::{DbBridgeStatusSnapshot, StatusCursors},
+        types::{DbArchivePage, DbBridgeStatusSnapshot, DbOperatorUptimeBucket, StatusCursors},
     },
     types::{
-        ReimbursementStatusCursor, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor,
+        ArchivedRow, DepositInfo, OperatorStatus, ReimbursementInfo, ReimbursementStatusCursor,
+        TxRecord, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor, WithdrawalSeq,
         WithdrawalStatusCursor,
     },
 };
 
-use super::schema::{
-    DepositInfoCursorSchema, ReimbursementStatusCursorSchema, WithdrawalInfoSchema,
-    WithdrawalPairingCursorSchema, WithdrawalPairingSchema, WithdrawalStatusCursorSchema,
+use super::{
+    archive_page_start,
+    schema::{
+        DepositArchiveSchema, DepositInProgressSchema, DepositInfoCursorSchema, DepositInfoSchema,
+        LastSuccessfulPollSchema, OperatorStatusSchema, OperatorUptimeSchema,
+        ReimbursementArchiveSchema, ReimbursementInfoSchema, ReimbursementStatusCursorSchema,
+        TxRecordSchema, WithdrawalArchiveSchema, WithdrawalInProgressSchema, WithdrawalInfoSchema,
+        WithdrawalPairingArchiveSchema, WithdrawalPairingCursorSchema, WithdrawalPairingSchema,
+        WithdrawalStatusCursorSchema,
+    },
+    ARCHIVE_SCAN_LIMIT,
 };
 
 const CURSOR_CELL_KEY: u8 = 0;
+const STATUS_CELL_KEY: u8 = 0;
+
+/// Reads one archive page of `rows`, in deposit order from the page start.
+fn archive_page<T>(
+    rows: impl Iterator<Item = Result<(DepositIdx, ArchivedRow<T>), typed_sled::error::Error>>,
+    limit: usize,
+    filter: impl Fn(&T) -> bool,
+) -> DbResult<DbArchivePage<T>> {
+    super::archive_page(
+        rows.map(|entry| entry.map(|(_, row)| row).map_err(DbError::from)),
+        limit,
+        ARCHIVE_SCAN_LIMIT,
+        filter,
+    )
+}
+
+/// Keeps the start of each of `request_txids` in `tree`, starting new ones at
+/// `now`, forgets the rest, and returns the starts.
+fn track_in_progress<S>(
+    tree: &SledTree<S>,
+    request_txids: &BTreeSet<Buf32>,
+    now: u64,
+) -> DbResult<BTreeMap<Buf32, u64>>
+where
+    S: Schema<Key = Buf32, Value = u64>,
+    u64: ValueCodec<S, Decoded = u64>,
+{
+    let tracked = tree
+        .iter()
+        .map(|result| result.map_err(DbError::from))
+        .collect::<DbResult<BTreeMap<_, _>>>()?;
+    for request_txid in tracked.keys() {
+        if !request_txids.contains(request_txid) {
+            tree.remove(request_txid)?;
+        }
+    }
+
+    let mut since = BTreeMap::new();
+    for request_txid in request_txids {
+        let started_at = match tracked.get(request_txid) {
+            Some(started_at) => *started_at,
+            None => {
+                tree.insert(request_txid, &now)?;
+                now
+            }
+        };
+        since.insert(*request_txid, started_at);
+    }
+    Ok(since)
+}
 
 /// Sled-backed bridge-status database.
 #[derive(Debug)]
 pub struct BridgeStatusDbSled {
     _db: SledDb,
+    deposits: SledTree<DepositInfoSchema>,
     withdrawals: SledTree<WithdrawalInfoSchema>,
+    reimbursements: SledTree<ReimbursementInfoSchema>,
     withdrawal_pairings: SledTree<WithdrawalPairingSchema>,
+    deposit_archive: SledTree<DepositArchiveSchema>,
+    withdrawal_archive: SledTree<WithdrawalArchiveSchema>,
+    reimbursement_archive: SledTree<ReimbursementArchiveSchema>,
+    withdrawal_pairing_archive: SledTree<WithdrawalPairingArchiveSchema>,
+    tx_records: SledTree<TxRecordSchema>,
     deposit_info_cursor: SledTree<DepositInfoCursorSchema>,
     withdrawal_pairing_cursor: SledTree<WithdrawalPairingCursorSchema>,
     withdrawal_status_cursor: SledTree<WithdrawalStatusCursorSchema>,
     reimbursement_status_cursor: SledTree<ReimbursementStatusCursorSchema>,
+    operators: SledTree<OperatorStatusSchema>,
+    last_successful_poll: SledTree<LastSuccessfulPollSchema>,
+    operator_uptime: SledTree<OperatorUptimeSchema>,
+    withdrawals_in_progress: SledTree<WithdrawalInProgressSchema>,
+    deposits_in_progress: SledTree<DepositInProgressSchema>,
 }
 
 impl BridgeStatusDbSled {
     /// Open the status database under `{datadir}/status`.
     pub fn open(datadir: impl AsRef<Path>) -> anyhow::Result<Self> {
-        let path = datadir.as_ref().join("status");
-        std::fs::create_dir_all(&path)
-            .with_context(|| format!("create bridge status db dir {}", path.display()))?;
-        let sled_db = sled::open(&path)
-            .with_context(|| format!("open bridge status sled db at {}", path.display()))?;
-        // typed-sled codec errors are not Send + Sync, so anyhow::Context cannot preserve them.
-        Self::from_sled_db(sled_db)
-            .map_err(|e| anyhow::anyhow!("initialize bridge status trees: {e}"))
+        open_sled_db(
+            datadir.as_ref().join("status"),
+            "bridge status",
+            Self::from_sled_db,
+        )
     }
 
     /// Open a temporary in-memory-like sled database deleted on drop.
     #[cfg(test)]
     pub fn open_temporary() -> anyhow::Result<Self> {
-        let sled_db = sled::Config::new()
-            .temporary(true)
-            .open()
-            .context("open temporary bridge status sled db")?;
-        // typed-sled codec errors are not Send + Sync, so anyhow::Context cannot preserve them.
-        Self::from_sled_db(sled_db)
-            .map_err(|e| anyhow::anyhow!("initialize temporary bridge status trees: {e}"))
+        status_utils::db::open_temporary_sled_db("bridge status", Self::from_sled_db)
     }
 
     fn from_sled_db(sled_db: sled::Db) -> DbResult<Self> {
         let db = SledDb::new(sled_db)?;
 
         Ok(Self {
+            deposits: db.get_tree::<DepositInfoSchema>()?,
             withdrawals: db.get_tree::<WithdrawalInfoSchema>()?,
+            reimbursements: db.get_tree::<ReimbursementInfoSchema>()?,
             withdrawal_pairings: db.get_tree::<WithdrawalPairingSchema>()?,
+            deposit_archive: db.get_tree::<DepositArchiveSchema>()?,
+            withdrawal_archive: db.get_tree::<WithdrawalArchiveSchema>()?,
+            reimbursement_archive: db.get_tree::<ReimbursementArchiveSchema>()?,
+            withdrawal_pairing_archive: db.get_tree::<WithdrawalPairingArchiveSchema>()?,
+            tx_records: db.get_tree::<TxRecordSchema>()?,
             deposit_info_cursor: db.get_tree::<DepositInfoCursorSchema>()?,
             withdrawal_pairing_cursor: db.get_tree::<WithdrawalPairingCursorSchema>()?,
             withdrawal_status_cursor: db.get_tree::<WithdrawalStatusCursorSchema>()?,
             reimbursement_status_cursor: db.get_tree::<ReimbursementStatusCursorSchema>()?,
+            operators: db.get_tree::<OperatorStatusSchema>()?,
+            last_successful_poll: db.get_tree::<LastSuccessfulPollSchema>()?,
+            operator_uptime: db.get_tree::<OperatorUptimeSchema>()?,
+            withdrawals_in_progress: db.get_tree::<WithdrawalInProgressSchema>()?,
+            deposits_in_progress: db.get_tree::<DepositInProgressSchema>()?,
             _db: db,
         })
     }
@@ -99,11 +177,22 @@ impl BridgeStatusDbSled {
 impl BridgeStatusDb for BridgeStatusDbSled {
     fn get_status_snapshot(&self) -> DbResult<DbBridgeStatusSnapshot> {
         Ok(DbBridgeStatusSnapshot {
+            operators: self.operators.get(&STATUS_CELL_KEY)?.unwrap_or_default(),
+            deposits: self
+                .deposits
+                .iter()
+                .map(|result| result.map_err(DbError::from))
+                .collect::<DbResult<_>>()?,
             withdrawals: self
                 .withdrawals
                 .iter()
                 .map(|result| result.map_err(DbError::from))
                 .collect::<DbResult<_>>()?,
+            reimbursements: self
+                .reimbursements
+                .iter()
+                .map(|result| result.map_err(DbError::from))
+                .collect::<DbResult<_>>()?,
             withdrawal_pairings: self
                 .withdrawal_pairings
                 .iter()
@@ -116,9 +205,19 @@ impl BridgeStatusDb for BridgeStatusDbSled {
                 })
                 .collect::<DbResult<_>>()?,
             cursors: self.status_cursors()?,
+            last_successful_poll_at: self.last_successful_poll.get(&STATUS_CELL_KEY)?,
         })
     }
 
+    fn put_deposit_info(&self, deposit_idx: DepositIdx, info: &DepositInfo) -> DbResult<()> {
+        self.deposits.insert(&deposit_idx, info)?;
+        Ok(())
+    }
+
+    fn del_deposit_info(&self, deposit_idx: DepositIdx) -> DbResult<bool> {
+        Ok(self.deposits.take(&deposit_idx)?.is_some())
+    }
+
     fn put_withdrawal_info(&self, deposit_idx: DepositIdx, info: &WithdrawalInfo) -> DbResult<()> {
         self.withdrawals.insert(&deposit_idx, info)?;
         Ok(())
@@ -128,6 +227,19 @@ impl BridgeStatusDb for BridgeStatusDbSled {
         Ok(self.withdrawals.take(&deposit_idx)?.is_some())
     }
 
+    fn put_reimbursement_info(
+        &self,
+        deposit_idx: DepositIdx,
+        info: &ReimbursementInfo,
+    ) -> DbResult<()> {
+        self.reimbursements.insert(&deposit_idx, info)?;
+        Ok(())
+    }
+
+    fn del_reimbursement_info(&self, deposit_idx: DepositIdx) -> DbResult<bool> {
+        Ok(self.reimbursements.take(&deposit_idx)?.is_some())
+    }
+
     fn put_withdrawal_pairings(&self, pairings: &[WithdrawalPairing]) -> DbResult<()> {
         for pairing in pairings {
             self.withdrawal_pairings
@@ -141,21 +253,115 @@ impl BridgeStatusDb for BridgeStatusDbSled {
             return Ok(());
         }
 
-        let deposit_indices = self
+        let pairings = self
             .withdrawal_pairings
             .range(start..end)?
-            .map(|result| {
-                result
-                    .map(|(deposit_idx, _)| deposit_idx)
-                    .map_err(DbError::from)
-            })
+            .map(|result| result.map_err(DbError::from))
             .collect::<DbResult<Vec<_>>>()?;
-        for deposit_idx in deposit_indices {
+        for (deposit_idx, withdrawal_seq) in pairings {
+            self.withdrawal_pairing_archive
+                .insert(&deposit_idx, &withdrawal_seq)?;
             self.withdrawal_pairings.remove(&deposit_idx)?;
         }
         Ok(())
     }
 
+    fn get_withdrawal_seq(&self, deposit_idx: DepositIdx) -> DbResult<Option<WithdrawalSeq>> {
+        match self.withdrawal_pairings.get(&deposit_idx)? {
+            Some(withdrawal_seq) => Ok(Some(withdrawal_seq)),
+            None => Ok(self.withdrawal_pairing_archive.get(&deposit_idx)?),
+        }
+    }
+
+    fn put_archived_deposits(&self, rows: &[ArchivedRow<DepositInfo>]) -> DbResult<()> {
+        for row in rows {
+            self.deposit_archive.insert(&row.deposit_idx, row)?;
+        }
+        Ok(())
+    }
+
+    fn get_archived_deposit(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<DepositInfo>>> {
+        Ok(self.deposit_archive.get(&deposit_idx)?)
+    }
+
+    fn archived_deposits_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&DepositInfo) -> bool,
+    ) -> DbResult<DbArchivePage<DepositInfo>> {
+        let Some(start) = archive_page_start(after) else {
+            return Ok(DbArchivePage::default());
+        };
+        archive_page(self.deposit_archive.range(start..)?, limit, filter)
+    }
+
+    fn put_archived_withdrawals(&self, rows: &[ArchivedRow<WithdrawalInfo>]) -> DbResult<()> {
+        for row in rows {
+            self.withdrawal_archive.insert(&row.deposit_idx, row)?;
+        }
+        Ok(())
+    }
+
+    fn get_archived_withdrawal(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<WithdrawalInfo>>> {
+        Ok(self.withdrawal_archive.get(&deposit_idx)?)
+    }
+
+    fn archived_withdrawals_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&WithdrawalInfo) -> bool,
+    ) -> DbResult<DbArchivePage<WithdrawalInfo>> {
+        let Some(start) = archive_page_start(after) else {
+            return Ok(DbArchivePage::default());
+        };
+        archive_page(self.withdrawal_archive.range(start..)?, limit, filter)
+    }
+
+    fn put_archived_reimbursements(&self, rows: &[ArchivedRow<ReimbursementInfo>]) -> DbResult<()> {
+        for row in rows {
+            self.reimbursement_archive.insert(&row.deposit_idx, row)?;
+        }
+        Ok(())
+    }
+
+    fn get_archived_reimbursement(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<ReimbursementInfo>>> {
+        Ok(self.reimbursement_archive.get(&deposit_idx)?)
+    }
+
+    fn archived_reimbursements_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&ReimbursementInfo) -> bool,
+    ) -> DbResult<DbArchivePage<ReimbursementInfo>> {
+        let Some(start) = archive_page_start(after) else {
+            return Ok(DbArchivePage::default());
+        };
+        archive_page(self.reimbursement_archive.range(start..)?, limit, filter)
+    }
+
+    fn put_tx_records(&self, records: &[(Buf32, TxRecord)]) -> DbResult<()> {
+        for (txid, record) in records {
+            self.tx_records.insert(txid, record)?;
+        }
+        Ok(())
+    }
+
+    fn get_tx_record(&self, txid: &Buf32) -> DbResult<Option<TxRecord>> {
+        Ok(self.tx_records.get(txid)?)
+    }
+
     fn put_deposit_info_cursor(&self, cursor: DepositIdx) -> DbResult<()> {
         self.deposit_info_cursor.insert(&CURSOR_CELL_KEY, &cursor)?;
         Ok(())
@@ -178,6 +384,69 @@ impl BridgeStatusDb for BridgeStatusDbSled {
             .insert(&CURSOR_CELL_KEY, &cursor)?;
         Ok(())
     }
+
+    fn put_operators(&self, operators: &[OperatorStatus]) -> DbResult<()> {
+        self.operators
+            .insert(&STATUS_CELL_KEY, &operators.to_vec())?;
+        Ok(())
+    }
+
+    fn put_last_successful_poll_at(&self, polled_at: u64) -> DbResult<()> {
+        self.last_successful_poll
+            .insert(&STATUS_CELL_KEY, &polled_at)?;
+        Ok(())
+    }
+
+    fn record_operator_uptime(
+        &self,
+        bucket_start: u64,
+        operators: &[OperatorStatus],
+    ) -> DbResult<()> {
+        // The monitoring task is the only writer, so the bucket read-modify-write
+        // does not race.
+        let mut bucket = self.operator_uptime.get(&bucket_start)?.unwrap_or_default();
+        bucket.record(operators);
+        self.operator_uptime.insert(&bucket_start, &bucket)?;
+        Ok(())
+    }
+
+    fn get_operator_uptime_since(
+        &self,
+        since: u64,
+    ) -> DbResult<Vec<(u64, DbOperatorUptimeBucket)>> {
+        self.operator_uptime
+            .range(since..)?
+            .map(|result| result.map_err(DbError::from))
+            .collect()
+    }
+
+    fn prune_operator_uptime_before(&self, cutoff: u64) -> DbResult<()> {
+        let keys = self
+            .operator_uptime
+            .range(..cutoff)?
+            .map(|result| result.map(|(key, _)| key).map_err(DbError::from))
+            .collect::<DbResult<Vec<_>>>()?;
+        for key in keys {
+            self.operator_uptime.remove(&key)?;
+        }
+        Ok(())
+    }
+
+    fn track_withdrawals_in_progress(
+        &self,
+        request_txids: &BTreeSet<Buf32>,
+        now: u64,
+    ) -> DbResult<BTreeMap<Buf32, u64>> {
+        track_in_progress(&self.withdrawals_in_progress, request_txids, now)
+    }
+
+    fn track_deposits_in_progress(
+        &self,
+        request_txids: &BTreeSet<Buf32>,
+        now: u64,
+    ) -> DbResult<BTreeMap<Buf32, u64>> {
+        track_in_progress(&self.deposits_in_progress, request_txids, now)
+    }
 }
 
 #[cfg(test)]
@@ -185,16 +454,21 @@ mod tests {
     use std::{
         fs,
         path::PathBuf,
+        str::FromStr,
         time::{SystemTime, UNIX_EPOCH},
     };
 
-    use bitcoin::{hashes::Hash, Txid};
+    use bitcoin::{hashes::Hash, PublicKey, Txid};
+    use strata_bridge_rpc::types::RpcOperatorStatus;
     use strata_primitives::buf::Buf32;
 
     use super::*;
     use crate::{
         db::status::mock::MockBridgeStatusDb,
-        types::{WithdrawalInfo, WithdrawalPairing, WithdrawalStatus},
+        types::{
+            ArchivedRow, ChallengeStep, DepositStatus, ReimbursementStatus, WithdrawalInfo,
+            WithdrawalPairing, WithdrawalStatus,
+        },
     };
 
     fn txid(byte: u8) -> Txid {
@@ -228,11 +502,23 @@ mod tests {
         WithdrawalPairing::new(deposit_idx, withdrawal_seq)
     }
 
+    fn operator(name: &str) -> OperatorStatus {
+        let operator_pk = PublicKey::from_str(
+            "02157d08fa2eb6071cef0750bcf1c0c9e94c2f05f629cdbdb3982851dce9472cd0",
+        )
+        .expect("valid public key");
+        OperatorStatus::new(name.to_owned(), operator_pk, RpcOperatorStatus::Online)
+    }
+
     fn assert_empty_snapshot(db: &impl BridgeStatusDb) {
         let snapshot = db.get_status_snapshot().expect("snapshot");
+        assert!(snapshot.deposits.is_empty());
         assert!(snapshot.withdrawals.is_empty());
+        assert!(snapshot.reimbursements.is_empty());
         assert!(snapshot.withdrawal_pairings.is_empty());
         assert_eq!(snapshot.cursors, StatusCursors::default());
+        assert!(snapshot.operators.is_empty());
+        assert_eq!(snapshot.last_successful_poll_at, None);
     }
 
     fn assert_roundtrip(db: &impl BridgeStatusDb) {
@@ -251,8 +537,27 @@ mod tests {
             },
         };
 
+        db.put_deposit_info(
+            2,
+            &DepositInfo {
+                deposit_request_txid: txid(1),
+                deposit_txid: None,
+                status: DepositStatus::InProgress,
+            },
+        )
+        .expect("put deposit");
         db.put_withdrawal_info(2, &withdrawal)
             .expect("put withdrawal");
+        db.put_reimbursement_info(
+            2,
+            &ReimbursementInfo {
+                claim_txid: txid(5),
+                challenge_step: ChallengeStep::Claimed,
+                payout_txid: None,
+                status: ReimbursementStatus::InProgress,
+            },
+        )
+        .expect("put reimbursement");
         db.put_withdrawal_pairings(&[pairing(1, 7), pairing(2, 8)])
             .expect("put pairings");
         db.put_deposit_info_cursor(cursors.deposit_info)
@@ -263,8 +568,18 @@ mod tests {
             .expect("put withdrawal cursor");
         db.put_reimbursement_status_cursor(cursors.reimbursement_status)
             .expect("put reimbursement cursor");
+        db.put_operators(&[operator("Operator #1"), operator("Operator #2")])
+            .expect("put operators");
+        db.put_operators(&[operator("Operator #3")])
+            .expect("replace operators");
+        db.put_last_successful_poll_at(16)
+            .expect("put last successful poll");
 
         let snapshot = db.get_status_snapshot().expect("snapshot");
+        assert_eq!(snapshot.deposits.len(), 1);
+        assert_eq!(snapshot.deposits[0].1.deposit_request_txid, txid(1));
+        assert_eq!(snapshot.reimbursements.len(), 1);
+        assert_eq!(snapshot.reimbursements[0].1.claim_txid, txid(5));
         assert_eq!(snapshot.withdrawals.len(), 1);
         assert_eq!(snapshot.withdrawals[0].0, 2);
         assert_eq!(
@@ -276,16 +591,39 @@ mod tests {
             vec![pairing(1, 7), pairing(2, 8)]
         );
         assert_eq!(snapshot.cursors, cursors);
+        assert_eq!(
+            snapshot
+                .operators
+                .iter()
+                .map(OperatorStatus::operator_id)
+                .collect::<Vec<_>>(),
+            vec!["Operator #3"]
+        );
+        assert_eq!(snapshot.last_successful_poll_at, Some(16));
 
         db.del_withdrawal_pairings_range(0, 2)
             .expect("del pairings range");
         assert!(db.del_withdrawal_info(2).expect("del withdrawal"));
         assert!(!db.del_withdrawal_info(2).expect("del missing withdrawal"));
+        assert!(db.del_deposit_info(2).expect("del deposit"));
+        assert!(db.del_reimbursement_info(2).expect("del reimbursement"));
 
         let snapshot = db.get_status_snapshot().expect("snapshot after deletes");
+        assert!(snapshot.deposits.is_empty());
+        assert!(snapshot.reimbursements.is_empty());
         assert!(snapshot.withdrawals.is_empty());
         assert_eq!(snapshot.withdrawal_pairings, vec![pairing(2, 8)]);
         assert_eq!(snapshot.cursors, cursors);
+        assert_eq!(
+            db.get_withdrawal_seq(1)
+                .expect("get archived withdrawal seq"),
+            Some(7)
+        );
+        assert_eq!(
+            db.get_withdrawal_seq(2).expect("get withdrawal seq"),
+            Some(8)
+        );
+        assert_eq!(db.get_withdrawal_seq(3).expect("get missing seq"), None);
     }
 
     fn assert_pairing_range_delete(db: &impl BridgeStatusDb) {
@@ -318,6 +656,153 @@ mod tests {
         );
     }
 
+    fn assert_archive_pages(db: &impl BridgeStatusDb) {
+        let rows = [1, 2, 4, 5].map(|deposit_idx| ArchivedRow {
+            deposit_idx,
+            info: withdrawal_info(deposit_idx as u8, WithdrawalStatus::Complete),
+            confirmations: 6,
+        });
+        db.put_archived_withdrawals(&rows)
+            .expect("put archived withdrawals");
+
+        let page_indices = |after, limit, filter: fn(&WithdrawalInfo) -> bool| {
+            db.archived_withdrawals_after(after, limit, filter)
+                .expect("archive page")
+                .rows
+                .into_iter()
+                .map(|row| row.deposit_idx)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(page_indices(None, 3, |_| true), vec![1, 2, 4]);
+        assert_eq!(page_indices(Some(2), 3, |_| true), vec![4, 5]);
+        assert_eq!(
+            page_indices(None, 3, |info| info.withdrawal_request_txid.0[0] % 2 == 0),
+            vec![2, 4]
+        );
+        assert!(page_indices(Some(DepositIdx::MAX), 3, |_| true).is_empty());
+        assert!(page_indices(None, 0, |_| true).is_empty());
+        assert_eq!(
+            db.archived_withdrawals_after(Some(2), 3, |_| true)
+                .expect("archive page")
+                .next_after,
+            None
+        );
+        assert_eq!(
+            db.archived_withdrawals_after(None, 2, |_| true)
+                .expect("archive page")
+                .next_after,
+            Some(2)
+        );
+
+        assert_eq!(
+            db.get_archived_withdrawal(4)
+                .expect("get archived withdrawal")
+                .map(|row| row.info.withdrawal_request_txid),
+            Some(Buf32([4; 32]))
+        );
+        assert!(db
+            .get_archived_deposit(4)
+            .expect("get archived deposit")
+            .is_none());
+    }
+
+    fn assert_operator_uptime(db: &impl BridgeStatusDb) {
+        let offline = OperatorStatus::new(
+            "Operator #2".to_owned(),
+            *operator("Operator #1").operator_pk(),
+            RpcOperatorStatus::Offline,
+        );
+        db.record_operator_uptime(0, &[operator("Operator #1")])
+            .expect("record uptime");
+        db.record_operator_uptime(0, &[offline])
+            .expect("record uptime");
+        db.record_operator_uptime(3_600, &[operator("Operator #1")])
+            .expect("record uptime");
+
+        let buckets = db.get_operator_uptime_since(0).expect("uptime buckets");
+        assert_eq!(
+            buckets.iter().map(|(start, _)| *start).collect::<Vec<_>>(),
+            vec![0, 3_600]
+        );
+        let counts = buckets[0].1.operators.values().next().expect("counts");
+        assert_eq!((counts.online, counts.offline), (1, 1));
+
+        db.prune_operator_uptime_before(3_600)
+            .expect("prune uptime");
+        assert_eq!(
+            db.get_operator_uptime_since(0)
+                .expect("uptime buckets")
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn status_db_operator_uptime_sled() {
+        let db = BridgeStatusDbSled::open_temporary().expect("open db");
+        assert_operator_uptime(&db);
+    }
+
+    #[test]
+    fn status_db_operator_uptime_mock() {
+        assert_operator_uptime(&MockBridgeStatusDb::default());
+    }
+
+    fn assert_withdrawals_in_progress(db: &impl BridgeStatusDb) {
+        let first = Buf32([1; 32]);
+        let second = Buf32([2; 32]);
+
+        let since = db
+            .track_withdrawals_in_progress(&BTreeSet::from([first]), 100)
+            .expect("track withdrawals");
+        assert_eq!(since, BTreeMap::from([(first, 100)]));
+
+        let since = db
+            .track_withdrawals_in_progress(&BTreeSet::from([first, second]), 200)
+            .expect("track withdrawals");
+        assert_eq!(since, BTreeMap::from([(first, 100), (second, 200)]));
+
+        // Withdrawals that leave and re-enter progress start over.
+        db.track_withdrawals_in_progress(&BTreeSet::from([second]), 300)
+            .expect("track withdrawals");
+        let since = db
+            .track_withdrawals_in_progress(&BTreeSet::from([first, second]), 400)
+            .expect("track withdrawals");
+        assert_eq!(since, BTreeMap::from([(first, 400), (second, 200)]));
+
+        // Deposits are tracked apart from withdrawals.
+        let since = db
+            .track_deposits_in_progress(&BTreeSet::from([first]), 500)
+            .expect("track deposits");
+        assert_eq!(since, BTreeMap::from([(first, 500)]));
+        let since = db
+            .track_deposits_in_progress(&BTreeSet::from([first]), 600)
+            .expect("track deposits");
+        assert_eq!(since, BTreeMap::from([(first, 500)]));
+    }
+
+    #[test]
+    fn status_db_withdrawals_in_progress_sled() {
+        let db = BridgeStatusDbSled::open_temporary().expect("open db");
+        assert_withdrawals_in_progress(&db);
+    }
+
+    #[test]
+    fn status_db_withdrawals_in_progress_mock() {
+        assert_withdrawals_in_progress(&MockBridgeStatusDb::default());
+    }
+
+    #[test]
+    fn status_db_archive_pages_sled() {
+        let db = BridgeStatusDbSled::open_temporary().expect("open db");
+        assert_archive_pages(&db);
+    }
+
+    #[test]
+    fn status_db_archive_pages_mock() {
+        assert_archive_pages(&MockBridgeStatusDb::default());
+    }
+
     #[test]
     fn status_db_empty_snapshot_sled() {
         let db = BridgeStatusDbSled::open_temporary().expect("open db");
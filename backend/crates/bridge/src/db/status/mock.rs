@@ -1,34 +1,95 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::RwLock;
 
 use strata_bridge_primitives::types::DepositIdx;
+use strata_primitives::buf::Buf32;
 
 use crate::{
     db::{
         error::DbResult,
+        status::{archive_page_start, ARCHIVE_SCAN_LIMIT},
         traits::BridgeStatusDb,
-        types::{DbBridgeStatusSnapshot, StatusCursors},
+        types::{DbArchivePage, DbBridgeStatusSnapshot, DbOperatorUptimeBucket, StatusCursors},
     },
     types::{
-        ReimbursementStatusCursor, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor,
-        WithdrawalSeq, WithdrawalStatusCursor,
+        ArchivedRow, DepositInfo, OperatorStatus, ReimbursementInfo, ReimbursementStatusCursor,
+        TxRecord, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor, WithdrawalSeq,
+        WithdrawalStatusCursor,
     },
 };
 
 /// In-memory bridge-status database for tests.
 #[derive(Debug, Default)]
 pub(crate) struct MockBridgeStatusDb {
+    deposits: RwLock<BTreeMap<DepositIdx, DepositInfo>>,
     withdrawals: RwLock<BTreeMap<DepositIdx, WithdrawalInfo>>,
+    reimbursements: RwLock<BTreeMap<DepositIdx, ReimbursementInfo>>,
     withdrawal_pairings: RwLock<BTreeMap<DepositIdx, WithdrawalSeq>>,
+    deposit_archive: RwLock<BTreeMap<DepositIdx, ArchivedRow<DepositInfo>>>,
+    withdrawal_archive: RwLock<BTreeMap<DepositIdx, ArchivedRow<WithdrawalInfo>>>,
+    reimbursement_archive: RwLock<BTreeMap<DepositIdx, ArchivedRow<ReimbursementInfo>>>,
+    withdrawal_pairing_archive: RwLock<BTreeMap<DepositIdx, WithdrawalSeq>>,
+    tx_records: RwLock<BTreeMap<Buf32, TxRecord>>,
     deposit_info_cursor: RwLock<DepositIdx>,
     withdrawal_pairing_cursor: RwLock<WithdrawalPairingCursor>,
     withdrawal_status_cursor: RwLock<WithdrawalStatusCursor>,
     reimbursement_status_cursor: RwLock<ReimbursementStatusCursor>,
+    operators: RwLock<Vec<OperatorStatus>>,
+    last_successful_poll_at: RwLock<Option<u64>>,
+    operator_uptime: RwLock<BTreeMap<u64, DbOperatorUptimeBucket>>,
+    withdrawals_in_progress: RwLock<BTreeMap<Buf32, u64>>,
+    deposits_in_progress: RwLock<BTreeMap<Buf32, u64>>,
+}
+
+/// Keeps the start of each of `request_txids` in `tracked`, starting new ones
+/// at `now`, and forgets the rest.
+fn track_in_progress(
+    tracked: &RwLock<BTreeMap<Buf32, u64>>,
+    request_txids: &BTreeSet<Buf32>,
+    now: u64,
+) -> BTreeMap<Buf32, u64> {
+    let mut tracked = tracked.write().expect("mock in-progress lock poisoned");
+    tracked.retain(|request_txid, _| request_txids.contains(request_txid));
+    for request_txid in request_txids {
+        tracked.entry(*request_txid).or_insert(now);
+    }
+    tracked.clone()
+}
+
+fn archive_page<T: Copy>(
+    archive: &RwLock<BTreeMap<DepositIdx, ArchivedRow<T>>>,
+    after: Option<DepositIdx>,
+    limit: usize,
+    filter: impl Fn(&T) -> bool,
+) -> DbResult<DbArchivePage<T>> {
+    let start = archive_page_start(after);
+    let archive = archive.read().expect("mock archive lock poisoned");
+    super::archive_page(
+        start
+            .into_iter()
+            .flat_map(|start| archive.range(start..))
+            .map(|(_, row)| Ok(*row)),
+        limit,
+        ARCHIVE_SCAN_LIMIT,
+        filter,
+    )
 }
 
 impl BridgeStatusDb for MockBridgeStatusDb {
     fn get_status_snapshot(&self) -> DbResult<DbBridgeStatusSnapshot> {
         Ok(DbBridgeStatusSnapshot {
+            operators: self
+                .operators
+                .read()
+                .expect("mock operators lock poisoned")
+                .clone(),
+            deposits: self
+                .deposits
+                .read()
+                .expect("mock deposits lock poisoned")
+                .iter()
+                .map(|(deposit_idx, info)| (*deposit_idx, *info))
+                .collect(),
             withdrawals: self
                 .withdrawals
                 .read()
@@ -36,6 +97,13 @@ impl BridgeStatusDb for MockBridgeStatusDb {
                 .iter()
                 .map(|(deposit_idx, info)| (*deposit_idx, *info))
                 .collect(),
+            reimbursements: self
+                .reimbursements
+                .read()
+                .expect("mock reimbursements lock poisoned")
+                .iter()
+                .map(|(deposit_idx, info)| (*deposit_idx, *info))
+                .collect(),
             withdrawal_pairings: self
                 .withdrawal_pairings
                 .read()
@@ -63,9 +131,51 @@ impl BridgeStatusDb for MockBridgeStatusDb {
                     .read()
                     .expect("mock reimbursement_status_cursor lock poisoned"),
             },
+            last_successful_poll_at: *self
+                .last_successful_poll_at
+                .read()
+                .expect("mock last_successful_poll_at lock poisoned"),
         })
     }
 
+    fn put_deposit_info(&self, deposit_idx: DepositIdx, info: &DepositInfo) -> DbResult<()> {
+        self.deposits
+            .write()
+            .expect("mock deposits lock poisoned")
+            .insert(deposit_idx, *info);
+        Ok(())
+    }
+
+    fn del_deposit_info(&self, deposit_idx: DepositIdx) -> DbResult<bool> {
+        Ok(self
+            .deposits
+            .write()
+            .expect("mock deposits lock poisoned")
+            .remove(&deposit_idx)
+            .is_some())
+    }
+
+    fn put_reimbursement_info(
+        &self,
+        deposit_idx: DepositIdx,
+        info: &ReimbursementInfo,
+    ) -> DbResult<()> {
+        self.reimbursements
+            .write()
+            .expect("mock reimbursements lock poisoned")
+            .insert(deposit_idx, *info);
+        Ok(())
+    }
+
+    fn del_reimbursement_info(&self, deposit_idx: DepositIdx) -> DbResult<bool> {
+        Ok(self
+            .reimbursements
+            .write()
+            .expect("mock reimbursements lock poisoned")
+            .remove(&deposit_idx)
+            .is_some())
+    }
+
     fn put_withdrawal_info(&self, deposit_idx: DepositIdx, info: &WithdrawalInfo) -> DbResult<()> {
         self.withdrawals
             .write()
@@ -108,12 +218,138 @@ impl BridgeStatusDb for MockBridgeStatusDb {
             .range(start..end)
             .map(|(deposit_idx, _)| *deposit_idx)
             .collect::<Vec<_>>();
+        let mut archive = self
+            .withdrawal_pairing_archive
+            .write()
+            .expect("mock withdrawal_pairing_archive lock poisoned");
         for deposit_idx in deposit_indices {
-            withdrawal_pairings.remove(&deposit_idx);
+            if let Some(withdrawal_seq) = withdrawal_pairings.remove(&deposit_idx) {
+                archive.insert(deposit_idx, withdrawal_seq);
+            }
         }
         Ok(())
     }
 
+    fn get_withdrawal_seq(&self, deposit_idx: DepositIdx) -> DbResult<Option<WithdrawalSeq>> {
+        let withdrawal_seq = self
+            .withdrawal_pairings
+            .read()
+            .expect("mock withdrawal_pairings lock poisoned")
+            .get(&deposit_idx)
+            .copied();
+        Ok(withdrawal_seq.or_else(|| {
+            self.withdrawal_pairing_archive
+                .read()
+                .expect("mock withdrawal_pairing_archive lock poisoned")
+                .get(&deposit_idx)
+                .copied()
+        }))
+    }
+
+    fn put_archived_deposits(&self, rows: &[ArchivedRow<DepositInfo>]) -> DbResult<()> {
+        self.deposit_archive
+            .write()
+            .expect("mock deposit_archive lock poisoned")
+            .extend(rows.iter().map(|row| (row.deposit_idx, *row)));
+        Ok(())
+    }
+
+    fn get_archived_deposit(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<DepositInfo>>> {
+        Ok(self
+            .deposit_archive
+            .read()
+            .expect("mock deposit_archive lock poisoned")
+            .get(&deposit_idx)
+            .copied())
+    }
+
+    fn archived_deposits_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&DepositInfo) -> bool,
+    ) -> DbResult<DbArchivePage<DepositInfo>> {
+        archive_page(&self.deposit_archive, after, limit, filter)
+    }
+
+    fn put_archived_withdrawals(&self, rows: &[ArchivedRow<WithdrawalInfo>]) -> DbResult<()> {
+        self.withdrawal_archive
+            .write()
+            .expect("mock withdrawal_archive lock poisoned")
+            .extend(rows.iter().map(|row| (row.deposit_idx, *row)));
+        Ok(())
+    }
+
+    fn get_archived_withdrawal(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<WithdrawalInfo>>> {
+        Ok(self
+            .withdrawal_archive
+            .read()
+            .expect("mock withdrawal_archive lock poisoned")
+            .get(&deposit_idx)
+            .copied())
+    }
+
+    fn archived_withdrawals_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&WithdrawalInfo) -> bool,
+    ) -> DbResult<DbArchivePage<WithdrawalInfo>> {
+        archive_page(&self.withdrawal_archive, after, limit, filter)
+    }
+
+    fn put_archived_reimbursements(&self, rows: &[ArchivedRow<ReimbursementInfo>]) -> DbResult<()> {
+        self.reimbursement_archive
+            .write()
+            .expect("mock reimbursement_archive lock poisoned")
+            .extend(rows.iter().map(|row| (row.deposit_idx, *row)));
+        Ok(())
+    }
+
+    fn get_archived_reimbursement(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<ReimbursementInfo>>> {
+        Ok(self
+            .reimbursement_archive
+            .read()
+            .expect("mock reimbursement_archive lock poisoned")
+            .get(&deposit_idx)
+            .copied())
+    }
+
+    fn archived_reimbursements_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&ReimbursementInfo) -> bool,
+    ) -> DbResult<DbArchivePage<ReimbursementInfo>> {
+        archive_page(&self.reimbursement_archive, after, limit, filter)
+    }
+
+    fn put_tx_records(&self, records: &[(Buf32, TxRecord)]) -> DbResult<()> {
+        self.tx_records
+            .write()
+            .expect("mock tx_records lock poisoned")
+            .extend(records.iter().copied());
+        Ok(())
+    }
+
+    fn get_tx_record(&self, txid: &Buf32) -> DbResult<Option<TxRecord>> {
+        Ok(self
+            .tx_records
+            .read()
+            .expect("mock tx_records lock poisoned")
+            .get(txid)
+            .copied())
+    }
+
     fn put_deposit_info_cursor(&self, cursor: DepositIdx) -> DbResult<()> {
         *self
             .deposit_info_cursor
@@ -145,4 +381,79 @@ impl BridgeStatusDb for MockBridgeStatusDb {
             .expect("mock reimbursement_status_cursor lock poisoned") = cursor;
         Ok(())
     }
+
+    fn put_operators(&self, operators: &[OperatorStatus]) -> DbResult<()> {
+        *self
+            .operators
+            .write()
+            .expect("mock operators lock poisoned") = operators.to_vec();
+        Ok(())
+    }
+
+    fn put_last_successful_poll_at(&self, polled_at: u64) -> DbResult<()> {
+        *self
+            .last_successful_poll_at
+            .write()
+            .expect("mock last_successful_poll_at lock poisoned") = Some(polled_at);
+        Ok(())
+    }
+
+    fn record_operator_uptime(
+        &self,
+        bucket_start: u64,
+        operators: &[OperatorStatus],
+    ) -> DbResult<()> {
+        self.operator_uptime
+            .write()
+            .expect("mock operator_uptime lock poisoned")
+            .entry(bucket_start)
+            .or_default()
+            .record(operators);
+        Ok(())
+    }
+
+    fn get_operator_uptime_since(
+        &self,
+        since: u64,
+    ) -> DbResult<Vec<(u64, DbOperatorUptimeBucket)>> {
+        Ok(self
+            .operator_uptime
+            .read()
+            .expect("mock operator_uptime lock poisoned")
+            .range(since..)
+            .map(|(start, bucket)| (*start, bucket.clone()))
+            .collect())
+    }
+
+    fn prune_operator_uptime_before(&self, cutoff: u64) -> DbResult<()> {
+        self.operator_uptime
+            .write()
+            .expect("mock operator_uptime lock poisoned")
+            .retain(|start, _| *start >= cutoff);
+        Ok(())
+    }
+
+    fn track_withdrawals_in_progress(
+        &self,
+        request_txids: &BTreeSet<Buf32>,
+        now: u64,
+    ) -> DbResult<BTreeMap<Buf32, u64>> {
+        Ok(track_in_progress(
+            &self.withdrawals_in_progress,
+            request_txids,
+            now,
+        ))
+    }
+
+    fn track_deposits_in_progress(
+        &self,
+        request_txids: &BTreeSet<Buf32>,
+        now: u64,
+    ) -> DbResult<BTreeMap<Buf32, u64>> {
+        Ok(track_in_progress(
+            &self.deposits_in_progress,
+            request_txids,
+            now,
+        ))
+    }
 }
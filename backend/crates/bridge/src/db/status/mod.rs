@@ -5,3 +5,86 @@ pub(crate) mod schema;
 
 #[cfg(test)]
 pub(crate) mod mock;
+
+use strata_bridge_primitives::types::DepositIdx;
+
+use crate::{db::types::DbArchivePage, types::ArchivedRow};
+
+/// Most archived rows read for one page, so a selective filter cannot make a
+/// request scan the whole archive.
+pub(crate) const ARCHIVE_SCAN_LIMIT: usize = 5_000;
+
+/// First deposit index of an archive page following `after`, or `None` when
+/// no index follows it.
+pub(crate) fn archive_page_start(after: Option<DepositIdx>) -> Option<DepositIdx> {
+    after.map_or(Some(0), |after| after.checked_add(1))
+}
+
+/// Collects up to `limit` of `rows` matching `filter`, reading at most
+/// `scan_limit` rows.
+pub(crate) fn archive_page<T, E>(
+    rows: impl Iterator<Item = Result<ArchivedRow<T>, E>>,
+    limit: usize,
+    scan_limit: usize,
+    filter: impl Fn(&T) -> bool,
+) -> Result<DbArchivePage<T>, E> {
+    let mut page = DbArchivePage::default();
+    if limit == 0 {
+        return Ok(page);
+    }
+
+    for (scanned, row) in rows.enumerate() {
+        let row = row?;
+        let deposit_idx = row.deposit_idx;
+        if filter(&row.info) {
+            page.rows.push(row);
+        }
+        if page.rows.len() >= limit || scanned + 1 >= scan_limit {
+            page.next_after = Some(deposit_idx);
+            break;
+        }
+    }
+    Ok(page)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    fn rows(
+        indices: &[DepositIdx],
+    ) -> impl Iterator<Item = Result<ArchivedRow<u32>, Infallible>> + '_ {
+        indices.iter().map(|deposit_idx| {
+            Ok(ArchivedRow {
+                deposit_idx: *deposit_idx,
+                info: *deposit_idx,
+                confirmations: 6,
+            })
+        })
+    }
+
+    #[test]
+    fn archive_page_stops_at_the_scan_limit() {
+        let page =
+            archive_page(rows(&[1, 2, 3, 4, 5]), 10, 3, |info| *info > 4).expect("archive page");
+        assert!(page.rows.is_empty());
+        assert_eq!(page.next_after, Some(3));
+
+        let page = archive_page(rows(&[1, 2, 3, 4, 5]), 2, 10, |info| info % 2 == 1)
+            .expect("archive page");
+        assert_eq!(
+            page.rows
+                .iter()
+                .map(|row| row.deposit_idx)
+                .collect::<Vec<_>>(),
+            vec![1, 3]
+        );
+        assert_eq!(page.next_after, Some(3));
+
+        let page = archive_page(rows(&[1, 2]), 5, 10, |_| true).expect("archive page");
+        assert_eq!(page.rows.len(), 2);
+        assert_eq!(page.next_after, None);
+    }
+}
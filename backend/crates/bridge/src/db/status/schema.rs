@@ -1,18 +1,33 @@
 //! Schema and codec definitions for the bridge-status DB trees.
 
+use status_utils::impl_json_value_codec;
 use typed_sled::{
-    codec::{CodecError, ValueCodec},
+    codec::{CodecError, KeyCodec},
     schema::TreeName,
     Schema,
 };
 
 use strata_bridge_primitives::types::DepositIdx;
-
-use crate::types::{
-    ReimbursementStatusCursor, WithdrawalInfo, WithdrawalPairingCursor, WithdrawalSeq,
-    WithdrawalStatusCursor,
+use strata_primitives::buf::Buf32;
+
+use crate::{
+    db::types::DbOperatorUptimeBucket,
+    types::{
+        ArchivedRow, DepositInfo, OperatorStatus, ReimbursementInfo, ReimbursementStatusCursor,
+        TxRecord, WithdrawalInfo, WithdrawalPairingCursor, WithdrawalSeq, WithdrawalStatusCursor,
+    },
 };
 
+/// Deposit status rows keyed by bridge deposit index.
+#[derive(Debug)]
+pub(crate) struct DepositInfoSchema;
+
+impl Schema for DepositInfoSchema {
+    const TREE_NAME: TreeName = TreeName("deposit_info");
+    type Key = DepositIdx;
+    type Value = DepositInfo;
+}
+
 /// Withdrawal status rows keyed by bridge deposit index.
 #[derive(Debug)]
 pub(crate) struct WithdrawalInfoSchema;
@@ -23,6 +38,16 @@ impl Schema for WithdrawalInfoSchema {
     type Value = WithdrawalInfo;
 }
 
+/// Reimbursement status rows keyed by bridge deposit index.
+#[derive(Debug)]
+pub(crate) struct ReimbursementInfoSchema;
+
+impl Schema for ReimbursementInfoSchema {
+    const TREE_NAME: TreeName = TreeName("reimbursement_info");
+    type Key = DepositIdx;
+    type Value = ReimbursementInfo;
+}
+
 /// Withdrawal-to-deposit pairing rows keyed by bridge deposit index.
 #[derive(Debug)]
 pub(crate) struct WithdrawalPairingSchema;
@@ -33,6 +58,57 @@ impl Schema for WithdrawalPairingSchema {
     type Value = WithdrawalSeq;
 }
 
+/// Archived withdrawal-to-deposit pairings keyed by bridge deposit index,
+/// moved here when their pairing row is garbage collected.
+#[derive(Debug)]
+pub(crate) struct WithdrawalPairingArchiveSchema;
+
+impl Schema for WithdrawalPairingArchiveSchema {
+    const TREE_NAME: TreeName = TreeName("withdrawal_pairing_archive");
+    type Key = DepositIdx;
+    type Value = WithdrawalSeq;
+}
+
+/// Archived terminal deposit rows keyed by bridge deposit index.
+#[derive(Debug)]
+pub(crate) struct DepositArchiveSchema;
+
+impl Schema for DepositArchiveSchema {
+    const TREE_NAME: TreeName = TreeName("deposit_archive");
+    type Key = DepositIdx;
+    type Value = ArchivedRow<DepositInfo>;
+}
+
+/// Archived terminal withdrawal rows keyed by bridge deposit index.
+#[derive(Debug)]
+pub(crate) struct WithdrawalArchiveSchema;
+
+impl Schema for WithdrawalArchiveSchema {
+    const TREE_NAME: TreeName = TreeName("withdrawal_archive");
+    type Key = DepositIdx;
+    type Value = ArchivedRow<WithdrawalInfo>;
+}
+
+/// Archived terminal reimbursement rows keyed by bridge deposit index.
+#[derive(Debug)]
+pub(crate) struct ReimbursementArchiveSchema;
+
+impl Schema for ReimbursementArchiveSchema {
+    const TREE_NAME: TreeName = TreeName("reimbursement_archive");
+    type Key = DepositIdx;
+    type Value = ArchivedRow<ReimbursementInfo>;
+}
+
+/// Lifecycle records keyed by the byte-order hash of each bridge transaction.
+#[derive(Debug)]
+pub(crate) struct TxRecordSchema;
+
+impl Schema for TxRecordSchema {
+    const TREE_NAME: TreeName = TreeName("tx_record");
+    type Key = Buf32;
+    type Value = TxRecord;
+}
+
 /// Deposit-info cursor cell.
 #[derive(Debug)]
 pub(crate) struct DepositInfoCursorSchema;
@@ -73,35 +149,100 @@ impl Schema for ReimbursementStatusCursorSchema {
     type Value = ReimbursementStatusCursor;
 }
 
-// ---- Value codecs ----
+/// Operator statuses of the latest poll.
+#[derive(Debug)]
+pub(crate) struct OperatorStatusSchema;
+
+impl Schema for OperatorStatusSchema {
+    const TREE_NAME: TreeName = TreeName("operator_status");
+    type Key = u8;
+    type Value = Vec<OperatorStatus>;
+}
 
-macro_rules! impl_json_value_codec {
-    ($schema:ty, $value:ty) => {
-        impl ValueCodec<$schema> for $value {
-            type Decoded = Self;
+/// Unix timestamp in seconds of the latest completed poll.
+#[derive(Debug)]
+pub(crate) struct LastSuccessfulPollSchema;
 
-            fn encode_value(&self) -> Result<Vec<u8>, CodecError> {
-                serde_json::to_vec(self).map_err(|e| CodecError::SerializationFailed {
-                    schema: <$schema>::TREE_NAME.0,
-                    source: e.into(),
-                })
+impl Schema for LastSuccessfulPollSchema {
+    const TREE_NAME: TreeName = TreeName("last_successful_poll");
+    type Key = u8;
+    type Value = u64;
+}
+
+/// Operator uptime buckets keyed by their start in unix seconds.
+#[derive(Debug)]
+pub(crate) struct OperatorUptimeSchema;
+
+impl Schema for OperatorUptimeSchema {
+    const TREE_NAME: TreeName = TreeName("operator_uptime");
+    type Key = u64;
+    type Value = DbOperatorUptimeBucket;
+}
+
+/// Unix timestamp in seconds since which each withdrawal has been in
+/// progress, keyed by the byte-order hash of its request transaction.
+#[derive(Debug)]
+pub(crate) struct WithdrawalInProgressSchema;
+
+impl Schema for WithdrawalInProgressSchema {
+    const TREE_NAME: TreeName = TreeName("withdrawal_in_progress_since");
+    type Key = Buf32;
+    type Value = u64;
+}
+
+/// Unix timestamp in seconds since which each deposit has been in progress,
+/// keyed by the byte-order hash of its request transaction.
+#[derive(Debug)]
+pub(crate) struct DepositInProgressSchema;
+
+impl Schema for DepositInProgressSchema {
+    const TREE_NAME: TreeName = TreeName("deposit_in_progress_since");
+    type Key = Buf32;
+    type Value = u64;
+}
+
+// ---- Key codecs ----
+
+macro_rules! impl_buf32_key_codec {
+    ($schema:ty) => {
+        impl KeyCodec<$schema> for Buf32 {
+            fn encode_key(&self) -> Result<Vec<u8>, CodecError> {
+                Ok(self.0.to_vec())
             }
 
-            fn decode_value(data: sled::IVec) -> Result<Self::Decoded, CodecError> {
-                serde_json::from_slice(data.as_ref()).map_err(|e| {
-                    CodecError::DeserializationFailed {
-                        schema: <$schema>::TREE_NAME.0,
-                        source: e.into(),
-                    }
-                })
+            fn decode_key(buf: &[u8]) -> Result<Self, CodecError> {
+                let bytes: [u8; 32] = buf.try_into().map_err(|_| CodecError::InvalidKeyLength {
+                    schema: <$schema>::TREE_NAME.0,
+                    expected: 32,
+                    actual: buf.len(),
+                })?;
+                Ok(Buf32(bytes))
             }
         }
     };
 }
 
+impl_buf32_key_codec!(TxRecordSchema);
+impl_buf32_key_codec!(WithdrawalInProgressSchema);
+impl_buf32_key_codec!(DepositInProgressSchema);
+
+// ---- Value codecs ----
+
+impl_json_value_codec!(DepositInfoSchema, DepositInfo);
 impl_json_value_codec!(WithdrawalInfoSchema, WithdrawalInfo);
+impl_json_value_codec!(ReimbursementInfoSchema, ReimbursementInfo);
 impl_json_value_codec!(WithdrawalPairingSchema, WithdrawalSeq);
+impl_json_value_codec!(WithdrawalPairingArchiveSchema, WithdrawalSeq);
+impl_json_value_codec!(DepositArchiveSchema, ArchivedRow<DepositInfo>);
+impl_json_value_codec!(WithdrawalArchiveSchema, ArchivedRow<WithdrawalInfo>);
+impl_json_value_codec!(ReimbursementArchiveSchema, ArchivedRow<ReimbursementInfo>);
+impl_json_value_codec!(TxRecordSchema, TxRecord);
 impl_json_value_codec!(DepositInfoCursorSchema, DepositIdx);
 impl_json_value_codec!(WithdrawalPairingCursorSchema, WithdrawalPairingCursor);
 impl_json_value_codec!(WithdrawalStatusCursorSchema, WithdrawalStatusCursor);
 impl_json_value_codec!(ReimbursementStatusCursorSchema, ReimbursementStatusCursor);
+impl_json_value_codec!(OperatorStatusSchema, Vec<OperatorStatus>);
+impl_json_value_codec!(LastSuccessfulPollSchema, u64);
+impl_json_value_codec!(OperatorUptimeSchema, DbOperatorUptimeBucket);
+impl_json_value_codec!(WithdrawalInProgressSchema, u64);
+impl_json_value_codec!(DepositInProgressSchema, u64);
@@ -1,15 +1,19 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use strata_bridge_primitives::types::DepositIdx;
+use strata_primitives::buf::Buf32;
 
 use crate::{
     db::{
         error::DbResult,
         types::{
-            DbBridgeStatusSnapshot, DbIndexerState, DbWithdrawalEventIndex, DbWithdrawalRequest,
-            DbWithdrawalRequestRow,
+            DbArchivePage, DbBridgeStatusSnapshot, DbIndexerState, DbOperatorUptimeBucket,
+            DbWithdrawalEventIndex, DbWithdrawalRequest, DbWithdrawalRequestRow,
         },
     },
     types::{
-        ReimbursementStatusCursor, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor,
+        ArchivedRow, DepositInfo, OperatorStatus, ReimbursementInfo, ReimbursementStatusCursor,
+        TxRecord, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor, WithdrawalSeq,
         WithdrawalStatusCursor,
     },
 };
@@ -53,18 +57,105 @@ pub(crate) trait BridgeStatusDb: Send + Sync {
     /// Loads all persisted status rows, pairings, and cursors.
     fn get_status_snapshot(&self) -> DbResult<DbBridgeStatusSnapshot>;
 
+    /// Inserts or replaces one deposit status row.
+    fn put_deposit_info(&self, deposit_idx: DepositIdx, info: &DepositInfo) -> DbResult<()>;
+
+    /// Deletes one deposit status row.
+    fn del_deposit_info(&self, deposit_idx: DepositIdx) -> DbResult<bool>;
+
     /// Inserts or replaces one withdrawal status row.
     fn put_withdrawal_info(&self, deposit_idx: DepositIdx, info: &WithdrawalInfo) -> DbResult<()>;
 
     /// Deletes one withdrawal status row.
     fn del_withdrawal_info(&self, deposit_idx: DepositIdx) -> DbResult<bool>;
 
+    /// Inserts or replaces one reimbursement status row.
+    fn put_reimbursement_info(
+        &self,
+        deposit_idx: DepositIdx,
+        info: &ReimbursementInfo,
+    ) -> DbResult<()>;
+
+    /// Deletes one reimbursement status row.
+    fn del_reimbursement_info(&self, deposit_idx: DepositIdx) -> DbResult<bool>;
+
     /// Inserts or replaces withdrawal-to-deposit pairings.
     fn put_withdrawal_pairings(&self, pairings: &[WithdrawalPairing]) -> DbResult<()>;
 
-    /// Deletes withdrawal-to-deposit pairing rows in `start..end`.
+    /// Moves withdrawal-to-deposit pairing rows in `start..end` to the pairing
+    /// archive.
     fn del_withdrawal_pairings_range(&self, start: DepositIdx, end: DepositIdx) -> DbResult<()>;
 
+    /// Returns the withdrawal sequence ever paired with a deposit, from its
+    /// pairing row or the pairing archive.
+    fn get_withdrawal_seq(&self, deposit_idx: DepositIdx) -> DbResult<Option<WithdrawalSeq>>;
+
+    /// Inserts or replaces archived terminal deposit rows.
+    fn put_archived_deposits(&self, rows: &[ArchivedRow<DepositInfo>]) -> DbResult<()>;
+
+    /// Returns the archived row of one deposit.
+    fn get_archived_deposit(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<DepositInfo>>>;
+
+    /// Returns up to `limit` archived deposit rows matching `filter`, in
+    /// ascending deposit order after the `after` deposit index,
+    /// scanning at most
+    /// [`ARCHIVE_SCAN_LIMIT`](crate::db::status::ARCHIVE_SCAN_LIMIT) rows.
+    fn archived_deposits_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&DepositInfo) -> bool,
+    ) -> DbResult<DbArchivePage<DepositInfo>>;
+
+    /// Inserts or replaces archived terminal withdrawal rows.
+    fn put_archived_withdrawals(&self, rows: &[ArchivedRow<WithdrawalInfo>]) -> DbResult<()>;
+
+    /// Returns the archived row of one withdrawal.
+    fn get_archived_withdrawal(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<WithdrawalInfo>>>;
+
+    /// Returns up to `limit` archived withdrawal rows matching `filter`, in
+    /// ascending deposit order after the `after` deposit index,
+    /// scanning at most
+    /// [`ARCHIVE_SCAN_LIMIT`](crate::db::status::ARCHIVE_SCAN_LIMIT) rows.
+    fn archived_withdrawals_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&WithdrawalInfo) -> bool,
+    ) -> DbResult<DbArchivePage<WithdrawalInfo>>;
+
+    /// Inserts or replaces archived terminal reimbursement rows.
+    fn put_archived_reimbursements(&self, rows: &[ArchivedRow<ReimbursementInfo>]) -> DbResult<()>;
+
+    /// Returns the archived row of one reimbursement.
+    fn get_archived_reimbursement(
+        &self,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<Option<ArchivedRow<ReimbursementInfo>>>;
+
+    /// Returns up to `limit` archived reimbursement rows matching `filter`, in
+    /// ascending deposit order after the `after` deposit index,
+    /// scanning at most
+    /// [`ARCHIVE_SCAN_LIMIT`](crate::db::status::ARCHIVE_SCAN_LIMIT) rows.
+    fn archived_reimbursements_after(
+        &self,
+        after: Option<DepositIdx>,
+        limit: usize,
+        filter: impl Fn(&ReimbursementInfo) -> bool,
+    ) -> DbResult<DbArchivePage<ReimbursementInfo>>;
+
+    /// Inserts or replaces lifecycle records keyed by transaction hash.
+    fn put_tx_records(&self, records: &[(Buf32, TxRecord)]) -> DbResult<()>;
+
+    /// Returns the lifecycle record of one transaction.
+    fn get_tx_record(&self, txid: &Buf32) -> DbResult<Option<TxRecord>>;
+
     /// Stores the deposit-info polling cursor.
     fn put_deposit_info_cursor(&self, cursor: DepositIdx) -> DbResult<()>;
 
@@ -76,4 +167,45 @@ pub(crate) trait BridgeStatusDb: Send + Sync {
 
     /// Stores the reimbursement-status polling cursor.
     fn put_reimbursement_status_cursor(&self, cursor: ReimbursementStatusCursor) -> DbResult<()>;
+
+    /// Replaces the stored operator statuses.
+    fn put_operators(&self, operators: &[OperatorStatus]) -> DbResult<()>;
+
+    /// Stores when the monitor last completed a poll, in unix seconds.
+    fn put_last_successful_poll_at(&self, polled_at: u64) -> DbResult<()>;
+
+    /// Folds one poll of `operators` into the uptime bucket starting at
+    /// `bucket_start`, in unix seconds.
+    fn record_operator_uptime(
+        &self,
+        bucket_start: u64,
+        operators: &[OperatorStatus],
+    ) -> DbResult<()>;
+
+    /// Fetches the operator uptime buckets starting at or after `since`, in
+    /// ascending order.
+    fn get_operator_uptime_since(&self, since: u64)
+        -> DbResult<Vec<(u64, DbOperatorUptimeBucket)>>;
+
+    /// Deletes the operator uptime buckets starting before `cutoff`.
+    fn prune_operator_uptime_before(&self, cutoff: u64) -> DbResult<()>;
+
+    /// Tracks the withdrawals in progress by request txid and returns since
+    /// when each has been, in unix seconds.
+    ///
+    /// Withdrawals not tracked yet start at `now`; tracked ones missing from
+    /// `request_txids` are forgotten.
+    fn track_withdrawals_in_progress(
+        &self,
+        request_txids: &BTreeSet<Buf32>,
+        now: u64,
+    ) -> DbResult<BTreeMap<Buf32, u64>>;
+
+    /// Tracks the deposits in progress by the byte-order hash of their
+    /// request txid, like [`Self::track_withdrawals_in_progress`].
+    fn track_deposits_in_progress(
+        &self,
+        request_txids: &BTreeSet<Buf32>,
+        now: u64,
+    ) -> DbResult<BTreeMap<Buf32, u64>>;
 }
@@ -1,12 +1,16 @@
 //! Types shared by DB traits and implementations.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use strata_bridge_primitives::types::DepositIdx;
 use strata_primitives::buf::Buf32;
 
+use strata_bridge_rpc::types::RpcOperatorStatus;
+
 use crate::types::{
-    ReimbursementStatusCursor, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor,
-    WithdrawalStatusCursor,
+    ArchivedRow, DepositInfo, OperatorStatus, ReimbursementInfo, ReimbursementStatusCursor,
+    WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor, WithdrawalStatusCursor,
 };
 
 /// Assembled snapshot of bridge-status cursors.
@@ -18,12 +22,76 @@ pub(crate) struct StatusCursors {
     pub(crate) reimbursement_status: ReimbursementStatusCursor,
 }
 
+/// Archived rows matching a filter, read from a bounded scan of the archive.
+#[derive(Debug, Clone)]
+pub(crate) struct DbArchivePage<T> {
+    pub(crate) rows: Vec<ArchivedRow<T>>,
+    /// Deposit index of the last row scanned, if the scan stopped before the
+    /// end of the archive.
+    pub(crate) next_after: Option<DepositIdx>,
+}
+
+impl<T> Default for DbArchivePage<T> {
+    fn default() -> Self {
+        Self {
+            rows: Vec::new(),
+            next_after: None,
+        }
+    }
+}
+
 /// Snapshot of all persisted bridge-status rows and cursors.
 #[derive(Debug, Clone)]
 pub(crate) struct DbBridgeStatusSnapshot {
+    pub(crate) operators: Vec<OperatorStatus>,
+    pub(crate) deposits: Vec<(DepositIdx, DepositInfo)>,
     pub(crate) withdrawals: Vec<(DepositIdx, WithdrawalInfo)>,
+    pub(crate) reimbursements: Vec<(DepositIdx, ReimbursementInfo)>,
     pub(crate) withdrawal_pairings: Vec<WithdrawalPairing>,
     pub(crate) cursors: StatusCursors,
+    /// Unix timestamp in seconds of the latest completed poll, if any.
+    pub(crate) last_successful_poll_at: Option<u64>,
+}
+
+/// Number of polls that observed an operator online or offline.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DbOperatorCounts {
+    pub(crate) online: u64,
+    pub(crate) offline: u64,
+}
+
+impl DbOperatorCounts {
+    pub(crate) fn merge(&mut self, other: &Self) {
+        self.online += other.online;
+        self.offline += other.offline;
+    }
+
+    pub(crate) fn total(&self) -> u64 {
+        self.online + self.offline
+    }
+}
+
+/// Aggregated operator polls for a fixed time bucket, keyed by hex-encoded
+/// operator public key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DbOperatorUptimeBucket {
+    pub(crate) operators: BTreeMap<String, DbOperatorCounts>,
+}
+
+impl DbOperatorUptimeBucket {
+    pub(crate) fn record(&mut self, operators: &[OperatorStatus]) {
+        for operator in operators {
+            let counts = self
+                .operators
+                .entry(operator.operator_pk().to_string())
+                .or_default();
+            if matches!(operator.status(), RpcOperatorStatus::Offline) {
+                counts.offline += 1;
+            } else {
+                counts.online += 1;
+            }
+        }
+    }
 }
 
 /// Indexer checkpoint. Tracks the highest block number that has been fully
@@ -31,6 +99,10 @@ pub(crate) struct DbBridgeStatusSnapshot {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub(crate) struct DbIndexerState {
     pub(crate) last_scanned_block: u64,
+    /// Chain head when the checkpoint was written. Absent in checkpoints
+    /// written before the head was recorded.
+    #[serde(default)]
+    pub(crate) chain_head: Option<u64>,
 }
 
 /// Identity of one `WithdrawalIntentEvent` emitted on the EVM chain.
@@ -1,7 +1,7 @@
 use std::path::Path;
 
-use anyhow::Context;
 use sled::transaction::TransactionError;
+use status_utils::db::open_sled_db;
 use typed_sled::{error::Error as TSledError, transaction::SledTransactional, SledDb, SledTree};
 
 use crate::db::{
@@ -55,26 +55,17 @@ impl WithdrawalIndexerDbSled {
     /// intermediate directories if they don't exist; sled itself only creates
     /// the leaf, so a fresh datadir without intermediate parents would fail.
     pub fn open(datadir: impl AsRef<Path>) -> anyhow::Result<Self> {
-        let path = datadir.as_ref().join("withdrawal_index");
-        std::fs::create_dir_all(&path)
-            .with_context(|| format!("create withdrawal index dir {}", path.display()))?;
-        let sled_db = sled::open(&path)
-            .with_context(|| format!("open withdrawal index sled db at {}", path.display()))?;
-        // typed-sled codec errors are not Send + Sync, so anyhow::Context cannot preserve them.
-        Self::from_sled_db(sled_db)
-            .map_err(|e| anyhow::anyhow!("initialize withdrawal index trees: {e}"))
+        open_sled_db(
+            datadir.as_ref().join("withdrawal_index"),
+            "withdrawal index",
+            Self::from_sled_db,
+        )
     }
 
     /// Open a temporary in-memory-like sled database deleted on drop.
     #[cfg(test)]
     pub fn open_temporary() -> anyhow::Result<Self> {
-        let sled_db = sled::Config::new()
-            .temporary(true)
-            .open()
-            .context("open temporary withdrawal index sled db")?;
-        // typed-sled codec errors are not Send + Sync, so anyhow::Context cannot preserve them.
-        Self::from_sled_db(sled_db)
-            .map_err(|e| anyhow::anyhow!("initialize temporary withdrawal index trees: {e}"))
+        status_utils::db::open_temporary_sled_db("withdrawal index", Self::from_sled_db)
     }
 
     fn from_sled_db(sled_db: sled::Db) -> DbResult<Self> {
@@ -254,6 +245,7 @@ mod tests {
         let req = make_withdrawal_request(1);
         let state = DbIndexerState {
             last_scanned_block: 12345,
+            chain_head: None,
         };
 
         {
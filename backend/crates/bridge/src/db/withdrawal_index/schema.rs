@@ -1,7 +1,8 @@
 //! Schema and codec definitions for the withdrawal-indexer trees.
 
+use status_utils::impl_json_value_codec;
 use typed_sled::{
-    codec::{CodecError, KeyCodec, ValueCodec},
+    codec::{CodecError, KeyCodec},
     schema::TreeName,
     Schema,
 };
@@ -87,30 +88,6 @@ impl KeyCodec<WithdrawalEventIndexSchema> for DbWithdrawalEventKey {
 
 // ---- Value codecs ----
 
-macro_rules! impl_json_value_codec {
-    ($schema:ty, $value:ty) => {
-        impl ValueCodec<$schema> for $value {
-            type Decoded = Self;
-
-            fn encode_value(&self) -> Result<Vec<u8>, CodecError> {
-                serde_json::to_vec(self).map_err(|e| CodecError::SerializationFailed {
-                    schema: <$schema>::TREE_NAME.0,
-                    source: e.into(),
-                })
-            }
-
-            fn decode_value(data: sled::IVec) -> Result<Self::Decoded, CodecError> {
-                serde_json::from_slice(data.as_ref()).map_err(|e| {
-                    CodecError::DeserializationFailed {
-                        schema: <$schema>::TREE_NAME.0,
-                        source: e.into(),
-                    }
-                })
-            }
-        }
-    };
-}
-
 impl_json_value_codec!(IndexerStateSchema, DbIndexerState);
 impl_json_value_codec!(WithdrawalRequestSchema, DbWithdrawalRequest);
 impl_json_value_codec!(WithdrawalEventIndexSchema, DbWithdrawalEventIndex);
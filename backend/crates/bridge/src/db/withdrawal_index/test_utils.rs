@@ -59,6 +59,7 @@ pub(crate) fn assert_withdrawal_event_roundtrip<D: WithdrawalIndexerDb>(db: &D)
 
     let state = DbIndexerState {
         last_scanned_block: 4242,
+        chain_head: Some(4250),
     };
     db.put_indexer_state("withdrawal_index", &state)
         .expect("put state");
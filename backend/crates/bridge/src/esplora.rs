@@ -1,7 +1,7 @@
 use anyhow::Result;
 use bitcoin::Txid;
 use serde::Deserialize;
-use std::time::Duration;
+use status_utils::EsploraClient;
 use strata_primitives::L1Height;
 use tracing::error;
 
@@ -11,51 +11,12 @@ struct TxStatus {
     block_height: Option<L1Height>,
 }
 
-pub(crate) struct EsploraClient {
-    base_url: String,
-    client: reqwest::Client,
-}
-
-impl EsploraClient {
-    pub(crate) fn new(esplora_url: &str, request_timeout_s: u64) -> Self {
-        Self {
-            base_url: esplora_url.trim_end_matches('/').to_string(),
-            client: reqwest::Client::builder()
-                .timeout(Duration::from_secs(request_timeout_s))
-                .build()
-                .expect("failed to create Esplora HTTP client"),
-        }
-    }
-
-    fn url(&self, path: &str) -> String {
-        format!("{}{path}", self.base_url)
-    }
-
-    async fn get_tip_height(&self) -> reqwest::Result<String> {
-        let resp = self
-            .client
-            .get(self.url("/blocks/tip/height"))
-            .send()
-            .await?;
-        resp.text().await
-    }
-
-    async fn get_tx_status(&self, txid: Txid) -> Option<TxStatus> {
-        let status_path = format!("/tx/{txid}/status");
-        let status_resp = self.client.get(self.url(&status_path)).send().await;
-
-        match status_resp {
-            Ok(resp) => match resp.json().await {
-                Ok(status) => Some(status),
-                Err(e) => {
-                    error!(%txid, error = %e, "failed to parse tx status JSON from esplora");
-                    None
-                }
-            },
-            Err(e) => {
-                error!(%txid, error = %e, "failed to fetch tx status from esplora");
-                None
-            }
+async fn get_tx_status(esplora_client: &EsploraClient, txid: Txid) -> Option<TxStatus> {
+    match esplora_client.get_json(&format!("/tx/{txid}/status")).await {
+        Ok(status) => Some(status),
+        Err(e) => {
+            error!(%txid, error = %e, "failed to fetch tx status from esplora");
+            None
         }
     }
 }
@@ -75,7 +36,7 @@ pub(crate) async fn get_tx_confirmations(
     txid: Txid,
     chain_tip_height: L1Height,
 ) -> Option<u64> {
-    let status = esplora_client.get_tx_status(txid).await?;
+    let status = get_tx_status(esplora_client, txid).await?;
 
     status
         .block_height
@@ -91,20 +52,6 @@ fn confirmations_from_block_height(chain_tip_height: L1Height, block_height: L1H
 mod tests {
     use super::*;
 
-    #[test]
-    fn esplora_client_normalizes_base_url_once() {
-        let client = EsploraClient::new("http://localhost:3002///", 5);
-
-        assert_eq!(
-            client.url("/blocks/tip/height"),
-            "http://localhost:3002/blocks/tip/height"
-        );
-        assert_eq!(
-            client.url("/tx/abc/status"),
-            "http://localhost:3002/tx/abc/status"
-        );
-    }
-
     #[test]
     fn tx_status_deserializes_l1_height_from_esplora_json() {
         let status: TxStatus =
@@ -1,17 +1,29 @@
+mod alerts;
+mod archive;
+mod badge;
 mod bridge_rpc;
 mod cache;
 mod context;
 mod db;
 mod esplora;
+mod lookup;
+mod prometheus;
 mod state;
 mod status;
 mod types;
+mod uptime;
 mod withdrawal_indexer;
 mod withdrawal_requests;
 mod withdrawal_status;
 
+pub use archive::{
+    get_archived_deposits, get_archived_reimbursements, get_archived_withdrawals, ArchiveQuery,
+};
+pub use badge::{get_bridge_badge, get_operator_badge};
 pub use context::BridgeMonitoringContext;
 pub use db::{BridgeStatusDbSled, WithdrawalIndexerDbSled};
+pub use lookup::{get_bridge_deposit, get_bridge_tx};
+pub use prometheus::encode_bridge_metrics;
 pub use status::{bridge_monitoring_task, get_bridge_status};
 pub use types::BridgeStatus;
 pub use withdrawal_indexer::task::run_withdrawal_indexer;
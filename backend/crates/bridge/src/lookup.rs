@@ -0,0 +1,267 @@
+//! Lookup of bridge transfers by transaction id and deposit index.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use bitcoin::{hashes::Hash, Txid};
+use serde::Serialize;
+use strata_bridge_primitives::types::DepositIdx;
+use strata_primitives::buf::Buf32;
+use tracing::warn;
+
+use super::{
+    context::BridgeMonitoringContext,
+    db::traits::{BridgeStatusDb, WithdrawalIndexerDb},
+    state::BridgeMonitoringState,
+    types::{TxRecord, TxRole},
+};
+
+/// Lifecycle record of a bridge transaction with the other transactions of
+/// its deposit.
+#[derive(Serialize, Debug)]
+struct TxLookup {
+    #[serde(flatten)]
+    record: TxRecord,
+    related: Vec<RelatedTx>,
+}
+
+/// Transaction of the same deposit, formatted as the lookup accepts it.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+struct RelatedTx {
+    role: TxRole,
+    txid: String,
+}
+
+impl RelatedTx {
+    fn new(txid: Buf32, role: TxRole) -> Self {
+        let txid = match role {
+            TxRole::WithdrawalRequest => format!("0x{}", hex::encode(txid.0)),
+            _ => Txid::from_byte_array(txid.0).to_string(),
+        };
+        Self { role, txid }
+    }
+}
+
+/// Index keys a transaction id may be stored under, in lookup order.
+///
+/// Bitcoin txids are displayed in reverse byte order and EVM transaction
+/// hashes are not, so an id without a `0x` prefix is tried both ways.
+fn tx_keys(txid: &str) -> Option<Vec<Buf32>> {
+    let (hex_txid, is_evm) = match txid.strip_prefix("0x") {
+        Some(hex_txid) => (hex_txid, true),
+        None => (txid, false),
+    };
+    let bytes: [u8; 32] = hex::decode(hex_txid).ok()?.try_into().ok()?;
+    if is_evm {
+        return Some(vec![Buf32(bytes)]);
+    }
+
+    let mut reversed = bytes;
+    reversed.reverse();
+    Some(vec![Buf32(reversed), Buf32(bytes)])
+}
+
+fn find_tx_record(status_db: &impl BridgeStatusDb, txid: &str) -> Result<TxRecord, StatusCode> {
+    let keys = tx_keys(txid).ok_or(StatusCode::BAD_REQUEST)?;
+    for key in keys {
+        match status_db.get_tx_record(&key) {
+            Ok(Some(record)) => return Ok(record),
+            Ok(None) => {}
+            Err(e) => {
+                warn!(%txid, error = %e, "failed to look up bridge transaction");
+                return Err(StatusCode::INTERNAL_SERVER_ERROR);
+            }
+        }
+    }
+    Err(StatusCode::NOT_FOUND)
+}
+
+/// Looks up `txid` and joins the other transactions of its deposit through
+/// the deposit lifecycle.
+async fn lookup_tx(
+    state: &BridgeMonitoringState,
+    status_db: &impl BridgeStatusDb,
+    withdrawal_index: &impl WithdrawalIndexerDb,
+    txid: &str,
+) -> Result<TxLookup, StatusCode> {
+    let record = find_tx_record(status_db, txid)?;
+    let lifecycle = state
+        .deposit_lifecycle(status_db, withdrawal_index, record.deposit_idx)
+        .await
+        .map_err(|e| {
+            warn!(%txid, error = %e, "failed to join bridge transaction lifecycle");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    // Each role occurs once per deposit, so the record's role identifies it.
+    let related = lifecycle
+        .txids()
+        .into_iter()
+        .filter(|(_, role)| *role != record.role)
+        .map(|(txid, role)| RelatedTx::new(txid, role))
+        .collect();
+    Ok(TxLookup { record, related })
+}
+
+/// Handler to get the lifecycle record of a bridge transaction,
+/// `/api/bridge/tx/{txid}`
+///
+/// Accepts deposit request, deposit, fulfillment, claim and payout txids as
+/// well as `0x`-prefixed EVM withdrawal transaction hashes. The response
+/// lists the other transactions of the deposit under `related`.
+pub async fn get_bridge_tx(
+    context: Arc<BridgeMonitoringContext>,
+    Path(txid): Path<String>,
+) -> Result<Response, StatusCode> {
+    let lookup = lookup_tx(
+        context.state(),
+        context.status_db(),
+        context.withdrawal_index(),
+        &txid,
+    )
+    .await?;
+    Ok(Json(lookup).into_response())
+}
+
+/// Handler to get the end-to-end lifecycle of a deposit,
+/// `/api/bridge/deposits/{deposit_idx}`
+pub async fn get_bridge_deposit(
+    context: Arc<BridgeMonitoringContext>,
+    Path(deposit_idx): Path<DepositIdx>,
+) -> Result<Response, StatusCode> {
+    let lifecycle = context
+        .state()
+        .deposit_lifecycle(context.status_db(), context.withdrawal_index(), deposit_idx)
+        .await
+        .map_err(|e| {
+            warn!(deposit_idx, error = %e, "failed to join deposit lifecycle");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+    if lifecycle.is_empty() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    Ok(Json(lifecycle).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        db::{status::mock::MockBridgeStatusDb, withdrawal_index::mock::MockWithdrawalIndexerDb},
+        types::{
+            ArchivedRow, DepositInfo, DepositStatus, TransferStage, WithdrawalInfo,
+            WithdrawalStatus,
+        },
+    };
+
+    #[test]
+    fn finds_bitcoin_and_evm_transactions() {
+        let status_db = MockBridgeStatusDb::default();
+        let mut evm_tx_hash = [0; 32];
+        evm_tx_hash[0] = 0xab;
+        let mut fulfillment_bytes = [0; 32];
+        fulfillment_bytes[0] = 0xcd;
+        let fulfillment_txid = Txid::from_byte_array(fulfillment_bytes);
+        let records = TxRecord::index(
+            9,
+            TransferStage::Withdrawal(WithdrawalInfo {
+                withdrawal_request_txid: Buf32(evm_tx_hash),
+                fulfillment_txid: Some(fulfillment_txid),
+                status: WithdrawalStatus::Complete,
+            }),
+            Some(2),
+        )
+        .collect::<Vec<_>>();
+        status_db.put_tx_records(&records).expect("put tx records");
+
+        let record =
+            find_tx_record(&status_db, &fulfillment_txid.to_string()).expect("fulfillment found");
+        assert_eq!(record.deposit_idx, 9);
+        assert_eq!(record.role, TxRole::Fulfillment);
+
+        let evm_hash = format!("0x{}", hex::encode(evm_tx_hash));
+        let record = find_tx_record(&status_db, &evm_hash).expect("withdrawal request found");
+        assert_eq!(record.role, TxRole::WithdrawalRequest);
+        let record = find_tx_record(&status_db, &evm_hash[2..]).expect("unprefixed hash found");
+        assert_eq!(record.role, TxRole::WithdrawalRequest);
+
+        assert_eq!(
+            find_tx_record(&status_db, &Txid::all_zeros().to_string()).unwrap_err(),
+            StatusCode::NOT_FOUND
+        );
+        assert_eq!(
+            find_tx_record(&status_db, "not-a-txid").unwrap_err(),
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            find_tx_record(&status_db, "0xabcd").unwrap_err(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn lists_related_transactions_of_purged_rows() {
+        let status_db = MockBridgeStatusDb::default();
+        let withdrawal_index = MockWithdrawalIndexerDb::default();
+        let txid = |byte| Txid::from_byte_array([byte; 32]);
+        let deposit = DepositInfo {
+            deposit_request_txid: txid(1),
+            deposit_txid: Some(txid(2)),
+            status: DepositStatus::Complete,
+        };
+        let withdrawal = WithdrawalInfo {
+            withdrawal_request_txid: Buf32([3; 32]),
+            fulfillment_txid: Some(txid(4)),
+            status: WithdrawalStatus::Complete,
+        };
+        status_db
+            .put_archived_deposits(&[ArchivedRow {
+                deposit_idx: 5,
+                info: deposit,
+                confirmations: 6,
+            }])
+            .expect("archive deposit");
+        status_db
+            .put_archived_withdrawals(&[ArchivedRow {
+                deposit_idx: 5,
+                info: withdrawal,
+                confirmations: 6,
+            }])
+            .expect("archive withdrawal");
+        let records = TxRecord::index(5, TransferStage::Deposit(deposit), Some(6))
+            .chain(TxRecord::index(
+                5,
+                TransferStage::Withdrawal(withdrawal),
+                Some(6),
+            ))
+            .collect::<Vec<_>>();
+        status_db.put_tx_records(&records).expect("put tx records");
+
+        let lookup = lookup_tx(
+            &BridgeMonitoringState::default(),
+            &status_db,
+            &withdrawal_index,
+            &txid(4).to_string(),
+        )
+        .await
+        .expect("fulfillment found");
+
+        assert_eq!(lookup.record.role, TxRole::Fulfillment);
+        assert_eq!(
+            lookup.related,
+            vec![
+                RelatedTx::new(Buf32(txid(1).to_byte_array()), TxRole::DepositRequest),
+                RelatedTx::new(Buf32(txid(2).to_byte_array()), TxRole::Deposit),
+                RelatedTx {
+                    role: TxRole::WithdrawalRequest,
+                    txid: format!("0x{}", hex::encode([3; 32])),
+                },
+            ]
+        );
+        assert_eq!(lookup.related[0].txid, txid(1).to_string());
+    }
+}
@@ -0,0 +1,234 @@
+//! Prometheus metrics for bridge monitoring.
+
+use strata_bridge_rpc::types::RpcOperatorStatus;
+use tracing::warn;
+
+use status_utils::{MetricKind, PrometheusEncoder};
+
+use super::{
+    context::BridgeMonitoringContext,
+    db::traits::WithdrawalIndexerDb,
+    types::{BridgeStatus, DepositStatus, ReimbursementStatus, WithdrawalStatus},
+    withdrawal_indexer::TASK_NAME,
+};
+
+const DEPOSIT_STATUSES: [DepositStatus; 3] = [
+    DepositStatus::InProgress,
+    DepositStatus::Failed,
+    DepositStatus::Complete,
+];
+
+const WITHDRAWAL_STATUSES: [WithdrawalStatus; 2] =
+    [WithdrawalStatus::InProgress, WithdrawalStatus::Complete];
+
+const REIMBURSEMENT_STATUSES: [ReimbursementStatus; 5] = [
+    ReimbursementStatus::NotStarted,
+    ReimbursementStatus::InProgress,
+    ReimbursementStatus::Slashed,
+    ReimbursementStatus::Aborted,
+    ReimbursementStatus::Complete,
+];
+
+fn deposit_status_label(status: DepositStatus) -> &'static str {
+    match status {
+        DepositStatus::InProgress => "in_progress",
+        DepositStatus::Failed => "failed",
+        DepositStatus::Complete => "complete",
+    }
+}
+
+fn withdrawal_status_label(status: WithdrawalStatus) -> &'static str {
+    match status {
+        WithdrawalStatus::InProgress => "in_progress",
+        WithdrawalStatus::Complete => "complete",
+    }
+}
+
+fn reimbursement_status_label(status: ReimbursementStatus) -> &'static str {
+    match status {
+        ReimbursementStatus::NotStarted => "not_started",
+        ReimbursementStatus::InProgress => "in_progress",
+        ReimbursementStatus::Slashed => "slashed",
+        ReimbursementStatus::Aborted => "aborted",
+        ReimbursementStatus::Complete => "complete",
+    }
+}
+
+/// Writes one sample per status label, including statuses with no rows.
+fn encode_status_counts<'a>(
+    encoder: &mut PrometheusEncoder,
+    name: &str,
+    all_labels: impl IntoIterator<Item = &'a str>,
+    row_labels: impl IntoIterator<Item = &'a str>,
+) {
+    let row_labels = row_labels.into_iter().collect::<Vec<_>>();
+    for label in all_labels {
+        let count = row_labels.iter().filter(|row| **row == label).count();
+        encoder.sample(name, &[("status", label)], count as f64);
+    }
+}
+
+fn encode_bridge_status(status: &BridgeStatus, encoder: &mut PrometheusEncoder) {
+    encoder.family(
+        "status_bridge_operator_online",
+        MetricKind::Gauge,
+        "Whether the bridge operator is online (1) or not (0).",
+    );
+    for operator in &status.operators {
+        let operator_pk = operator.operator_pk().to_string();
+        encoder.sample(
+            "status_bridge_operator_online",
+            &[
+                ("operator_id", operator.operator_id()),
+                ("operator_pk", &operator_pk),
+            ],
+            f64::from(u8::from(matches!(
+                operator.status(),
+                RpcOperatorStatus::Online
+            ))),
+        );
+    }
+
+    encoder.family(
+        "status_bridge_deposits",
+        MetricKind::Gauge,
+        "Number of tracked deposits by status.",
+    );
+    encode_status_counts(
+        encoder,
+        "status_bridge_deposits",
+        DEPOSIT_STATUSES.map(deposit_status_label),
+        status
+            .deposits
+            .iter()
+            .map(|deposit| deposit_status_label(deposit.status)),
+    );
+
+    encoder.family(
+        "status_bridge_withdrawals",
+        MetricKind::Gauge,
+        "Number of tracked withdrawals by status.",
+    );
+    encode_status_counts(
+        encoder,
+        "status_bridge_withdrawals",
+        WITHDRAWAL_STATUSES.map(withdrawal_status_label),
+        status
+            .withdrawals
+            .iter()
+            .map(|withdrawal| withdrawal_status_label(withdrawal.status)),
+    );
+
+    encoder.family(
+        "status_bridge_reimbursements",
+        MetricKind::Gauge,
+        "Number of tracked reimbursements by status.",
+    );
+    encode_status_counts(
+        encoder,
+        "status_bridge_reimbursements",
+        REIMBURSEMENT_STATUSES.map(reimbursement_status_label),
+        status
+            .reimbursements
+            .iter()
+            .map(|reimbursement| reimbursement_status_label(reimbursement.status)),
+    );
+}
+
+/// Writes bridge operator, bridge row, withdrawal indexer and bridge RPC error
+/// metrics to `encoder`.
+pub async fn encode_bridge_metrics(
+    context: &BridgeMonitoringContext,
+    encoder: &mut PrometheusEncoder,
+) {
+    encode_bridge_status(&context.bridge_status().await, encoder);
+
+    match context.withdrawal_index().get_indexer_state(TASK_NAME) {
+        Ok(Some(state)) => {
+            encoder.family(
+                "status_withdrawal_indexer_last_scanned_block",
+                MetricKind::Gauge,
+                "Highest EVM block fully scanned by the withdrawal indexer.",
+            );
+            encoder.sample(
+                "status_withdrawal_indexer_last_scanned_block",
+                &[],
+                state.last_scanned_block as f64,
+            );
+            if let Some(chain_head) = state.chain_head {
+                encoder.family(
+                    "status_withdrawal_indexer_chain_head",
+                    MetricKind::Gauge,
+                    "EVM chain head seen by the withdrawal indexer's latest scan.",
+                );
+                encoder.sample(
+                    "status_withdrawal_indexer_chain_head",
+                    &[],
+                    chain_head as f64,
+                );
+            }
+        }
+        Ok(None) => {}
+        Err(e) => warn!(error = %e, "could not read withdrawal indexer state"),
+    }
+
+    encoder.family(
+        "status_bridge_rpc_errors_total",
+        MetricKind::Counter,
+        "Number of bridge RPC requests that failed on a client after retries.",
+    );
+    encoder.sample(
+        "status_bridge_rpc_errors_total",
+        &[],
+        context.bridge_rpc().error_count() as f64,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::{hashes::Hash, PublicKey, Txid};
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::types::{DepositInfo, OperatorStatus};
+
+    #[test]
+    fn bridge_status_counts_include_empty_statuses() {
+        let operator_pk = PublicKey::from_str(
+            "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+        )
+        .expect("valid public key");
+        let status = BridgeStatus {
+            operators: vec![OperatorStatus::new(
+                "Alpen Labs #1".to_owned(),
+                operator_pk,
+                RpcOperatorStatus::Online,
+            )],
+            deposits: vec![
+                DepositInfo {
+                    deposit_request_txid: Txid::from_byte_array([1; 32]),
+                    deposit_txid: None,
+                    status: DepositStatus::InProgress,
+                },
+                DepositInfo {
+                    deposit_request_txid: Txid::from_byte_array([2; 32]),
+                    deposit_txid: None,
+                    status: DepositStatus::InProgress,
+                },
+            ],
+            ..BridgeStatus::default()
+        };
+
+        let mut encoder = PrometheusEncoder::new();
+        encode_bridge_status(&status, &mut encoder);
+        let metrics = encoder.finish();
+
+        assert!(metrics.contains(&format!(
+            "status_bridge_operator_online{{operator_id=\"Alpen Labs #1\",operator_pk=\"{operator_pk}\"}} 1\n"
+        )));
+        assert!(metrics.contains("status_bridge_deposits{status=\"in_progress\"} 2\n"));
+        assert!(metrics.contains("status_bridge_deposits{status=\"failed\"} 0\n"));
+        assert!(metrics.contains("status_bridge_withdrawals{status=\"complete\"} 0\n"));
+        assert!(metrics.contains("status_bridge_reimbursements{status=\"slashed\"} 0\n"));
+    }
+}
@@ -8,13 +8,14 @@ use super::{
     cache::BridgeStatusCache,
     db::{
         error::DbResult,
-        traits::BridgeStatusDb,
+        traits::{BridgeStatusDb, WithdrawalIndexerDb},
         types::{DbBridgeStatusSnapshot, DbWithdrawalRequestRow},
     },
     types::{
-        BridgeStatus, DepositInfo, DepositStatus, OperatorStatus, ReimbursementInfo,
-        ReimbursementStatus, ReimbursementStatusCursor, WithdrawalInfo, WithdrawalPairing,
-        WithdrawalPairingCursor, WithdrawalSeq, WithdrawalStatus, WithdrawalStatusCursor,
+        ArchivedRow, BridgeStatus, DepositInfo, DepositLifecycle, DepositStatus, OperatorStatus,
+        ReimbursementInfo, ReimbursementStatus, ReimbursementStatusCursor, StageRow, TransferStage,
+        TxRecord, WithdrawalInfo, WithdrawalPairing, WithdrawalPairingCursor, WithdrawalSeq,
+        WithdrawalStatus, WithdrawalStatusCursor,
     },
 };
 
@@ -85,8 +86,15 @@ impl BridgeMonitoringState {
     ) -> DbResult<()> {
         let mut cache_updates = Vec::new();
         let mut terminal_deposit_indices_to_purge = Vec::new();
+        let mut archived_rows = Vec::new();
+        let mut tx_records = Vec::new();
 
         for update in updates {
+            tx_records.extend(TxRecord::index(
+                update.deposit_idx,
+                TransferStage::Deposit(update.info),
+                update.confirmations,
+            ));
             match update.info.status {
                 DepositStatus::InProgress => {
                     cache_updates.push((update.deposit_idx, update.info, None));
@@ -98,6 +106,11 @@ impl BridgeMonitoringState {
 
                     if confirmations >= max_confirmations {
                         terminal_deposit_indices_to_purge.push(update.deposit_idx);
+                        archived_rows.push(ArchivedRow {
+                            deposit_idx: update.deposit_idx,
+                            info: update.info,
+                            confirmations,
+                        });
                     } else {
                         cache_updates.push((update.deposit_idx, update.info, Some(confirmations)));
                     }
@@ -111,7 +124,17 @@ impl BridgeMonitoringState {
         };
         let next_cursor =
             next_deposit_info_cursor(current_cursor, &terminal_deposit_indices_to_purge);
+        for (deposit_idx, info, _) in &cache_updates {
+            status_db.put_deposit_info(*deposit_idx, info)?;
+        }
+        status_db.put_tx_records(&tx_records)?;
+        status_db.put_archived_deposits(&archived_rows)?;
         status_db.put_deposit_info_cursor(next_cursor)?;
+        for deposit_idx in &terminal_deposit_indices_to_purge {
+            if let Err(e) = status_db.del_deposit_info(*deposit_idx) {
+                warn!(deposit_idx, error = %e, "failed to purge old deposit row");
+            }
+        }
 
         let mut cache = self.cache.write().await;
         cache.apply_deposit_updates(cache_updates);
@@ -168,9 +191,14 @@ impl BridgeMonitoringState {
         cache.withdrawal_pairings_from(cursor)
     }
 
-    pub(crate) async fn update_operators(&self, operators: Vec<OperatorStatus>) {
-        let mut cache = self.cache.write().await;
-        cache.update_operators(operators);
+    pub(crate) async fn update_operators(
+        &self,
+        status_db: &impl BridgeStatusDb,
+        operators: Vec<OperatorStatus>,
+    ) -> DbResult<()> {
+        status_db.put_operators(&operators)?;
+        self.cache.write().await.update_operators(operators);
+        Ok(())
     }
 
     pub(crate) async fn apply_withdrawal_updates(
@@ -182,8 +210,15 @@ impl BridgeMonitoringState {
         let mut cache_updates = Vec::new();
         let mut withdrawal_infos_to_persist = Vec::new();
         let mut terminal_deposit_indices_to_purge = Vec::new();
+        let mut archived_rows = Vec::new();
+        let mut tx_records = Vec::new();
 
         for update in updates {
+            tx_records.extend(TxRecord::index(
+                update.deposit_idx,
+                TransferStage::Withdrawal(update.info),
+                update.confirmations,
+            ));
             match update.info.status {
                 WithdrawalStatus::InProgress => {
                     cache_updates.push((update.deposit_idx, update.info, None));
@@ -195,6 +230,11 @@ impl BridgeMonitoringState {
 
                     if confirmations >= max_confirmations {
                         terminal_deposit_indices_to_purge.push(update.deposit_idx);
+                        archived_rows.push(ArchivedRow {
+                            deposit_idx: update.deposit_idx,
+                            info: update.info,
+                            confirmations,
+                        });
                     }
                     withdrawal_infos_to_persist.push((update.deposit_idx, update.info));
                     cache_updates.push((update.deposit_idx, update.info, Some(confirmations)));
@@ -224,6 +264,8 @@ impl BridgeMonitoringState {
         for (deposit_idx, info) in &withdrawal_infos_to_persist {
             status_db.put_withdrawal_info(*deposit_idx, info)?;
         }
+        status_db.put_tx_records(&tx_records)?;
+        status_db.put_archived_withdrawals(&archived_rows)?;
         status_db.put_withdrawal_status_cursor(next_cursor)?;
 
         let pairing_purge_frontier = next_cursor.next_deposit_idx;
@@ -266,8 +308,15 @@ impl BridgeMonitoringState {
     ) -> DbResult<()> {
         let mut cache_updates = Vec::new();
         let mut terminal_deposit_indices_to_purge = Vec::new();
+        let mut archived_rows = Vec::new();
+        let mut tx_records = Vec::new();
 
         for update in updates {
+            tx_records.extend(TxRecord::index(
+                update.deposit_idx,
+                TransferStage::Reimbursement(update.info),
+                update.confirmations,
+            ));
             match update.info.status {
                 ReimbursementStatus::NotStarted => continue,
                 ReimbursementStatus::InProgress => {
@@ -282,6 +331,11 @@ impl BridgeMonitoringState {
 
                     if confirmations >= max_confirmations {
                         terminal_deposit_indices_to_purge.push(update.deposit_idx);
+                        archived_rows.push(ArchivedRow {
+                            deposit_idx: update.deposit_idx,
+                            info: update.info,
+                            confirmations,
+                        });
                     } else {
                         cache_updates.push((update.deposit_idx, update.info, Some(confirmations)));
                     }
@@ -320,7 +374,17 @@ impl BridgeMonitoringState {
             )
         };
 
+        for (deposit_idx, info, _) in &cache_updates {
+            status_db.put_reimbursement_info(*deposit_idx, info)?;
+        }
+        status_db.put_tx_records(&tx_records)?;
+        status_db.put_archived_reimbursements(&archived_rows)?;
         status_db.put_reimbursement_status_cursor(next_cursor)?;
+        for deposit_idx in &terminal_deposit_indices_to_purge {
+            if let Err(e) = status_db.del_reimbursement_info(*deposit_idx) {
+                warn!(deposit_idx, error = %e, "failed to purge old reimbursement row");
+            }
+        }
 
         let mut purged_withdrawal_deposit_indices = Vec::new();
         for deposit_idx in withdrawal_deposit_indices_to_purge {
@@ -340,6 +404,77 @@ impl BridgeMonitoringState {
         Ok(())
     }
 
+    /// Joins the deposit, paired withdrawal request, withdrawal and
+    /// reimbursement rows of one deposit.
+    ///
+    /// Rows and the pairing fall back to the status DB archive once they are
+    /// purged from the cache.
+    pub(crate) async fn deposit_lifecycle(
+        &self,
+        status_db: &impl BridgeStatusDb,
+        withdrawal_index: &impl WithdrawalIndexerDb,
+        deposit_idx: DepositIdx,
+    ) -> DbResult<DepositLifecycle> {
+        let (deposit, withdrawal, reimbursement, withdrawal_seq) = {
+            let cache = self.cache.read().await;
+            (
+                cache.deposit(deposit_idx).map(|entry| StageRow {
+                    info: entry.data,
+                    confirmations: entry.confirmations,
+                }),
+                cache.withdrawal(deposit_idx).map(|entry| StageRow {
+                    info: entry.data,
+                    confirmations: entry.confirmations,
+                }),
+                cache.reimbursement(deposit_idx).map(|entry| StageRow {
+                    info: entry.data,
+                    confirmations: entry.confirmations,
+                }),
+                cache.withdrawal_pairing(deposit_idx),
+            )
+        };
+        let deposit = match deposit {
+            Some(deposit) => Some(deposit),
+            None => status_db.get_archived_deposit(deposit_idx)?.map(Into::into),
+        };
+        let withdrawal = match withdrawal {
+            Some(withdrawal) => Some(withdrawal),
+            None => status_db
+                .get_archived_withdrawal(deposit_idx)?
+                .map(Into::into),
+        };
+        let reimbursement = match reimbursement {
+            Some(reimbursement) => Some(reimbursement),
+            None => status_db
+                .get_archived_reimbursement(deposit_idx)?
+                .map(Into::into),
+        };
+        let withdrawal_seq = match withdrawal_seq {
+            Some(withdrawal_seq) => Some(withdrawal_seq),
+            None => status_db.get_withdrawal_seq(deposit_idx)?,
+        };
+        let withdrawal_request = match withdrawal_seq {
+            Some(withdrawal_seq) => withdrawal_index
+                .fetch_withdrawal_requests_from(withdrawal_seq, 1)?
+                .into_iter()
+                .find(|row| row.seq == withdrawal_seq)
+                .map(Into::into),
+            None => None,
+        };
+
+        Ok(DepositLifecycle {
+            deposit_idx,
+            deposit,
+            withdrawal_request,
+            withdrawal,
+            reimbursement,
+        })
+    }
+
+    pub(crate) async fn operators(&self) -> Vec<OperatorStatus> {
+        self.cache.read().await.get_operators()
+    }
+
     pub(crate) async fn bridge_status(&self, max_confirmations: u64) -> BridgeStatus {
         let cache = self.cache.read().await;
 
@@ -379,6 +514,7 @@ impl BridgeMonitoringState {
                 .into_iter()
                 .map(|(_, info)| info)
                 .collect(),
+            maintenance: Vec::new(),
         }
     }
 }
@@ -533,7 +669,13 @@ mod tests {
     use bitcoin::{hashes::Hash, Txid};
     use strata_primitives::buf::Buf32;
 
-    use crate::db::{traits::BridgeStatusDb, BridgeStatusDbSled};
+    use crate::{
+        db::{
+            traits::BridgeStatusDb, withdrawal_index::test_utils::make_withdrawal_request,
+            BridgeStatusDbSled, WithdrawalIndexerDbSled,
+        },
+        types::{ChallengeStep, TxRole},
+    };
 
     fn deposit_info(status: DepositStatus) -> DepositInfo {
         DepositInfo {
@@ -782,9 +924,23 @@ mod tests {
             status: WithdrawalStatus::Complete,
         };
 
+        status_db
+            .put_deposit_info(3, &deposit_info(DepositStatus::InProgress))
+            .expect("put deposit info");
         status_db
             .put_withdrawal_info(2, &withdrawal_info)
             .expect("put withdrawal info");
+        status_db
+            .put_reimbursement_info(
+                1,
+                &ReimbursementInfo {
+                    claim_txid: Txid::from_byte_array([5; 32]),
+                    challenge_step: ChallengeStep::Claimed,
+                    payout_txid: None,
+                    status: ReimbursementStatus::InProgress,
+                },
+            )
+            .expect("put reimbursement info");
         status_db
             .put_withdrawal_pairings(&[pairing(2, 7)])
             .expect("put withdrawal pairing");
@@ -834,13 +990,13 @@ mod tests {
         );
 
         let status = state.bridge_status(6).await;
-        assert!(status.deposits.is_empty());
+        assert_eq!(status.deposits.len(), 1);
         assert_eq!(status.withdrawals.len(), 1);
-        assert!(status.reimbursements.is_empty());
+        assert_eq!(status.reimbursements.len(), 1);
     }
 
     #[tokio::test]
-    async fn deposit_updates_persist_cursor_only() {
+    async fn deposit_updates_persist_rows_and_cursor() {
         let status_db = BridgeStatusDbSled::open_temporary().expect("open status db");
         let state = BridgeMonitoringState::default();
         let deposit_request_txid = Txid::from_byte_array([6; 32]);
@@ -861,12 +1017,13 @@ mod tests {
                 6,
             )
             .await
-            .expect("persist deposit cursor");
+            .expect("persist deposit row");
 
         let snapshot = status_db
             .get_status_snapshot()
             .expect("load status snapshot");
         assert_eq!(snapshot.cursors.deposit_info, 0);
+        assert_eq!(snapshot.deposits.len(), 1);
         assert!(snapshot.withdrawals.is_empty());
 
         state
@@ -884,16 +1041,137 @@ mod tests {
                 6,
             )
             .await
-            .expect("persist deposit cursor");
+            .expect("persist deposit row");
 
         let snapshot = status_db
             .get_status_snapshot()
             .expect("load status snapshot");
         assert_eq!(snapshot.cursors.deposit_info, 1);
+        assert!(snapshot.deposits.is_empty());
         assert!(snapshot.withdrawals.is_empty());
         assert_eq!(state.select_deposit_info_candidates(&[0, 1]).await, vec![1]);
     }
 
+    #[tokio::test]
+    async fn purged_deposits_stay_indexed_and_archived() {
+        let status_db = BridgeStatusDbSled::open_temporary().expect("open status db");
+        let state = BridgeMonitoringState::default();
+        let deposit_request_txid = Txid::from_byte_array([6; 32]);
+        let deposit_txid = Txid::from_byte_array([7; 32]);
+
+        state
+            .apply_deposit_info_updates(
+                &status_db,
+                vec![DepositInfoUpdate {
+                    deposit_idx: 4,
+                    info: DepositInfo {
+                        deposit_request_txid,
+                        deposit_txid: Some(deposit_txid),
+                        status: DepositStatus::Complete,
+                    },
+                    confirmations: Some(6),
+                }],
+                6,
+            )
+            .await
+            .expect("apply deposit update");
+        assert!(state.bridge_status(6).await.deposits.is_empty());
+
+        let record = status_db
+            .get_tx_record(&Buf32([7; 32]))
+            .expect("get deposit record")
+            .expect("deposit record indexed");
+        assert_eq!(record.deposit_idx, 4);
+        assert_eq!(record.role, TxRole::Deposit);
+        assert_eq!(record.confirmations, Some(6));
+        assert!(matches!(
+            record.stage,
+            TransferStage::Deposit(info) if info.deposit_request_txid == deposit_request_txid
+        ));
+
+        let record = status_db
+            .get_tx_record(&Buf32([6; 32]))
+            .expect("get deposit request record")
+            .expect("deposit request record indexed");
+        assert_eq!(record.role, TxRole::DepositRequest);
+
+        let archived = status_db
+            .archived_deposits_after(None, 10, |_| true)
+            .expect("archived deposits")
+            .rows;
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].deposit_idx, 4);
+        assert_eq!(archived[0].confirmations, 6);
+
+        let lifecycle = state
+            .deposit_lifecycle(
+                &status_db,
+                &WithdrawalIndexerDbSled::open_temporary().expect("open index db"),
+                4,
+            )
+            .await
+            .expect("join deposit lifecycle");
+        assert_eq!(
+            lifecycle.deposit.map(|row| row.confirmations),
+            Some(Some(6))
+        );
+    }
+
+    #[tokio::test]
+    async fn deposit_lifecycle_joins_rows_and_purged_pairing() {
+        let status_db = BridgeStatusDbSled::open_temporary().expect("open status db");
+        let withdrawal_index = WithdrawalIndexerDbSled::open_temporary().expect("open index db");
+        let state = BridgeMonitoringState::default();
+
+        withdrawal_index
+            .insert_withdrawal_event(&[make_withdrawal_request(1)])
+            .expect("insert withdrawal event");
+        withdrawal_index
+            .insert_withdrawal_event(&[make_withdrawal_request(2)])
+            .expect("insert withdrawal event");
+        status_db
+            .put_withdrawal_pairings(&[pairing(3, 1)])
+            .expect("put withdrawal pairing");
+        status_db
+            .del_withdrawal_pairings_range(0, 4)
+            .expect("purge withdrawal pairing");
+        state
+            .apply_deposit_info_updates(
+                &status_db,
+                vec![DepositInfoUpdate {
+                    deposit_idx: 3,
+                    info: deposit_info(DepositStatus::Complete),
+                    confirmations: Some(2),
+                }],
+                6,
+            )
+            .await
+            .expect("apply deposit update");
+
+        let lifecycle = state
+            .deposit_lifecycle(&status_db, &withdrawal_index, 3)
+            .await
+            .expect("join deposit lifecycle");
+        assert_eq!(
+            lifecycle.deposit.map(|row| row.confirmations),
+            Some(Some(2))
+        );
+        let withdrawal_request = lifecycle
+            .withdrawal_request
+            .expect("paired withdrawal request");
+        assert_eq!(withdrawal_request.withdrawal_seq, 1);
+        assert_eq!(withdrawal_request.tx_hash, Buf32([2; 32]));
+        assert_eq!(withdrawal_request.destination, "02".repeat(22));
+        assert!(lifecycle.withdrawal.is_none());
+        assert!(lifecycle.reimbursement.is_none());
+
+        assert!(state
+            .deposit_lifecycle(&status_db, &withdrawal_index, 4)
+            .await
+            .expect("join unknown deposit")
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn withdrawal_pairings_persist_rows_and_cursor() {
         let status_db = BridgeStatusDbSled::open_temporary().expect("open status db");
@@ -1,21 +1,35 @@
 use anyhow::Result;
-use axum::http::StatusCode;
 use axum::Json;
-use bitcoin::Txid;
-use std::{collections::BTreeSet, sync::Arc};
+use bitcoin::{hashes::Hash, Txid};
+use status_alerts::evaluate_rules;
+use status_feed::ComponentState;
+use status_utils::{unix_time_s, Component, EsploraClient, FreshStatus};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 use strata_bridge_primitives::types::DepositIdx;
-use strata_primitives::L1Height;
+use strata_bridge_rpc::types::RpcOperatorStatus;
+use strata_primitives::{buf::Buf32, L1Height};
 use strata_tasks::ShutdownGuard;
 
 use super::{
+    alerts::{
+        operator_alerts, operator_rule_samples, transfer_alerts, transfer_rule_samples,
+        OPERATOR_ALERT_SOURCE, OPERATOR_RULE_ALERT_SOURCE, TRANSFER_ALERT_SOURCE,
+        TRANSFER_RULE_ALERT_SOURCE,
+    },
     bridge_rpc,
     context::BridgeMonitoringContext,
-    esplora::{self, get_bitcoin_chain_tip_height, EsploraClient},
+    db::traits::{BridgeStatusDb, WithdrawalIndexerDb},
+    esplora::{self, get_bitcoin_chain_tip_height},
     state::{DepositInfoUpdate, ReimbursementInfoUpdate},
     types::{
         BridgeStatus, DepositInfo, DepositStatus, OperatorStatus, ReimbursementInfo,
-        ReimbursementStatus,
+        ReimbursementStatus, WithdrawalStatus,
     },
+    uptime::record_operator_uptime,
+    withdrawal_indexer::TASK_NAME,
     withdrawal_requests::fetch_withdrawal_requests,
     withdrawal_status::get_withdrawal_updates,
 };
@@ -50,7 +64,26 @@ pub async fn bridge_monitoring_task(
             ));
         }
 
-        context.state().update_operators(operator_statuses).await;
+        context
+            .alerts()
+            .reconcile(OPERATOR_ALERT_SOURCE, operator_alerts(&operator_statuses))
+            .await;
+        record_operator_polls(&context, &operator_statuses);
+        let indexer_lag_blocks = indexer_lag_blocks(&context);
+        let operator_rule_alerts = evaluate_rules(context.alerts().config().rules(), |metric| {
+            operator_rule_samples(&operator_statuses, indexer_lag_blocks, metric)
+        });
+        context
+            .alerts()
+            .reconcile(OPERATOR_RULE_ALERT_SOURCE, operator_rule_alerts)
+            .await;
+        if let Err(e) = context
+            .state()
+            .update_operators(context.status_db(), operator_statuses)
+            .await
+        {
+            warn!(error = %e, "failed to persist operator statuses");
+        }
 
         let chain_tip_height = match get_bitcoin_chain_tip_height(context.esplora()).await {
             Ok(height) => height,
@@ -192,12 +225,110 @@ pub async fn bridge_monitoring_task(
             warn!(error = %e, "failed to persist reimbursement status updates");
         }
 
-        context.mark_status_available();
+        let bridge_status = context.bridge_status().await;
+        let withdrawals_since = withdrawals_in_progress_since(&context, &bridge_status);
+        let deposits_since = deposits_in_progress_since(&context, &bridge_status);
+        let transfer_alerts = transfer_alerts(
+            &bridge_status,
+            context.alerts().config().withdrawal_stuck_threshold_s(),
+            &withdrawals_since,
+        );
+        context
+            .alerts()
+            .reconcile(TRANSFER_ALERT_SOURCE, transfer_alerts)
+            .await;
+        let transfer_rule_alerts = evaluate_rules(context.alerts().config().rules(), |metric| {
+            transfer_rule_samples(&bridge_status, &deposits_since, &withdrawals_since, metric)
+        });
+        context
+            .alerts()
+            .reconcile(TRANSFER_RULE_ALERT_SOURCE, transfer_rule_alerts)
+            .await;
+
+        context.record_successful_poll(unix_time_s());
     }
 
     Ok(())
 }
 
+/// Returns the persisted start of each withdrawal in progress in `status`, in
+/// unix seconds.
+fn withdrawals_in_progress_since(
+    context: &BridgeMonitoringContext,
+    status: &BridgeStatus,
+) -> BTreeMap<Buf32, u64> {
+    let request_txids = status
+        .withdrawals
+        .iter()
+        .filter(|withdrawal| matches!(withdrawal.status, WithdrawalStatus::InProgress))
+        .map(|withdrawal| withdrawal.withdrawal_request_txid)
+        .collect();
+    context
+        .status_db()
+        .track_withdrawals_in_progress(&request_txids, unix_time_s())
+        .unwrap_or_else(|e| {
+            warn!(error = %e, "failed to track withdrawals in progress");
+            BTreeMap::new()
+        })
+}
+
+/// Returns the persisted start of each deposit in progress in `status`, in
+/// unix seconds, keyed by the byte-order hash of its request txid.
+fn deposits_in_progress_since(
+    context: &BridgeMonitoringContext,
+    status: &BridgeStatus,
+) -> BTreeMap<Buf32, u64> {
+    let request_txids = status
+        .deposits
+        .iter()
+        .filter(|deposit| matches!(deposit.status, DepositStatus::InProgress))
+        .map(|deposit| Buf32(deposit.deposit_request_txid.to_byte_array()))
+        .collect();
+    context
+        .status_db()
+        .track_deposits_in_progress(&request_txids, unix_time_s())
+        .unwrap_or_else(|e| {
+            warn!(error = %e, "failed to track deposits in progress");
+            BTreeMap::new()
+        })
+}
+
+/// Records the operators that went offline or came back online since the last
+/// tick, for the status feeds, and folds the poll into the uptime history.
+fn record_operator_polls(context: &BridgeMonitoringContext, operators: &[OperatorStatus]) {
+    let now = unix_time_s();
+    for operator in operators {
+        let state = if matches!(operator.status(), RpcOperatorStatus::Offline) {
+            ComponentState::Offline
+        } else {
+            ComponentState::Online
+        };
+        context.events().observe(
+            Component::Operator(operator.operator_pk().to_string()),
+            operator.operator_id(),
+            state,
+            now,
+        );
+    }
+    if let Err(e) = record_operator_uptime(context.status_db(), now, operators) {
+        warn!(error = %e, "failed to record operator uptime");
+    }
+}
+
+/// Blocks the withdrawal indexer's latest scan is behind the EVM chain head,
+/// if it has scanned.
+fn indexer_lag_blocks(context: &BridgeMonitoringContext) -> Option<u64> {
+    match context.withdrawal_index().get_indexer_state(TASK_NAME) {
+        Ok(state) => {
+            state.and_then(|state| Some(state.chain_head?.saturating_sub(state.last_scanned_block)))
+        }
+        Err(e) => {
+            warn!(error = %e, "could not read withdrawal indexer state");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 fn count_deposit_indices_from(
     deposit_indices: &[DepositIdx],
@@ -347,21 +478,28 @@ fn terminal_reimbursement_txid(info: &ReimbursementInfo) -> Option<Txid> {
 }
 
 /// Return latest bridge status extracted from cache.
+///
+/// The status hydrated from the database is served right away. Only before
+/// any poll has completed does the handler wait for the first one, and it
+/// serves the status marked stale if that poll does not complete in time.
 pub async fn get_bridge_status(
     context: Arc<BridgeMonitoringContext>,
-) -> std::result::Result<Json<BridgeStatus>, StatusCode> {
-    let initial_status_wait_timeout = context.initial_status_wait_timeout();
-    if timeout(
-        initial_status_wait_timeout,
-        context.wait_until_initial_status(),
-    )
-    .await
-    .is_err()
-    {
-        return Err(StatusCode::SERVICE_UNAVAILABLE);
+) -> Json<FreshStatus<BridgeStatus>> {
+    if context.last_successful_poll_at().is_none() {
+        let _ = timeout(
+            context.initial_status_wait_timeout(),
+            context.wait_until_initial_status(),
+        )
+        .await;
     }
 
-    Ok(Json(context.bridge_status().await))
+    let mut status = context.bridge_status().await;
+    status.maintenance = context.maintenance().active_for_operators();
+    Json(FreshStatus::new(
+        status,
+        context.last_successful_poll_at(),
+        context.config().status_refetch_interval(),
+    ))
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
-use bitcoin::{PublicKey, Txid};
+use bitcoin::{hashes::Hash, PublicKey, Txid};
 use serde::{Deserialize, Serialize};
+use status_maintenance::MaintenanceWindow;
 use strata_bridge_primitives::types::DepositIdx;
 use strata_bridge_rpc::types::{
     RpcClaimPhase, RpcDepositInfo, RpcDepositStatus, RpcOperatorStatus, RpcReimbursementStatus,
@@ -7,6 +8,8 @@ use strata_bridge_rpc::types::{
 };
 use strata_primitives::buf::Buf32;
 
+use crate::db::types::DbWithdrawalRequestRow;
+
 /// FIFO withdrawal-request sequence number.
 pub(crate) type WithdrawalSeq = u64;
 
@@ -30,6 +33,18 @@ impl OperatorStatus {
             status,
         }
     }
+
+    pub(crate) fn operator_id(&self) -> &str {
+        &self.operator_id
+    }
+
+    pub(crate) fn operator_pk(&self) -> &PublicKey {
+        &self.operator_pk
+    }
+
+    pub(crate) fn status(&self) -> &RpcOperatorStatus {
+        &self.status
+    }
 }
 
 /// In-memory cursor for withdrawal-to-deposit pairing progress.
@@ -243,18 +258,196 @@ impl From<&RpcClaimPhase> for ChallengeStep {
     }
 }
 
+/// Role of a transaction in the lifecycle of a bridge deposit.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TxRole {
+    DepositRequest,
+    Deposit,
+    WithdrawalRequest,
+    Fulfillment,
+    Claim,
+    Payout,
+}
+
+/// Last observed row of the lifecycle stage a transaction belongs to.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub(crate) enum TransferStage {
+    Deposit(DepositInfo),
+    Withdrawal(WithdrawalInfo),
+    Reimbursement(ReimbursementInfo),
+}
+
+impl TransferStage {
+    /// Transactions of the stage keyed by their byte-order hash, with their role.
+    pub(crate) fn txids(&self) -> Vec<(Buf32, TxRole)> {
+        let txid = |txid: &Txid| Buf32(txid.to_byte_array());
+        match self {
+            Self::Deposit(info) => {
+                std::iter::once((txid(&info.deposit_request_txid), TxRole::DepositRequest))
+                    .chain(
+                        info.deposit_txid
+                            .as_ref()
+                            .map(|t| (txid(t), TxRole::Deposit)),
+                    )
+                    .collect()
+            }
+            Self::Withdrawal(info) => {
+                std::iter::once((info.withdrawal_request_txid, TxRole::WithdrawalRequest))
+                    .chain(
+                        info.fulfillment_txid
+                            .as_ref()
+                            .map(|t| (txid(t), TxRole::Fulfillment)),
+                    )
+                    .collect()
+            }
+            Self::Reimbursement(info) => std::iter::once((txid(&info.claim_txid), TxRole::Claim))
+                .chain(info.payout_txid.as_ref().map(|t| (txid(t), TxRole::Payout)))
+                .collect(),
+        }
+    }
+}
+
+/// Lifecycle record of a bridge transaction, indexed by its txid.
+///
+/// Records outlive the cache rows they were written from, so a transaction
+/// can still be looked up after its row is purged.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+pub(crate) struct TxRecord {
+    pub(crate) deposit_idx: DepositIdx,
+    pub(crate) role: TxRole,
+    pub(crate) confirmations: Option<u64>,
+    #[serde(flatten)]
+    pub(crate) stage: TransferStage,
+}
+
+impl TxRecord {
+    /// Records of every transaction of `stage`, keyed by transaction hash.
+    pub(crate) fn index(
+        deposit_idx: DepositIdx,
+        stage: TransferStage,
+        confirmations: Option<u64>,
+    ) -> impl Iterator<Item = (Buf32, Self)> {
+        stage.txids().into_iter().map(move |(txid, role)| {
+            (
+                txid,
+                Self {
+                    deposit_idx,
+                    role,
+                    confirmations,
+                    stage,
+                },
+            )
+        })
+    }
+}
+
+/// Status row of one lifecycle stage with its confirmations.
+#[derive(Serialize, Copy, Clone, Debug)]
+pub(crate) struct StageRow<T> {
+    #[serde(flatten)]
+    pub(crate) info: T,
+    pub(crate) confirmations: Option<u64>,
+}
+
+impl<T> From<ArchivedRow<T>> for StageRow<T> {
+    fn from(row: ArchivedRow<T>) -> Self {
+        Self {
+            info: row.info,
+            confirmations: Some(row.confirmations),
+        }
+    }
+}
+
+/// Terminal row of a lifecycle stage, archived once it reached
+/// `max_tx_confirmations` and kept after it is purged from the cache.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug)]
+pub(crate) struct ArchivedRow<T> {
+    pub(crate) deposit_idx: DepositIdx,
+    #[serde(flatten)]
+    pub(crate) info: T,
+    pub(crate) confirmations: u64,
+}
+
+/// Indexed EVM withdrawal request paired with a deposit.
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct WithdrawalRequestInfo {
+    pub(crate) withdrawal_seq: WithdrawalSeq,
+    pub(crate) tx_hash: Buf32,
+    pub(crate) log_index: u64,
+    pub(crate) amount_sats: u64,
+    /// Hex-encoded destination descriptor (BOSD).
+    pub(crate) destination: String,
+    pub(crate) selected_operator: u32,
+    pub(crate) block_number: u64,
+}
+
+impl From<DbWithdrawalRequestRow> for WithdrawalRequestInfo {
+    fn from(row: DbWithdrawalRequestRow) -> Self {
+        Self {
+            withdrawal_seq: row.seq,
+            tx_hash: row.request.tx_hash,
+            log_index: row.request.log_index,
+            amount_sats: row.request.amount_sats,
+            destination: hex::encode(&row.request.destination),
+            selected_operator: row.request.selected_operator,
+            block_number: row.request.block_number,
+        }
+    }
+}
+
+/// End-to-end lifecycle of one deposit, from the deposit to the operator
+/// payout. Stages the monitor has no row for are `None`.
+#[derive(Serialize, Clone, Debug)]
+pub(crate) struct DepositLifecycle {
+    pub(crate) deposit_idx: DepositIdx,
+    pub(crate) deposit: Option<StageRow<DepositInfo>>,
+    pub(crate) withdrawal_request: Option<WithdrawalRequestInfo>,
+    pub(crate) withdrawal: Option<StageRow<WithdrawalInfo>>,
+    pub(crate) reimbursement: Option<StageRow<ReimbursementInfo>>,
+}
+
+impl DepositLifecycle {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.deposit.is_none()
+            && self.withdrawal_request.is_none()
+            && self.withdrawal.is_none()
+            && self.reimbursement.is_none()
+    }
+
+    /// Transactions of every stage the deposit reached, with their role.
+    pub(crate) fn txids(&self) -> Vec<(Buf32, TxRole)> {
+        let deposit = self.deposit.map(|row| TransferStage::Deposit(row.info));
+        let withdrawal = self
+            .withdrawal
+            .map(|row| TransferStage::Withdrawal(row.info));
+        let reimbursement = self
+            .reimbursement
+            .map(|row| TransferStage::Reimbursement(row.info));
+        deposit
+            .into_iter()
+            .chain(withdrawal)
+            .chain(reimbursement)
+            .flat_map(|stage| stage.txids())
+            .collect()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct BridgeStatus {
     pub(crate) operators: Vec<OperatorStatus>,
     pub(crate) deposits: Vec<DepositInfo>,
     pub(crate) withdrawals: Vec<WithdrawalInfo>,
     pub(crate) reimbursements: Vec<ReimbursementInfo>,
+    /// Active maintenance windows covering a bridge operator.
+    #[serde(default)]
+    pub(crate) maintenance: Vec<MaintenanceWindow>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bitcoin::hashes::Hash;
 
     fn txid(byte: u8) -> Txid {
         Txid::from_byte_array([byte; 32])
@@ -313,6 +506,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tx_record_index_covers_stage_txids() {
+        let stage = TransferStage::Reimbursement(ReimbursementInfo {
+            claim_txid: txid(1),
+            challenge_step: ChallengeStep::NotApplicable,
+            payout_txid: Some(txid(2)),
+            status: ReimbursementStatus::Complete,
+        });
+
+        let records = TxRecord::index(7, stage, Some(3)).collect::<Vec<_>>();
+        assert_eq!(
+            records
+                .iter()
+                .map(|(key, record)| (*key, record.role))
+                .collect::<Vec<_>>(),
+            vec![
+                (Buf32([1; 32]), TxRole::Claim),
+                (Buf32([2; 32]), TxRole::Payout)
+            ]
+        );
+
+        let json = serde_json::to_value(records[1].1).expect("serialize record");
+        assert_eq!(json["deposit_idx"], 7);
+        assert_eq!(json["role"], "payout");
+        assert_eq!(json["stage"], "reimbursement");
+        assert_eq!(json["status"], "Complete");
+        assert_eq!(json["confirmations"], 3);
+
+        let decoded: TxRecord = serde_json::from_value(json).expect("deserialize record");
+        assert!(matches!(
+            decoded.stage,
+            TransferStage::Reimbursement(info) if info.payout_txid == Some(txid(2))
+        ));
+    }
+
     #[test]
     fn challenge_step_from_claim_phase_maps_all_variants() {
         let cases = [
@@ -0,0 +1,103 @@
+//! Operator uptime aggregated from the bridge monitor's polls.
+
+use std::collections::BTreeMap;
+
+use super::{
+    db::{error::DbResult, traits::BridgeStatusDb, types::DbOperatorCounts},
+    types::OperatorStatus,
+};
+
+/// Width of the aggregated uptime buckets.
+const BUCKET_S: u64 = 60 * 60;
+
+/// Trailing window of the reported uptime, as for network services. Older
+/// buckets are pruned.
+const UPTIME_WINDOW_S: u64 = 30 * 24 * 60 * 60;
+
+/// Folds one poll of `operators` into the uptime history and prunes buckets
+/// past the uptime window.
+pub(crate) fn record_operator_uptime(
+    db: &impl BridgeStatusDb,
+    now: u64,
+    operators: &[OperatorStatus],
+) -> DbResult<()> {
+    db.record_operator_uptime(now - now % BUCKET_S, operators)?;
+    db.prune_operator_uptime_before(now.saturating_sub(UPTIME_WINDOW_S))
+}
+
+/// Percentage of polls in the uptime window ending at `now` in which each
+/// operator was not offline, keyed by hex-encoded public key. Operators
+/// without polls are left out.
+pub(crate) fn operator_uptime_pcts(
+    db: &impl BridgeStatusDb,
+    now: u64,
+) -> DbResult<BTreeMap<String, f64>> {
+    let mut counts = BTreeMap::<String, DbOperatorCounts>::new();
+    for (_, bucket) in db.get_operator_uptime_since(now.saturating_sub(UPTIME_WINDOW_S))? {
+        for (operator_pk, bucket_counts) in bucket.operators {
+            counts.entry(operator_pk).or_default().merge(&bucket_counts);
+        }
+    }
+    Ok(counts
+        .into_iter()
+        .filter(|(_, counts)| counts.total() > 0)
+        .map(|(operator_pk, counts)| {
+            let uptime_pct = counts.online as f64 * 100.0 / counts.total() as f64;
+            (operator_pk, uptime_pct)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use bitcoin::PublicKey;
+    use strata_bridge_rpc::types::RpcOperatorStatus;
+
+    use super::*;
+    use crate::db::status::mock::MockBridgeStatusDb;
+
+    const OPERATOR_PK: &str = "02157d08fa2eb6071cef0750bcf1c0c9e94c2f05f629cdbdb3982851dce9472cd0";
+
+    fn operator(status: RpcOperatorStatus) -> OperatorStatus {
+        let operator_pk = PublicKey::from_str(OPERATOR_PK).expect("valid public key");
+        OperatorStatus::new("Operator #1".to_owned(), operator_pk, status)
+    }
+
+    #[test]
+    fn uptime_counts_polls_within_the_window() {
+        let db = MockBridgeStatusDb::default();
+        let now = 40 * 24 * 60 * 60;
+
+        // Outside the window: offline.
+        for i in 0..4 {
+            record_operator_uptime(
+                &db,
+                now - UPTIME_WINDOW_S - BUCKET_S + i,
+                &[operator(RpcOperatorStatus::Offline)],
+            )
+            .expect("record uptime");
+        }
+        // Within the window: 3 of 4 polls online.
+        for status in [
+            RpcOperatorStatus::Online,
+            RpcOperatorStatus::Online,
+            RpcOperatorStatus::Online,
+            RpcOperatorStatus::Offline,
+        ] {
+            record_operator_uptime(&db, now, &[operator(status)]).expect("record uptime");
+        }
+
+        assert_eq!(
+            operator_uptime_pcts(&db, now).expect("uptime"),
+            BTreeMap::from([(OPERATOR_PK.to_owned(), 75.0)])
+        );
+        assert_eq!(
+            db.get_operator_uptime_since(0)
+                .expect("uptime buckets")
+                .len(),
+            1
+        );
+    }
+}
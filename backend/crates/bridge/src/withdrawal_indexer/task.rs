@@ -124,10 +124,22 @@ where
 {
     let head = rpc.block_number().await?;
     let safe_head = head.saturating_sub(cfg.finality_lag());
-    let from = match db.get_indexer_state(TASK_NAME)? {
+    let state = db.get_indexer_state(TASK_NAME)?;
+    let from = match &state {
         None => cfg.start_block(),
         Some(state) => state.last_scanned_block.saturating_add(1),
     };
+    // Record the head before scanning, so the lag keeps growing while the
+    // indexer is caught up or its scans fail.
+    if let Some(state) = state.filter(|state| state.chain_head != Some(head)) {
+        db.put_indexer_state(
+            TASK_NAME,
+            &DbIndexerState {
+                chain_head: Some(head),
+                ..state
+            },
+        )?;
+    }
     if safe_head < from {
         debug!(from, safe_head, head, "tip caught up; skipping");
         return Ok(TickOutcome::CaughtUp);
@@ -159,6 +171,7 @@ where
         TASK_NAME,
         &DbIndexerState {
             last_scanned_block: to,
+            chain_head: Some(head),
         },
     )?;
     Ok(if to == safe_head {
@@ -274,7 +287,8 @@ withdrawal_denomination_sats = 100000000
         assert_eq!(
             db.get_indexer_state(TASK_NAME).expect("state"),
             Some(DbIndexerState {
-                last_scanned_block: 0
+                last_scanned_block: 0,
+                chain_head: Some(0),
             }),
             "cursor should advance after scanning block 0"
         );
@@ -316,6 +330,7 @@ withdrawal_denomination_sats = 100000000
             TASK_NAME,
             &DbIndexerState {
                 last_scanned_block: 0,
+                chain_head: None,
             },
         )
         .expect("rewind cursor");
@@ -406,6 +421,31 @@ withdrawal_denomination_sats = 100000000
         assert_eq!(db.max_withdrawal_seq().expect("max"), None);
     }
 
+    #[tokio::test]
+    async fn failed_scan_still_records_chain_head() {
+        let db = MockWithdrawalIndexerDb::default();
+        db.put_indexer_state(
+            TASK_NAME,
+            &DbIndexerState {
+                last_scanned_block: 10,
+                chain_head: Some(10),
+            },
+        )
+        .expect("seed cursor");
+        let dup = make_log(TEST_WITHDRAWAL_DENOMINATION_SATS, 0xAA, 5, 11);
+        let rpc = MockEthRpc::new(vec![(600, vec![dup.clone(), dup])]);
+
+        tick(&db, &rpc, &cfg()).await.expect_err("expected error");
+
+        assert_eq!(
+            db.get_indexer_state(TASK_NAME).expect("state"),
+            Some(DbIndexerState {
+                last_scanned_block: 10,
+                chain_head: Some(600),
+            })
+        );
+    }
+
     #[tokio::test]
     async fn tick_signals_caught_up_at_safe_head() {
         let db = MockWithdrawalIndexerDb::default();
@@ -1,12 +1,13 @@
 use std::collections::BTreeMap;
 
+use status_utils::EsploraClient;
 use strata_primitives::L1Height;
 use tracing::warn;
 
 use super::{
     bridge_rpc::{self, RpcClientManager},
     db::traits::WithdrawalIndexerDb,
-    esplora::{self, EsploraClient},
+    esplora,
     state::WithdrawalInfoUpdate,
     types::{WithdrawalInfo, WithdrawalPairing, WithdrawalStatus},
     withdrawal_requests,
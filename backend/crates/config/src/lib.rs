@@ -1,9 +1,9 @@
 use bitcoin::PublicKey;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
-use tracing::{debug, trace};
+use tracing::{debug, trace, warn};
 
-use status_utils::ExponentialBackoff;
+use status_utils::{Component, ExponentialBackoff};
 
 /// Main configuration struct containing all application settings
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -22,6 +22,22 @@ pub struct Config {
 
     /// Withdrawal-intent indexer configuration
     withdrawal_indexer: WithdrawalIndexerConfig,
+
+    /// Alerting configuration
+    #[serde(default)]
+    alerts: AlertsConfig,
+
+    /// Planned maintenance windows
+    #[serde(default)]
+    maintenance: Vec<MaintenanceWindowConfig>,
+
+    /// Atom and RSS feed configuration
+    #[serde(default)]
+    feed: FeedConfig,
+
+    /// Statuspage-compatible API configuration
+    #[serde(default)]
+    statuspage: StatusPageConfig,
 }
 
 /// Configuration for the API server
@@ -32,6 +48,9 @@ pub struct ApiServerConfig {
 
     /// Port number to bind the server to
     port: u16,
+
+    /// Bearer token of the admin API. The admin API is disabled without one.
+    admin_token: Option<String>,
 }
 
 impl ApiServerConfig {
@@ -44,6 +63,11 @@ impl ApiServerConfig {
     pub fn port(&self) -> u16 {
         self.port
     }
+
+    /// Get the admin API bearer token, if the admin API is enabled
+    pub fn admin_token(&self) -> Option<&str> {
+        self.admin_token.as_deref()
+    }
 }
 
 /// Default retry policy base for exponential backoff
@@ -58,6 +82,28 @@ const DEFAULT_NETWORK_INITIAL_STATUS_WAIT_TIMEOUT_S: u64 = 5;
 /// Default time a bridge status request waits for the first poll result.
 const DEFAULT_BRIDGE_INITIAL_STATUS_WAIT_TIMEOUT_S: u64 = 30;
 
+/// Default number of polls a chain head may stay unchanged before it is degraded.
+const DEFAULT_STALL_THRESHOLD_POLLS: u64 = 6;
+
+/// Default time in seconds a chain head may stay unchanged before it is degraded.
+const DEFAULT_STALL_THRESHOLD_S: u64 = 120;
+
+/// Default number of recent probes latency percentiles are computed over.
+const DEFAULT_LATENCY_WINDOW_POLLS: usize = 100;
+
+/// Default time in seconds without a new L1 block before L1 is flagged stalled.
+const DEFAULT_L1_NO_NEW_BLOCK_THRESHOLD_S: u64 = 60 * 60;
+
+/// Default number of L1 blocks the sequencer may trail the Esplora tip.
+const DEFAULT_L1_READER_LAG_THRESHOLD_BLOCKS: u64 = 3;
+
+/// Default time in seconds without a checkpoint landing on L1 before
+/// checkpoints are degraded.
+const DEFAULT_CHECKPOINT_STALE_THRESHOLD_S: u64 = 2 * 60 * 60;
+
+/// Default number of recent L2 blocks chain metrics are computed over.
+const DEFAULT_METRICS_WINDOW_BLOCKS: usize = 100;
+
 /// Default indexed WRT rows to read per withdrawal-index DB request.
 const DEFAULT_WITHDRAWAL_PAIRING_BATCH_SIZE: usize = 1_000;
 
@@ -67,6 +113,27 @@ fn default_esplora_request_timeout_s() -> u64 {
 fn default_network_initial_status_wait_timeout_s() -> u64 {
     DEFAULT_NETWORK_INITIAL_STATUS_WAIT_TIMEOUT_S
 }
+fn default_stall_threshold_polls() -> u64 {
+    DEFAULT_STALL_THRESHOLD_POLLS
+}
+fn default_stall_threshold_s() -> u64 {
+    DEFAULT_STALL_THRESHOLD_S
+}
+fn default_latency_window_polls() -> usize {
+    DEFAULT_LATENCY_WINDOW_POLLS
+}
+fn default_l1_no_new_block_threshold_s() -> u64 {
+    DEFAULT_L1_NO_NEW_BLOCK_THRESHOLD_S
+}
+fn default_l1_reader_lag_threshold_blocks() -> u64 {
+    DEFAULT_L1_READER_LAG_THRESHOLD_BLOCKS
+}
+fn default_checkpoint_stale_threshold_s() -> u64 {
+    DEFAULT_CHECKPOINT_STALE_THRESHOLD_S
+}
+fn default_metrics_window_blocks() -> usize {
+    DEFAULT_METRICS_WINDOW_BLOCKS
+}
 fn default_bridge_initial_status_wait_timeout_s() -> u64 {
     DEFAULT_BRIDGE_INITIAL_STATUS_WAIT_TIMEOUT_S
 }
@@ -86,18 +153,219 @@ pub struct NetworkMonitoringConfig {
     /// Bundler health check URL
     bundler_url: String,
 
+    /// Bundler JSON-RPC endpoint. When set together with
+    /// `bundler_entry_point`, the default bundler probe also checks the
+    /// supported entry points and chain id.
+    bundler_rpc_url: Option<String>,
+
+    /// ERC-4337 entry point address the bundler must support.
+    bundler_entry_point: Option<String>,
+
     /// Max retries for status queries
     retry_policy_max_retries: u64,
 
     /// Total time in seconds to spend retrying status queries
     retry_policy_total_time_s: u64,
 
-    /// Network status refetch interval in seconds
+    /// Default probe interval and history sampling interval in seconds
     status_refetch_interval_s: u64,
 
-    /// Timeout for HTTP status requests waiting on the first poll result.
+    /// Timeout for HTTP status requests waiting on the first poll result when
+    /// no poll has ever completed.
     #[serde(default = "default_network_initial_status_wait_timeout_s")]
     initial_status_wait_timeout_s: u64,
+
+    /// Polls a probe's head may stay unchanged before the service is reported
+    /// degraded. `0` disables the poll-count check.
+    #[serde(default = "default_stall_threshold_polls")]
+    stall_threshold_polls: u64,
+
+    /// Seconds a probe's head may stay unchanged before the service is
+    /// reported degraded. `0` disables the wall-clock check.
+    #[serde(default = "default_stall_threshold_s")]
+    stall_threshold_s: u64,
+
+    /// Number of recent probes per service that latency percentiles are
+    /// computed over.
+    #[serde(default = "default_latency_window_polls")]
+    latency_window_polls: usize,
+
+    /// Chain id every identity-checked endpoint must report from
+    /// `eth_chainId`.
+    expected_chain_id: Option<u64>,
+
+    /// Hash of block 0 every identity-checked JSON-RPC endpoint must report.
+    expected_genesis_hash: Option<String>,
+
+    /// Age in seconds of the Esplora tip block after which L1 is flagged as
+    /// producing no new blocks. `0` disables the flag.
+    #[serde(default = "default_l1_no_new_block_threshold_s")]
+    l1_no_new_block_threshold_s: u64,
+
+    /// L1 blocks the sequencer's L1 reader may trail the Esplora tip before it
+    /// is flagged as lagging. `0` disables the flag.
+    #[serde(default = "default_l1_reader_lag_threshold_blocks")]
+    l1_reader_lag_threshold_blocks: u64,
+
+    /// Seconds since the latest checkpoint landed on L1 after which
+    /// checkpoints are reported degraded. `0` disables the age check.
+    #[serde(default = "default_checkpoint_stale_threshold_s")]
+    checkpoint_stale_threshold_s: u64,
+
+    /// Number of recent L2 blocks block time, throughput and fee metrics are
+    /// computed over.
+    #[serde(default = "default_metrics_window_blocks")]
+    metrics_window_blocks: usize,
+
+    /// Health probes, one per monitored service. When empty, the sequencer,
+    /// RPC and bundler URLs above are probed as before.
+    #[serde(default, deserialize_with = "deserialize_unique_names")]
+    probes: Vec<ProbeConfig>,
+}
+
+/// JSON-RPC method whose result carries the OL chain status.
+const STRATA_CHAIN_STATUS_METHOD: &str = "strata_getChainStatus";
+
+/// Health probe for one network service.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProbeConfig {
+    /// Service name the probe result is reported under.
+    name: String,
+
+    /// How the service is probed.
+    #[serde(flatten)]
+    kind: ProbeKind,
+
+    /// Predicate a response must satisfy for the service to be online. Without
+    /// one, any successful response counts.
+    expect: Option<ProbePredicate>,
+
+    /// Path to a monotonically increasing head in the response, used to
+    /// detect stalls.
+    head_path: Option<String>,
+
+    /// Round trip in milliseconds above which an online service is reported
+    /// degraded.
+    degraded_latency_ms: Option<u64>,
+
+    /// Probe interval in seconds. Defaults to `status_refetch_interval_s`.
+    interval_s: Option<u64>,
+
+    /// Max retries for this probe. Defaults to `retry_policy_max_retries`.
+    retry_policy_max_retries: Option<u64>,
+
+    /// Total time in seconds to spend retrying this probe. Defaults to
+    /// `retry_policy_total_time_s`.
+    retry_policy_total_time_s: Option<u64>,
+
+    /// Compare the endpoint's chain id and genesis hash against
+    /// `expected_chain_id` and `expected_genesis_hash`, reporting the service
+    /// misconfigured on mismatch. Applies to `json_rpc` probes and to the RPC
+    /// endpoint of `bundler` probes, which are checked for the chain id only.
+    #[serde(default)]
+    check_chain_identity: bool,
+}
+
+/// Kind of request a probe makes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ProbeKind {
+    /// JSON-RPC call. Paths are resolved against the call's `result`.
+    JsonRpc {
+        url: String,
+        method: String,
+        #[serde(default)]
+        params: Vec<serde_json::Value>,
+    },
+
+    /// HTTP GET. Paths are resolved against the JSON response body.
+    Http {
+        url: String,
+        /// Required status code. Any 2xx status is accepted when unset.
+        expected_status: Option<u16>,
+    },
+
+    /// Raw TCP connect to `host:port`.
+    Tcp { address: String },
+
+    /// ERC-4337 bundler. Its health endpoint must report healthy,
+    /// `eth_supportedEntryPoints` must include `entry_point`, and
+    /// `eth_chainId` must match the chain id of `chain_rpc_url`. `expect` and
+    /// `head_path` are ignored.
+    Bundler {
+        health_url: String,
+        rpc_url: String,
+        entry_point: String,
+        chain_rpc_url: String,
+    },
+}
+
+/// Predicate on a probe response.
+///
+/// Paths are dot-separated object keys or array indices (`tip.slot`,
+/// `entries.0`); an empty path is the whole response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProbePredicate {
+    /// The value at `path` is present and not null.
+    Exists {
+        #[serde(default)]
+        path: String,
+    },
+
+    /// The value at `path` equals `value`.
+    Equals {
+        #[serde(default)]
+        path: String,
+        value: serde_json::Value,
+    },
+
+    /// The string at `path` contains `value`. Without a path, HTTP probes match
+    /// against the raw response body.
+    Contains { path: Option<String>, value: String },
+
+    /// The value at `path` is a `0x`-prefixed hex quantity.
+    HexQuantity {
+        #[serde(default)]
+        path: String,
+    },
+
+    /// The response is a health report that says the service is healthy: a
+    /// plain `ok` body, a JSON `"ok"` string, or a JSON object whose `status`
+    /// is `ok`, `healthy` or `up`, ignoring case.
+    Healthy,
+}
+
+impl ProbeConfig {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> &ProbeKind {
+        &self.kind
+    }
+
+    pub fn expect(&self) -> Option<&ProbePredicate> {
+        self.expect.as_ref()
+    }
+
+    pub fn head_path(&self) -> Option<&str> {
+        self.head_path.as_deref()
+    }
+
+    pub fn degraded_latency_ms(&self) -> Option<u64> {
+        self.degraded_latency_ms
+    }
+
+    pub fn check_chain_identity(&self) -> bool {
+        self.check_chain_identity
+    }
+
+    /// Returns `true` if the probe calls `strata_getChainStatus`, whose result
+    /// is also reported as OL chain progress.
+    pub fn is_chain_status(&self) -> bool {
+        matches!(&self.kind, ProbeKind::JsonRpc { method, .. } if method == STRATA_CHAIN_STATUS_METHOD)
+    }
 }
 
 impl NetworkMonitoringConfig {
@@ -121,20 +389,134 @@ impl NetworkMonitoringConfig {
         self.initial_status_wait_timeout_s
     }
 
-    /// Retry policy for sequencer status queries
-    pub fn sequencer_retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::new(
-            self.retry_policy_max_retries,
-            self.retry_policy_total_time_s,
-            DEFAULT_RETRY_POLICY_BASE,
-        )
+    pub fn stall_threshold_polls(&self) -> u64 {
+        self.stall_threshold_polls
+    }
+
+    pub fn stall_threshold_s(&self) -> u64 {
+        self.stall_threshold_s
+    }
+
+    pub fn latency_window_polls(&self) -> usize {
+        self.latency_window_polls
+    }
+
+    pub fn expected_chain_id(&self) -> Option<u64> {
+        self.expected_chain_id
+    }
+
+    pub fn expected_genesis_hash(&self) -> Option<&str> {
+        self.expected_genesis_hash.as_deref()
+    }
+
+    pub fn l1_no_new_block_threshold_s(&self) -> u64 {
+        self.l1_no_new_block_threshold_s
+    }
+
+    pub fn l1_reader_lag_threshold_blocks(&self) -> u64 {
+        self.l1_reader_lag_threshold_blocks
+    }
+
+    pub fn checkpoint_stale_threshold_s(&self) -> u64 {
+        self.checkpoint_stale_threshold_s
+    }
+
+    pub fn metrics_window_blocks(&self) -> usize {
+        self.metrics_window_blocks
+    }
+
+    /// Configured probes, or the sequencer, RPC and bundler checks when none
+    /// are configured.
+    pub fn probes(&self) -> Vec<ProbeConfig> {
+        if !self.probes.is_empty() {
+            return self.probes.clone();
+        }
+
+        vec![
+            ProbeConfig {
+                name: "sequencer".to_owned(),
+                kind: ProbeKind::JsonRpc {
+                    url: self.sequencer_url.clone(),
+                    method: STRATA_CHAIN_STATUS_METHOD.to_owned(),
+                    params: Vec::new(),
+                },
+                expect: Some(ProbePredicate::Exists {
+                    path: "tip.slot".to_owned(),
+                }),
+                head_path: Some("tip.slot".to_owned()),
+                degraded_latency_ms: None,
+                interval_s: None,
+                retry_policy_max_retries: None,
+                retry_policy_total_time_s: None,
+                check_chain_identity: false,
+            },
+            ProbeConfig {
+                name: "rpc_endpoint".to_owned(),
+                kind: ProbeKind::JsonRpc {
+                    url: self.rpc_url.clone(),
+                    method: "eth_blockNumber".to_owned(),
+                    params: Vec::new(),
+                },
+                expect: Some(ProbePredicate::HexQuantity {
+                    path: String::new(),
+                }),
+                head_path: Some(String::new()),
+                degraded_latency_ms: None,
+                interval_s: None,
+                retry_policy_max_retries: None,
+                retry_policy_total_time_s: None,
+                check_chain_identity: true,
+            },
+            ProbeConfig {
+                name: "bundler_endpoint".to_owned(),
+                kind: match (&self.bundler_rpc_url, &self.bundler_entry_point) {
+                    (Some(rpc_url), Some(entry_point)) => ProbeKind::Bundler {
+                        health_url: self.bundler_url.clone(),
+                        rpc_url: rpc_url.clone(),
+                        entry_point: entry_point.clone(),
+                        chain_rpc_url: self.rpc_url.clone(),
+                    },
+                    _ => ProbeKind::Http {
+                        url: self.bundler_url.clone(),
+                        expected_status: None,
+                    },
+                },
+                expect: Some(ProbePredicate::Healthy),
+                head_path: None,
+                degraded_latency_ms: None,
+                interval_s: None,
+                retry_policy_max_retries: None,
+                retry_policy_total_time_s: None,
+                check_chain_identity: true,
+            },
+        ]
+    }
+
+    /// Warns if only one of `bundler_rpc_url` and `bundler_entry_point` is
+    /// set, since the bundler checks need both and are silently skipped.
+    fn warn_on_partial_bundler_checks(&self) {
+        if self.bundler_rpc_url.is_some() != self.bundler_entry_point.is_some() {
+            warn!(
+                "only one of bundler_rpc_url and bundler_entry_point is set; \
+                 the bundler probe only checks the health endpoint"
+            );
+        }
     }
 
-    /// Retry policy for RPC endpoint status queries
-    pub fn rpc_retry_policy(&self) -> ExponentialBackoff {
+    /// Interval in seconds between runs of `probe`
+    pub fn probe_interval_s(&self, probe: &ProbeConfig) -> u64 {
+        probe.interval_s.unwrap_or(self.status_refetch_interval_s)
+    }
+
+    /// Retry policy for `probe` requests
+    pub fn probe_retry_policy(&self, probe: &ProbeConfig) -> ExponentialBackoff {
         ExponentialBackoff::new(
-            self.retry_policy_max_retries,
-            self.retry_policy_total_time_s,
+            probe
+                .retry_policy_max_retries
+                .unwrap_or(self.retry_policy_max_retries),
+            probe
+                .retry_policy_total_time_s
+                .unwrap_or(self.retry_policy_total_time_s),
             DEFAULT_RETRY_POLICY_BASE,
         )
     }
@@ -156,7 +538,8 @@ pub struct BridgeMonitoringConfig {
     /// Bridge status refetch interval in seconds
     status_refetch_interval_s: u64,
 
-    /// Timeout for HTTP status requests waiting on the first poll result.
+    /// Timeout for HTTP status requests waiting on the first poll result when
+    /// no poll has ever completed.
     #[serde(default = "default_bridge_initial_status_wait_timeout_s")]
     initial_status_wait_timeout_s: u64,
 
@@ -252,6 +635,369 @@ impl WithdrawalIndexerConfig {
     }
 }
 
+/// Default minimum time between two firing notifications of the same alert.
+const DEFAULT_ALERT_COOLDOWN_S: u64 = 15 * 60;
+
+/// Default time after which an in-progress withdrawal is reported as stuck.
+const DEFAULT_WITHDRAWAL_STUCK_THRESHOLD_S: u64 = 6 * 60 * 60;
+
+/// Default timeout for webhook requests in seconds.
+const DEFAULT_WEBHOOK_TIMEOUT_S: u64 = 10;
+
+fn default_alert_cooldown_s() -> u64 {
+    DEFAULT_ALERT_COOLDOWN_S
+}
+fn default_withdrawal_stuck_threshold_s() -> u64 {
+    DEFAULT_WITHDRAWAL_STUCK_THRESHOLD_S
+}
+fn default_webhook_timeout_s() -> u64 {
+    DEFAULT_WEBHOOK_TIMEOUT_S
+}
+
+/// Configuration for alert notifications
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AlertsConfig {
+    /// Minimum time in seconds between two firing notifications of the same
+    /// alert, so a flapping condition does not flood the webhooks.
+    #[serde(default = "default_alert_cooldown_s")]
+    cooldown_s: u64,
+
+    /// Time in seconds after which an in-progress withdrawal is reported as
+    /// stuck.
+    #[serde(default = "default_withdrawal_stuck_threshold_s")]
+    withdrawal_stuck_threshold_s: u64,
+
+    /// Timeout for webhook requests in seconds.
+    #[serde(default = "default_webhook_timeout_s")]
+    webhook_timeout_s: u64,
+
+    /// Webhooks notified when an alert fires or resolves.
+    #[serde(default)]
+    webhooks: Vec<WebhookConfig>,
+
+    /// Threshold rules evaluated on each monitoring tick.
+    #[serde(default, deserialize_with = "deserialize_unique_names")]
+    rules: Vec<AlertRule>,
+}
+
+impl Default for AlertsConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_s: DEFAULT_ALERT_COOLDOWN_S,
+            withdrawal_stuck_threshold_s: DEFAULT_WITHDRAWAL_STUCK_THRESHOLD_S,
+            webhook_timeout_s: DEFAULT_WEBHOOK_TIMEOUT_S,
+            webhooks: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl AlertsConfig {
+    pub fn cooldown_s(&self) -> u64 {
+        self.cooldown_s
+    }
+
+    pub fn withdrawal_stuck_threshold_s(&self) -> u64 {
+        self.withdrawal_stuck_threshold_s
+    }
+
+    pub fn webhook_timeout_s(&self) -> u64 {
+        self.webhook_timeout_s
+    }
+
+    pub fn webhooks(&self) -> &[WebhookConfig] {
+        &self.webhooks
+    }
+
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+}
+
+/// Alert raised when a monitored metric crosses a threshold.
+///
+/// A rule without `above` or `below` fires while the metric is non-zero,
+/// which suits the per-subject conditions such as `operator_offline`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AlertRule {
+    /// Identifies the rule in alert keys and messages.
+    name: String,
+
+    metric: AlertMetric,
+
+    /// Fires while the metric is above this value.
+    above: Option<f64>,
+
+    /// Fires while the metric is below this value.
+    below: Option<f64>,
+
+    /// Seconds the condition must hold before the alert fires.
+    #[serde(default)]
+    for_s: u64,
+
+    #[serde(default)]
+    severity: AlertSeverity,
+
+    /// Message of the alert. Defaults to the rule name.
+    description: Option<String>,
+}
+
+impl AlertRule {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn metric(&self) -> AlertMetric {
+        self.metric
+    }
+
+    pub fn above(&self) -> Option<f64> {
+        self.above
+    }
+
+    pub fn below(&self) -> Option<f64> {
+        self.below
+    }
+
+    pub fn for_s(&self) -> u64 {
+        self.for_s
+    }
+
+    pub fn severity(&self) -> AlertSeverity {
+        self.severity
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+}
+
+/// Metric an alert rule is evaluated against.
+///
+/// Per-subject metrics yield one value per service, operator or transfer and
+/// raise a separate alert for each.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertMetric {
+    /// 1 for each offline service, 0 otherwise.
+    ServiceOffline,
+    /// 95th percentile probe latency of each service in milliseconds.
+    ServiceLatencyP95Ms,
+    /// Seconds since the header time of the Bitcoin tip.
+    L1TipAgeS,
+    /// Blocks the sequencer's L1 reader is behind the Esplora tip.
+    L1ReaderLagBlocks,
+    /// Seconds since the latest checkpoint landed on L1.
+    CheckpointAgeS,
+    /// 1 for each offline bridge operator, 0 otherwise.
+    OperatorOffline,
+    /// 1 for each deposit in progress.
+    DepositInProgress,
+    /// 1 for each withdrawal in progress.
+    WithdrawalInProgress,
+    /// Number of withdrawals in progress.
+    WithdrawalQueueDepth,
+    /// Blocks between the EVM chain head and the withdrawal indexer.
+    IndexerLagBlocks,
+}
+
+/// How urgently an alert needs attention.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    #[default]
+    Warning,
+    Critical,
+}
+
+impl AlertSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+/// Webhook an alert notification is POSTed to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WebhookConfig {
+    url: String,
+
+    /// Payload template of the notification.
+    #[serde(default)]
+    format: WebhookFormat,
+}
+
+impl WebhookConfig {
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn format(&self) -> WebhookFormat {
+        self.format
+    }
+}
+
+/// Payload template of a webhook notification.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// The alert as a JSON object.
+    #[default]
+    Json,
+    /// A Slack incoming-webhook message.
+    Slack,
+    /// A Discord webhook message.
+    Discord,
+}
+
+/// Default title of the Atom and RSS feeds.
+const DEFAULT_FEED_TITLE: &str = "Alpen Status";
+
+/// Default number of entries in the Atom and RSS feeds.
+const DEFAULT_FEED_MAX_ENTRIES: usize = 50;
+
+fn default_feed_title() -> String {
+    DEFAULT_FEED_TITLE.to_owned()
+}
+fn default_feed_max_entries() -> usize {
+    DEFAULT_FEED_MAX_ENTRIES
+}
+
+/// Configuration for the Atom and RSS feeds of incidents and status changes
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeedConfig {
+    #[serde(default = "default_feed_title")]
+    title: String,
+
+    /// Public URL of the status page the feed entries link to.
+    link: Option<String>,
+
+    /// Number of most recent entries in a feed.
+    #[serde(default = "default_feed_max_entries")]
+    max_entries: usize,
+}
+
+impl Default for FeedConfig {
+    fn default() -> Self {
+        Self {
+            title: default_feed_title(),
+            link: None,
+            max_entries: DEFAULT_FEED_MAX_ENTRIES,
+        }
+    }
+}
+
+impl FeedConfig {
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn link(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+}
+
+/// Default page id of the Statuspage-compatible API.
+const DEFAULT_STATUS_PAGE_ID: &str = "alpen-status";
+
+/// Default page name of the Statuspage-compatible API.
+const DEFAULT_STATUS_PAGE_NAME: &str = "Alpen Status";
+
+fn default_status_page_id() -> String {
+    DEFAULT_STATUS_PAGE_ID.to_owned()
+}
+fn default_status_page_name() -> String {
+    DEFAULT_STATUS_PAGE_NAME.to_owned()
+}
+
+/// Configuration for the page described by the Statuspage-compatible API
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StatusPageConfig {
+    #[serde(default = "default_status_page_id")]
+    page_id: String,
+
+    #[serde(default = "default_status_page_name")]
+    name: String,
+
+    /// Public URL of the status page.
+    url: Option<String>,
+}
+
+impl Default for StatusPageConfig {
+    fn default() -> Self {
+        Self {
+            page_id: default_status_page_id(),
+            name: default_status_page_name(),
+            url: None,
+        }
+    }
+}
+
+impl StatusPageConfig {
+    pub fn page_id(&self) -> &str {
+        &self.page_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+}
+
+/// Planned maintenance of network services and bridge operators.
+///
+/// While the window is active, alerts of the covered components are
+/// suppressed and the status endpoints carry the window's message.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MaintenanceWindowConfig {
+    /// Unix time in seconds the window starts.
+    starts_at: u64,
+
+    /// Unix time in seconds the window ends.
+    ends_at: u64,
+
+    message: String,
+
+    /// Probe names of the covered network services.
+    #[serde(default)]
+    services: Vec<String>,
+
+    /// Hex-encoded public keys of the covered bridge operators.
+    #[serde(default)]
+    operators: Vec<PublicKey>,
+}
+
+impl MaintenanceWindowConfig {
+    pub fn starts_at(&self) -> u64 {
+        self.starts_at
+    }
+
+    pub fn ends_at(&self) -> u64 {
+        self.ends_at
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn services(&self) -> &[String] {
+        &self.services
+    }
+
+    pub fn operators(&self) -> &[PublicKey] {
+        &self.operators
+    }
+}
+
 /// Configuration for a bridge operator
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BridgeOperator {
@@ -312,7 +1058,9 @@ impl BridgeMonitoringConfig {
 impl Config {
     /// Load configuration from the specified path
     pub fn load_from_path(path: &str) -> Self {
-        parse_toml::<Config>(path)
+        let config = parse_toml::<Config>(path);
+        config.network.warn_on_partial_bundler_checks();
+        config
     }
 
     pub fn datadir(&self) -> &Path {
@@ -334,6 +1082,107 @@ impl Config {
     pub fn withdrawal_indexer(&self) -> &WithdrawalIndexerConfig {
         &self.withdrawal_indexer
     }
+
+    pub fn alerts(&self) -> &AlertsConfig {
+        &self.alerts
+    }
+
+    pub fn maintenance(&self) -> &[MaintenanceWindowConfig] {
+        &self.maintenance
+    }
+
+    pub fn feed(&self) -> &FeedConfig {
+        &self.feed
+    }
+
+    pub fn statuspage(&self) -> &StatusPageConfig {
+        &self.statuspage
+    }
+
+    /// Network services, by probe name, and bridge operators that incidents
+    /// and maintenance windows can refer to.
+    pub fn components(&self) -> Vec<Component> {
+        let services = self
+            .network_probes()
+            .into_iter()
+            .map(|probe| Component::Service(probe.name));
+        let operators = self
+            .bridge
+            .operators()
+            .iter()
+            .map(|operator| Component::Operator(operator.public_key().to_string()));
+        services.chain(operators).collect()
+    }
+
+    /// Network probes. Without configured probes, the withdrawal indexer's EVM
+    /// endpoint is added as an identity-checked probe when it differs from
+    /// `network.rpc_url`.
+    pub fn network_probes(&self) -> Vec<ProbeConfig> {
+        let mut probes = self.network.probes();
+        let indexer_url = self.withdrawal_indexer.eth_rpc_url();
+        if self.network.probes.is_empty() && indexer_url != self.network.rpc_url {
+            probes.push(ProbeConfig {
+                name: "withdrawal_indexer_rpc".to_owned(),
+                kind: ProbeKind::JsonRpc {
+                    url: indexer_url.to_owned(),
+                    method: "eth_blockNumber".to_owned(),
+                    params: Vec::new(),
+                },
+                expect: Some(ProbePredicate::HexQuantity {
+                    path: String::new(),
+                }),
+                head_path: Some(String::new()),
+                degraded_latency_ms: None,
+                interval_s: None,
+                retry_policy_max_retries: None,
+                retry_policy_total_time_s: None,
+                check_chain_identity: true,
+            });
+        }
+        probes
+    }
+}
+
+/// Config entries identified by a name that must be unique in their list.
+trait Named {
+    /// What the entries are, for error messages.
+    const KIND: &'static str;
+
+    fn name(&self) -> &str;
+}
+
+impl Named for ProbeConfig {
+    const KIND: &'static str = "probe";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Named for AlertRule {
+    const KIND: &'static str = "alert rule";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Deserializes a list of named entries, rejecting duplicate names.
+fn deserialize_unique_names<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de> + Named,
+{
+    let entries = Vec::<T>::deserialize(deserializer)?;
+    let mut names = std::collections::BTreeSet::new();
+    if let Some(duplicate) = entries.iter().find(|entry| !names.insert(entry.name())) {
+        return Err(serde::de::Error::custom(format!(
+            "duplicate {} name {:?}",
+            T::KIND,
+            duplicate.name()
+        )));
+    }
+    Ok(entries)
 }
 
 /// Reads and parses a TOML file from the given path into the given type `T`.
@@ -489,6 +1338,7 @@ datadir = "data"
 [server]
 host = "127.0.0.1"
 port = 8080
+admin_token = "s3cret"
 
 [network]
 sequencer_url = "https://sequencer.example.com"
@@ -498,6 +1348,15 @@ retry_policy_max_retries = 3
 retry_policy_total_time_s = 30
 status_refetch_interval_s = 5
 initial_status_wait_timeout_s = 4
+stall_threshold_polls = 3
+stall_threshold_s = 45
+latency_window_polls = 20
+expected_chain_id = 8150
+expected_genesis_hash = "0x0b5ec3a4f24b0a2d2ba1c36be6bd5b6bc4b5da4f6f9d7b7a3c1b1e0f3e5d2a11"
+l1_no_new_block_threshold_s = 1800
+l1_reader_lag_threshold_blocks = 2
+checkpoint_stale_threshold_s = 5400
+metrics_window_blocks = 25
 
 [bridge]
 esplora_request_timeout_s = 9
@@ -524,6 +1383,47 @@ finality_lag = 8
 start_block = 1234
 poll_interval_s = 7
 withdrawal_denomination_sats = 100000000
+
+[alerts]
+cooldown_s = 600
+withdrawal_stuck_threshold_s = 3600
+webhook_timeout_s = 3
+
+[[alerts.webhooks]]
+url = "https://hooks.slack.com/services/T000/B000/XXXX"
+format = "slack"
+
+[[alerts.webhooks]]
+url = "https://alerts.example.com/hook"
+
+[[alerts.rules]]
+name = "deposit_stuck"
+metric = "deposit_in_progress"
+for_s = 21600
+description = "Deposit in progress for more than 6h"
+
+[[alerts.rules]]
+name = "indexer_lagging"
+metric = "indexer_lag_blocks"
+above = 500
+severity = "critical"
+
+[[maintenance]]
+starts_at = 1760000000
+ends_at = 1760003600
+message = "Upgrading the RPC node and operator 1"
+services = ["rpc"]
+operators = ["0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"]
+
+[feed]
+title = "Alpen Testnet Status"
+link = "https://status.example.com"
+max_entries = 20
+
+[statuspage]
+page_id = "alpen-testnet"
+name = "Alpen Testnet"
+url = "https://status.example.com"
 "#;
 
         let config = toml::from_str::<Config>(config_content);
@@ -533,12 +1433,25 @@ withdrawal_denomination_sats = 100000000
         assert_eq!(config.datadir(), Path::new("data"));
         assert_eq!(config.server.host(), "127.0.0.1");
         assert_eq!(config.server.port(), 8080);
+        assert_eq!(config.server.admin_token(), Some("s3cret"));
         assert_eq!(
             config.network.sequencer_url(),
             "https://sequencer.example.com"
         );
         assert_eq!(config.network.status_refetch_interval(), 5);
         assert_eq!(config.network.initial_status_wait_timeout_s(), 4);
+        assert_eq!(config.network.stall_threshold_polls(), 3);
+        assert_eq!(config.network.stall_threshold_s(), 45);
+        assert_eq!(config.network.latency_window_polls(), 20);
+        assert_eq!(config.network.expected_chain_id(), Some(8150));
+        assert_eq!(config.network.l1_no_new_block_threshold_s(), 1800);
+        assert_eq!(config.network.l1_reader_lag_threshold_blocks(), 2);
+        assert_eq!(config.network.checkpoint_stale_threshold_s(), 5400);
+        assert_eq!(config.network.metrics_window_blocks(), 25);
+        assert_eq!(
+            config.network.expected_genesis_hash(),
+            Some("0x0b5ec3a4f24b0a2d2ba1c36be6bd5b6bc4b5da4f6f9d7b7a3c1b1e0f3e5d2a11")
+        );
         assert_eq!(config.bridge.esplora_url(), "https://esplora.example.com");
         assert_eq!(config.bridge.esplora_request_timeout_s(), 9);
         assert_eq!(config.bridge.max_tx_confirmations(), 12);
@@ -576,6 +1489,47 @@ withdrawal_denomination_sats = 100000000
             config.withdrawal_indexer().withdrawal_denomination_sats(),
             100_000_000
         );
+        assert_eq!(config.alerts().cooldown_s(), 600);
+        assert_eq!(config.alerts().withdrawal_stuck_threshold_s(), 3600);
+        assert_eq!(config.alerts().webhook_timeout_s(), 3);
+        assert_eq!(config.alerts().webhooks().len(), 2);
+        assert_eq!(config.alerts().webhooks()[0].format(), WebhookFormat::Slack);
+        assert_eq!(
+            config.alerts().webhooks()[1].url(),
+            "https://alerts.example.com/hook"
+        );
+        assert_eq!(config.alerts().webhooks()[1].format(), WebhookFormat::Json);
+        let rules = config.alerts().rules();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].metric(), AlertMetric::DepositInProgress);
+        assert_eq!(rules[0].for_s(), 21600);
+        assert_eq!(rules[0].severity(), AlertSeverity::Warning);
+        assert_eq!(
+            rules[0].description(),
+            Some("Deposit in progress for more than 6h")
+        );
+        assert_eq!(rules[1].name(), "indexer_lagging");
+        assert_eq!(rules[1].above(), Some(500.0));
+        assert_eq!(rules[1].below(), None);
+        assert_eq!(rules[1].severity(), AlertSeverity::Critical);
+        let maintenance = config.maintenance();
+        assert_eq!(maintenance.len(), 1);
+        assert_eq!(maintenance[0].starts_at(), 1_760_000_000);
+        assert_eq!(maintenance[0].ends_at(), 1_760_003_600);
+        assert_eq!(maintenance[0].services(), ["rpc"]);
+        assert_eq!(
+            maintenance[0].operators()[0],
+            *config.bridge.operators()[0].public_key()
+        );
+        assert_eq!(config.feed().title(), "Alpen Testnet Status");
+        assert_eq!(config.feed().link(), Some("https://status.example.com"));
+        assert_eq!(config.feed().max_entries(), 20);
+        assert_eq!(config.statuspage().page_id(), "alpen-testnet");
+        assert_eq!(config.statuspage().name(), "Alpen Testnet");
+        assert_eq!(
+            config.statuspage().url(),
+            Some("https://status.example.com")
+        );
     }
 
     #[test]
@@ -649,6 +1603,309 @@ eth_rpc_url = "https://rpc.example.com"
         assert_eq!(config.bridge.operators()[0].rpc_url(), None);
     }
 
+    #[test]
+    fn network_probes_parse() {
+        let toml_doc = r#"
+datadir = "data"
+
+[server]
+host = "127.0.0.1"
+port = 8080
+
+[network]
+sequencer_url = ""
+rpc_url = ""
+bundler_url = ""
+retry_policy_max_retries = 0
+retry_policy_total_time_s = 0
+status_refetch_interval_s = 0
+
+[[network.probes]]
+name = "sequencer"
+kind = "json_rpc"
+url = "https://sequencer.example.com"
+method = "strata_getChainStatus"
+expect = { type = "exists", path = "tip.slot" }
+head_path = "tip.slot"
+
+[[network.probes]]
+name = "faucet"
+kind = "http"
+url = "https://faucet.example.com/health"
+expected_status = 200
+expect = { type = "equals", path = "status", value = "ok" }
+degraded_latency_ms = 1500
+interval_s = 60
+retry_policy_max_retries = 1
+retry_policy_total_time_s = 5
+
+[[network.probes]]
+name = "prover"
+kind = "tcp"
+address = "prover.example.com:9000"
+
+[[network.probes]]
+name = "rpc"
+kind = "json_rpc"
+url = "https://rpc.example.com"
+method = "eth_blockNumber"
+check_chain_identity = true
+
+[bridge]
+esplora_url = ""
+max_tx_confirmations = 0
+status_refetch_interval_s = 0
+operators = []
+
+[withdrawal_indexer]
+eth_rpc_url = "https://rpc.example.com"
+"#;
+
+        let config = toml::from_str::<Config>(toml_doc).expect("parse config");
+        let probes = config.network().probes();
+        assert_eq!(probes.len(), 4);
+
+        assert_eq!(probes[0].name(), "sequencer");
+        assert!(probes[0].is_chain_status());
+        assert_eq!(probes[0].head_path(), Some("tip.slot"));
+
+        assert_eq!(probes[1].name(), "faucet");
+        assert_eq!(
+            probes[1].kind(),
+            &ProbeKind::Http {
+                url: "https://faucet.example.com/health".to_owned(),
+                expected_status: Some(200),
+            }
+        );
+        assert_eq!(
+            probes[1].expect(),
+            Some(&ProbePredicate::Equals {
+                path: "status".to_owned(),
+                value: serde_json::Value::from("ok"),
+            })
+        );
+
+        assert_eq!(
+            probes[2].kind(),
+            &ProbeKind::Tcp {
+                address: "prover.example.com:9000".to_owned(),
+            }
+        );
+        assert_eq!(probes[1].degraded_latency_ms(), Some(1500));
+
+        assert_eq!(config.network().probe_interval_s(&probes[1]), 60);
+        assert_eq!(
+            config
+                .network()
+                .probe_retry_policy(&probes[1])
+                .max_retries(),
+            1
+        );
+
+        assert_eq!(probes[2].expect(), None);
+        assert_eq!(probes[2].degraded_latency_ms(), None);
+        assert!(!probes[2].check_chain_identity());
+        assert!(probes[3].check_chain_identity());
+        assert_eq!(config.network().probe_interval_s(&probes[2]), 0);
+
+        let serialized = toml::to_string(&config).expect("serialize config");
+        assert_eq!(
+            toml::from_str::<Config>(&serialized).expect("reparse config"),
+            config
+        );
+    }
+
+    #[test]
+    fn duplicate_probe_names_are_rejected() {
+        let toml_doc = r#"
+sequencer_url = ""
+rpc_url = ""
+bundler_url = ""
+retry_policy_max_retries = 0
+retry_policy_total_time_s = 0
+status_refetch_interval_s = 0
+
+[[probes]]
+name = "rpc"
+kind = "tcp"
+address = "rpc.example.com:8545"
+
+[[probes]]
+name = "rpc"
+kind = "tcp"
+address = "rpc.example.com:8546"
+"#;
+
+        let error =
+            toml::from_str::<NetworkMonitoringConfig>(toml_doc).expect_err("duplicate probe names");
+        assert!(
+            error.to_string().contains(r#"duplicate probe name "rpc""#),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn duplicate_alert_rule_names_are_rejected() {
+        let toml_doc = r#"
+[[rules]]
+name = "queue"
+metric = "withdrawal_queue_depth"
+above = 10
+
+[[rules]]
+name = "queue"
+metric = "withdrawal_queue_depth"
+above = 100
+"#;
+
+        let error =
+            toml::from_str::<AlertsConfig>(toml_doc).expect_err("duplicate alert rule names");
+        assert!(
+            error
+                .to_string()
+                .contains(r#"duplicate alert rule name "queue""#),
+            "unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn network_probes_default_to_service_urls() {
+        let toml_doc = r#"
+datadir = "data"
+
+[server]
+host = "127.0.0.1"
+port = 8080
+
+[network]
+sequencer_url = "https://sequencer.example.com"
+rpc_url = "https://rpc.example.com"
+bundler_url = "https://bundler.example.com/health"
+retry_policy_max_retries = 0
+retry_policy_total_time_s = 0
+status_refetch_interval_s = 0
+
+[bridge]
+esplora_url = ""
+max_tx_confirmations = 0
+status_refetch_interval_s = 0
+operators = []
+
+[withdrawal_indexer]
+eth_rpc_url = "https://rpc.example.com"
+"#;
+
+        let config = toml::from_str::<Config>(toml_doc).expect("parse config");
+        let probes = config.network().probes();
+
+        assert_eq!(
+            probes.iter().map(ProbeConfig::name).collect::<Vec<_>>(),
+            vec!["sequencer", "rpc_endpoint", "bundler_endpoint"]
+        );
+        assert!(probes[0].is_chain_status());
+        assert!(matches!(
+            probes[1].kind(),
+            ProbeKind::JsonRpc { url, method, .. }
+                if url == "https://rpc.example.com" && method == "eth_blockNumber"
+        ));
+        assert!(matches!(
+            probes[2].kind(),
+            ProbeKind::Http { url, .. } if url == "https://bundler.example.com/health"
+        ));
+        assert_eq!(probes[2].expect(), Some(&ProbePredicate::Healthy));
+        assert_eq!(
+            probes
+                .iter()
+                .map(ProbeConfig::check_chain_identity)
+                .collect::<Vec<_>>(),
+            vec![false, true, true]
+        );
+
+        // The indexer shares the RPC endpoint, so it is not probed twice.
+        assert_eq!(config.network_probes(), probes);
+    }
+
+    #[test]
+    fn network_probes_check_separate_indexer_endpoint() {
+        let toml_doc = r#"
+datadir = "data"
+
+[server]
+host = "127.0.0.1"
+port = 8080
+
+[network]
+sequencer_url = "https://sequencer.example.com"
+rpc_url = "https://rpc.example.com"
+bundler_url = "https://bundler.example.com/health"
+retry_policy_max_retries = 0
+retry_policy_total_time_s = 0
+status_refetch_interval_s = 0
+
+[bridge]
+esplora_url = ""
+max_tx_confirmations = 0
+status_refetch_interval_s = 0
+operators = []
+
+[withdrawal_indexer]
+eth_rpc_url = "https://indexer-rpc.example.com"
+"#;
+
+        let config = toml::from_str::<Config>(toml_doc).expect("parse config");
+        let probes = config.network_probes();
+
+        assert_eq!(probes.len(), 4);
+        assert_eq!(probes[3].name(), "withdrawal_indexer_rpc");
+        assert!(probes[3].check_chain_identity());
+        assert!(matches!(
+            probes[3].kind(),
+            ProbeKind::JsonRpc { url, .. } if url == "https://indexer-rpc.example.com"
+        ));
+    }
+
+    #[test]
+    fn default_bundler_probe_checks_entry_point_when_configured() {
+        let toml_doc = r#"
+datadir = "data"
+
+[server]
+host = "127.0.0.1"
+port = 8080
+
+[network]
+sequencer_url = "https://sequencer.example.com"
+rpc_url = "https://rpc.example.com"
+bundler_url = "https://bundler.example.com/health"
+bundler_rpc_url = "https://bundler.example.com/rpc"
+bundler_entry_point = "0x0000000071727De22E5E9d8BAf0edAc6f37da032"
+retry_policy_max_retries = 0
+retry_policy_total_time_s = 0
+status_refetch_interval_s = 0
+
+[bridge]
+esplora_url = ""
+max_tx_confirmations = 0
+status_refetch_interval_s = 0
+operators = []
+
+[withdrawal_indexer]
+eth_rpc_url = "https://rpc.example.com"
+"#;
+
+        let config = toml::from_str::<Config>(toml_doc).expect("parse config");
+
+        assert_eq!(
+            config.network().probes()[2].kind(),
+            &ProbeKind::Bundler {
+                health_url: "https://bundler.example.com/health".to_owned(),
+                rpc_url: "https://bundler.example.com/rpc".to_owned(),
+                entry_point: "0x0000000071727De22E5E9d8BAf0edAc6f37da032".to_owned(),
+                chain_rpc_url: "https://rpc.example.com".to_owned(),
+            }
+        );
+    }
+
     #[test]
     fn test_monitoring_defaults() {
         let toml_doc = r#"
@@ -689,6 +1946,48 @@ eth_rpc_url = "https://rpc.example.com"
             config.network().initial_status_wait_timeout_s(),
             DEFAULT_NETWORK_INITIAL_STATUS_WAIT_TIMEOUT_S
         );
+        assert_eq!(
+            config.network().stall_threshold_polls(),
+            DEFAULT_STALL_THRESHOLD_POLLS
+        );
+        assert_eq!(
+            config.network().stall_threshold_s(),
+            DEFAULT_STALL_THRESHOLD_S
+        );
+        assert_eq!(
+            config.network().latency_window_polls(),
+            DEFAULT_LATENCY_WINDOW_POLLS
+        );
+        assert_eq!(
+            config.network().l1_no_new_block_threshold_s(),
+            DEFAULT_L1_NO_NEW_BLOCK_THRESHOLD_S
+        );
+        assert_eq!(
+            config.network().l1_reader_lag_threshold_blocks(),
+            DEFAULT_L1_READER_LAG_THRESHOLD_BLOCKS
+        );
+        assert_eq!(
+            config.network().checkpoint_stale_threshold_s(),
+            DEFAULT_CHECKPOINT_STALE_THRESHOLD_S
+        );
+        assert_eq!(
+            config.network().metrics_window_blocks(),
+            DEFAULT_METRICS_WINDOW_BLOCKS
+        );
+        assert_eq!(config.alerts(), &AlertsConfig::default());
+        assert_eq!(config.server().admin_token(), None);
+        assert!(config.maintenance().is_empty());
+        assert_eq!(config.feed(), &FeedConfig::default());
+        assert_eq!(config.feed().title(), DEFAULT_FEED_TITLE);
+        assert_eq!(config.feed().max_entries(), DEFAULT_FEED_MAX_ENTRIES);
+        assert_eq!(config.statuspage(), &StatusPageConfig::default());
+        assert_eq!(config.statuspage().page_id(), DEFAULT_STATUS_PAGE_ID);
+        assert_eq!(config.statuspage().name(), DEFAULT_STATUS_PAGE_NAME);
+        assert_eq!(config.alerts().cooldown_s(), DEFAULT_ALERT_COOLDOWN_S);
+        assert_eq!(
+            config.alerts().withdrawal_stuck_threshold_s(),
+            DEFAULT_WITHDRAWAL_STUCK_THRESHOLD_S
+        );
         assert_eq!(
             config.bridge().initial_status_wait_timeout_s(),
             DEFAULT_BRIDGE_INITIAL_STATUS_WAIT_TIMEOUT_S
@@ -0,0 +1,60 @@
+//! Handlers of the feed endpoints.
+
+use std::sync::Arc;
+
+use axum::{http::header, response::IntoResponse};
+use status_config::FeedConfig;
+use status_incidents::IncidentLog;
+use status_utils::unix_time_s;
+use tracing::warn;
+
+use super::{
+    events::StatusEventLog,
+    render::{atom, latest_entries, rss, FeedEntry},
+};
+
+const ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+const RSS_CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+
+/// Sources of the feed entries.
+#[derive(Debug)]
+pub struct StatusFeed {
+    config: FeedConfig,
+    events: Arc<StatusEventLog>,
+    incidents: Arc<IncidentLog>,
+}
+
+impl StatusFeed {
+    pub fn new(
+        config: FeedConfig,
+        events: Arc<StatusEventLog>,
+        incidents: Arc<IncidentLog>,
+    ) -> Self {
+        Self {
+            config,
+            events,
+            incidents,
+        }
+    }
+
+    fn entries(&self) -> Vec<FeedEntry> {
+        let incidents = self.incidents.history().unwrap_or_else(|e| {
+            warn!(error = %e, "failed to read incidents for the feeds");
+            Vec::new()
+        });
+        let events = self.events.latest(self.config.max_entries());
+        latest_entries(&incidents, &events, self.config.max_entries())
+    }
+}
+
+/// Handler to get the incidents and status changes as an Atom feed
+pub async fn get_atom_feed(feed: Arc<StatusFeed>) -> impl IntoResponse {
+    let body = atom(&feed.config, &feed.entries(), unix_time_s());
+    ([(header::CONTENT_TYPE, ATOM_CONTENT_TYPE)], body)
+}
+
+/// Handler to get the incidents and status changes as an RSS feed
+pub async fn get_rss_feed(feed: Arc<StatusFeed>) -> impl IntoResponse {
+    let body = rss(&feed.config, &feed.entries(), unix_time_s());
+    ([(header::CONTENT_TYPE, RSS_CONTENT_TYPE)], body)
+}
@@ -0,0 +1,79 @@
+//! Persistence of the availability transitions listed in the feeds.
+
+pub(crate) mod schema;
+
+use std::path::Path;
+
+use status_utils::db::{next_id, open_sled_db, open_temporary_sled_db, DbError, DbResult};
+use typed_sled::{SledDb, SledTree};
+
+use self::schema::StatusEventSchema;
+use crate::events::StatusEvent;
+
+/// Sled-backed status event database.
+#[derive(Debug)]
+pub struct StatusEventDbSled {
+    _db: SledDb,
+    events: SledTree<StatusEventSchema>,
+}
+
+impl StatusEventDbSled {
+    /// Open the status event database under `{datadir}/status_events`.
+    pub fn open(datadir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        open_sled_db(
+            datadir.as_ref().join("status_events"),
+            "status event",
+            Self::from_sled_db,
+        )
+    }
+
+    /// Open a temporary in-memory-like sled database deleted on drop.
+    ///
+    /// Used by the tests of the crates that take a status event log.
+    pub fn open_temporary() -> anyhow::Result<Self> {
+        open_temporary_sled_db("status event", Self::from_sled_db)
+    }
+
+    fn from_sled_db(sled_db: sled::Db) -> DbResult<Self> {
+        let db = SledDb::new(sled_db)?;
+
+        Ok(Self {
+            events: db.get_tree::<StatusEventSchema>()?,
+            _db: db,
+        })
+    }
+
+    /// Returns the id after the highest stored one.
+    pub(crate) fn next_id(&self) -> DbResult<u64> {
+        next_id(&self.events, "status event")
+    }
+
+    pub(crate) fn put(&self, event: &StatusEvent) -> DbResult<()> {
+        Ok(self.events.insert(&event.id(), event)?)
+    }
+
+    /// Fetches at most `limit` events, newest first.
+    pub(crate) fn latest(&self, limit: usize) -> DbResult<Vec<StatusEvent>> {
+        self.events
+            .iter()
+            .rev()
+            .take(limit)
+            .map(|result| result.map(|(_, event)| event).map_err(DbError::from))
+            .collect()
+    }
+
+    /// Deletes all but the `keep` newest events.
+    pub(crate) fn prune(&self, keep: usize) -> DbResult<()> {
+        let stale = self
+            .events
+            .iter()
+            .rev()
+            .skip(keep)
+            .map(|result| result.map(|(id, _)| id).map_err(DbError::from))
+            .collect::<DbResult<Vec<_>>>()?;
+        for id in stale {
+            self.events.remove(&id)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+//! Schema and codec definitions for the status event DB trees.
+
+use status_utils::impl_json_value_codec;
+use typed_sled::{schema::TreeName, Schema};
+
+use crate::events::StatusEvent;
+
+/// Availability transitions keyed by id, in the order they were observed.
+#[derive(Debug)]
+pub(crate) struct StatusEventSchema;
+
+impl Schema for StatusEventSchema {
+    const TREE_NAME: TreeName = TreeName("status_event");
+    type Key = u64;
+    type Value = StatusEvent;
+}
+
+impl_json_value_codec!(StatusEventSchema, StatusEvent);
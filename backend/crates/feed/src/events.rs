@@ -0,0 +1,164 @@
+//! Availability transitions of the monitored services and operators.
+
+use std::{collections::HashMap, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+use status_utils::Component;
+use tracing::warn;
+
+use super::db::StatusEventDbSled;
+
+/// Number of transitions kept in the database.
+const MAX_STORED_EVENTS: usize = 1_000;
+
+/// Availability of a component as listed in the feeds.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ComponentState {
+    Online,
+    Offline,
+}
+
+/// A component going offline or coming back online.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct StatusEvent {
+    id: u64,
+    /// Unix time in seconds the transition was observed.
+    at: u64,
+    component: Component,
+    /// Display name of the component, e.g. the operator name.
+    name: String,
+    state: ComponentState,
+}
+
+impl StatusEvent {
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn at(&self) -> u64 {
+        self.at
+    }
+
+    pub fn component(&self) -> &Component {
+        &self.component
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn state(&self) -> ComponentState {
+        self.state
+    }
+}
+
+/// Records the transitions reported by the monitors.
+#[derive(Debug)]
+pub struct StatusEventLog {
+    db: StatusEventDbSled,
+    /// Last observed state of each component.
+    states: Mutex<HashMap<Component, ComponentState>>,
+}
+
+impl StatusEventLog {
+    /// Restores the last recorded state of each component from `db`.
+    pub fn load(db: StatusEventDbSled) -> anyhow::Result<Self> {
+        let mut states = HashMap::new();
+        let events = db
+            .latest(MAX_STORED_EVENTS)
+            .map_err(|e| anyhow::anyhow!("load status events: {e}"))?;
+        // Newest first, so the first event seen for a component is its state.
+        for event in events {
+            states.entry(event.component).or_insert(event.state);
+        }
+
+        Ok(Self {
+            db,
+            states: Mutex::new(states),
+        })
+    }
+
+    /// Records a transition if `component` changed state since the last
+    /// observation.
+    ///
+    /// Components seen for the first time are assumed to have been online, so
+    /// only an initial offline state is recorded.
+    pub fn observe(&self, component: Component, name: &str, state: ComponentState, now: u64) {
+        let mut states = self.states.lock().expect("status event lock poisoned");
+        let previous = states.insert(component.clone(), state);
+        if previous.unwrap_or(ComponentState::Online) == state {
+            return;
+        }
+
+        let result = self.db.next_id().and_then(|id| {
+            self.db.put(&StatusEvent {
+                id,
+                at: now,
+                component,
+                name: name.to_owned(),
+                state,
+            })?;
+            self.db.prune(MAX_STORED_EVENTS)
+        });
+        if let Err(e) = result {
+            warn!(error = %e, %name, "failed to record status event");
+        }
+    }
+
+    /// At most `limit` transitions, newest first.
+    pub fn latest(&self, limit: usize) -> Vec<StatusEvent> {
+        self.db.latest(limit).unwrap_or_else(|e| {
+            warn!(error = %e, "failed to read status events");
+            Vec::new()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(name: &str) -> Component {
+        Component::Service(name.to_owned())
+    }
+
+    #[test]
+    fn only_transitions_are_recorded() {
+        let db = StatusEventDbSled::open_temporary().expect("open status event db");
+        let log = StatusEventLog::load(db).expect("load status events");
+
+        log.observe(service("rpc"), "rpc", ComponentState::Online, 100);
+        log.observe(service("bundler"), "bundler", ComponentState::Offline, 100);
+        log.observe(service("bundler"), "bundler", ComponentState::Offline, 110);
+        log.observe(service("bundler"), "bundler", ComponentState::Online, 120);
+
+        let events = log.latest(10);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].id(), 1);
+        assert_eq!(events[0].state(), ComponentState::Online);
+        assert_eq!(events[0].at(), 120);
+        assert_eq!(events[1].state(), ComponentState::Offline);
+        assert_eq!(events[1].component(), &service("bundler"));
+    }
+
+    #[test]
+    fn load_restores_last_states() {
+        let db = StatusEventDbSled::open_temporary().expect("open status event db");
+        db.put(&StatusEvent {
+            id: 0,
+            at: 100,
+            component: service("rpc"),
+            name: "rpc".to_owned(),
+            state: ComponentState::Offline,
+        })
+        .expect("put status event");
+        let log = StatusEventLog::load(db).expect("load status events");
+
+        log.observe(service("rpc"), "rpc", ComponentState::Offline, 110);
+        assert_eq!(log.latest(10).len(), 1);
+
+        log.observe(service("rpc"), "rpc", ComponentState::Online, 120);
+        assert_eq!(log.latest(10)[0].id(), 1);
+    }
+}
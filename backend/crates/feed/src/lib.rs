@@ -0,0 +1,8 @@
+mod api;
+mod db;
+mod events;
+mod render;
+
+pub use api::{get_atom_feed, get_rss_feed, StatusFeed};
+pub use db::StatusEventDbSled;
+pub use events::{ComponentState, StatusEvent, StatusEventLog};
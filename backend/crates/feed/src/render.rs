@@ -0,0 +1,204 @@
+//! Renders incidents and status events as Atom and RSS documents.
+
+use std::fmt::Write;
+
+use status_config::FeedConfig;
+use status_incidents::Incident;
+use status_utils::{escape_xml, rfc3339, rfc822, Component};
+
+use super::events::{ComponentState, StatusEvent};
+
+/// Namespace of the entry ids, which must not change across deployments.
+const ID_PREFIX: &str = "urn:status-dashboard";
+
+/// Entry of a feed, common to both formats.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FeedEntry {
+    /// Stable id of the incident or event the entry is about.
+    id: String,
+    title: String,
+    summary: String,
+    /// Unix time in seconds the entry was first published.
+    published: u64,
+    /// Unix time in seconds of the entry's latest change.
+    updated: u64,
+}
+
+impl FeedEntry {
+    /// Entry of an incident, updated with each incident update.
+    pub(crate) fn incident(incident: &Incident) -> Self {
+        let status = incident.status().as_str();
+        let summary = match incident.updates().last() {
+            Some(update) => format!("{status}: {}", update.message()),
+            None => status.to_owned(),
+        };
+        Self {
+            id: format!("{ID_PREFIX}:incident:{}", incident.id()),
+            title: incident.title().to_owned(),
+            summary,
+            published: incident.created_at(),
+            updated: incident.updated_at(),
+        }
+    }
+
+    /// Entry of a component going offline or coming back online.
+    pub(crate) fn event(event: &StatusEvent) -> Self {
+        let subject = match event.component() {
+            Component::Service(_) => format!("Service {}", event.name()),
+            Component::Operator(_) => format!("Bridge operator {}", event.name()),
+        };
+        let title = match event.state() {
+            ComponentState::Offline => format!("{subject} is offline"),
+            ComponentState::Online => format!("{subject} is back online"),
+        };
+        Self {
+            id: format!("{ID_PREFIX}:event:{}", event.id()),
+            summary: title.clone(),
+            title,
+            published: event.at(),
+            updated: event.at(),
+        }
+    }
+}
+
+/// Merges the entries newest first, keeping at most `max_entries`.
+pub(crate) fn latest_entries(
+    incidents: &[Incident],
+    events: &[StatusEvent],
+    max_entries: usize,
+) -> Vec<FeedEntry> {
+    let mut entries: Vec<_> = incidents
+        .iter()
+        .map(FeedEntry::incident)
+        .chain(events.iter().map(FeedEntry::event))
+        .collect();
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.updated));
+    entries.truncate(max_entries);
+    entries
+}
+
+/// Time of the newest entry, or `now` for an empty feed.
+fn last_updated(entries: &[FeedEntry], now: u64) -> u64 {
+    entries.first().map_or(now, |entry| entry.updated)
+}
+
+/// Renders an Atom 1.0 document.
+pub(crate) fn atom(config: &FeedConfig, entries: &[FeedEntry], now: u64) -> String {
+    let title = escape_xml(config.title());
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    xml.push('\n');
+    xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+    xml.push('\n');
+    let _ = writeln!(xml, "  <id>{ID_PREFIX}:feed</id>");
+    let _ = writeln!(xml, "  <title>{title}</title>");
+    let _ = writeln!(
+        xml,
+        "  <updated>{}</updated>",
+        rfc3339(last_updated(entries, now))
+    );
+    let _ = writeln!(xml, "  <author><name>{title}</name></author>");
+    if let Some(link) = config.link() {
+        let _ = writeln!(
+            xml,
+            r#"  <link rel="alternate" href="{}"/>"#,
+            escape_xml(link)
+        );
+    }
+    for entry in entries {
+        xml.push_str("  <entry>\n");
+        let _ = writeln!(xml, "    <id>{}</id>", escape_xml(&entry.id));
+        let _ = writeln!(xml, "    <title>{}</title>", escape_xml(&entry.title));
+        let _ = writeln!(
+            xml,
+            "    <published>{}</published>",
+            rfc3339(entry.published)
+        );
+        let _ = writeln!(xml, "    <updated>{}</updated>", rfc3339(entry.updated));
+        let _ = writeln!(xml, "    <summary>{}</summary>", escape_xml(&entry.summary));
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Renders an RSS 2.0 document.
+pub(crate) fn rss(config: &FeedConfig, entries: &[FeedEntry], now: u64) -> String {
+    let title = escape_xml(config.title());
+    let mut xml = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    xml.push('\n');
+    xml.push_str("<rss version=\"2.0\">\n  <channel>\n");
+    let _ = writeln!(xml, "    <title>{title}</title>");
+    if let Some(link) = config.link() {
+        let _ = writeln!(xml, "    <link>{}</link>", escape_xml(link));
+    }
+    let _ = writeln!(
+        xml,
+        "    <description>Incidents and status changes of {title}</description>"
+    );
+    let _ = writeln!(
+        xml,
+        "    <lastBuildDate>{}</lastBuildDate>",
+        rfc822(last_updated(entries, now))
+    );
+    for entry in entries {
+        xml.push_str("    <item>\n");
+        let _ = writeln!(
+            xml,
+            r#"      <guid isPermaLink="false">{}</guid>"#,
+            escape_xml(&entry.id)
+        );
+        let _ = writeln!(xml, "      <title>{}</title>", escape_xml(&entry.title));
+        let _ = writeln!(
+            xml,
+            "      <description>{}</description>",
+            escape_xml(&entry.summary)
+        );
+        let _ = writeln!(xml, "      <pubDate>{}</pubDate>", rfc822(entry.updated));
+        xml.push_str("    </item>\n");
+    }
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u64, title: &str, updated: u64) -> FeedEntry {
+        FeedEntry {
+            id: format!("{ID_PREFIX}:event:{id}"),
+            title: title.to_owned(),
+            summary: title.to_owned(),
+            published: updated,
+            updated,
+        }
+    }
+
+    #[test]
+    fn renders_entries_with_stable_ids() {
+        let config = FeedConfig::default();
+        let entries = [
+            entry(1, "Service rpc is back online", 1_709_210_096),
+            entry(0, "Service rpc & bundler are offline", 0),
+        ];
+
+        let atom = atom(&config, &entries, 1_767_225_599);
+        assert!(atom.contains("<updated>2024-02-29T12:34:56Z</updated>\n  <author>"));
+        assert!(atom.contains("<id>urn:status-dashboard:event:1</id>"));
+        assert!(atom.contains("<title>Service rpc &amp; bundler are offline</title>"));
+        assert_eq!(atom.matches("<entry>").count(), 2);
+
+        let rss = rss(&config, &entries, 1_767_225_599);
+        assert!(rss.contains("<lastBuildDate>Thu, 29 Feb 2024 12:34:56 GMT</lastBuildDate>"));
+        assert!(rss.contains(r#"<guid isPermaLink="false">urn:status-dashboard:event:0</guid>"#));
+        assert!(rss.contains("<pubDate>Thu, 01 Jan 1970 00:00:00 GMT</pubDate>"));
+        assert_eq!(rss.matches("<item>").count(), 2);
+    }
+
+    #[test]
+    fn empty_feed_is_updated_now() {
+        let atom = atom(&FeedConfig::default(), &[], 0);
+        assert!(atom.contains("<updated>1970-01-01T00:00:00Z</updated>"));
+        assert!(!atom.contains("<entry>"));
+    }
+}
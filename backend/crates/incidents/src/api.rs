@@ -0,0 +1,72 @@
+//! Handlers of the incident endpoints.
+
+use std::sync::Arc;
+
+use axum::{extract::Path, http::StatusCode, Json};
+use status_utils::unix_time_s;
+use tracing::{info, warn};
+
+use super::{
+    log::{IncidentError, IncidentLog},
+    types::{Incident, IncidentList, IncidentUpdateRequest, NewIncident, ResolveIncident},
+};
+
+type ApiResult<T> = Result<T, (StatusCode, String)>;
+
+fn error_response(e: IncidentError) -> (StatusCode, String) {
+    let status = match e {
+        IncidentError::NotFound(_) => StatusCode::NOT_FOUND,
+        IncidentError::AlreadyResolved(_) => StatusCode::CONFLICT,
+        IncidentError::Empty(_) | IncidentError::UnknownComponent(_) => StatusCode::BAD_REQUEST,
+        IncidentError::Db(_) => {
+            warn!(error = %e, "incident storage failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    (status, e.to_string())
+}
+
+/// Handler to list the active and recently resolved incidents
+pub async fn get_incidents(log: Arc<IncidentLog>) -> ApiResult<Json<IncidentList>> {
+    log.list(unix_time_s()).map(Json).map_err(error_response)
+}
+
+/// Admin handler to create an incident
+pub async fn create_incident(
+    log: Arc<IncidentLog>,
+    Json(new): Json<NewIncident>,
+) -> ApiResult<(StatusCode, Json<Incident>)> {
+    let incident = log.create(new, unix_time_s()).map_err(error_response)?;
+    info!(
+        id = incident.id(),
+        title = incident.title(),
+        "incident created"
+    );
+    Ok((StatusCode::CREATED, Json(incident)))
+}
+
+/// Admin handler to post an update to an incident
+pub async fn update_incident(
+    log: Arc<IncidentLog>,
+    Path(id): Path<u64>,
+    Json(update): Json<IncidentUpdateRequest>,
+) -> ApiResult<Json<Incident>> {
+    let incident = log
+        .update(id, update, unix_time_s())
+        .map_err(error_response)?;
+    info!(id, status = ?incident.status(), "incident updated");
+    Ok(Json(incident))
+}
+
+/// Admin handler to resolve an incident
+pub async fn resolve_incident(
+    log: Arc<IncidentLog>,
+    Path(id): Path<u64>,
+    Json(resolve): Json<ResolveIncident>,
+) -> ApiResult<Json<Incident>> {
+    let incident = log
+        .resolve(id, resolve, unix_time_s())
+        .map_err(error_response)?;
+    info!(id, "incident resolved");
+    Ok(Json(incident))
+}
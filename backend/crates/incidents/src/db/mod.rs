@@ -0,0 +1,67 @@
+//! Persistence of the incidents posted through the admin API.
+
+pub(crate) mod schema;
+
+use std::path::Path;
+
+use status_utils::db::{next_id, open_sled_db, DbError, DbResult};
+use typed_sled::{SledDb, SledTree};
+
+use self::schema::IncidentSchema;
+use crate::types::Incident;
+
+/// Sled-backed incident database.
+#[derive(Debug)]
+pub struct IncidentDbSled {
+    _db: SledDb,
+    incidents: SledTree<IncidentSchema>,
+}
+
+impl IncidentDbSled {
+    /// Open the incident database under `{datadir}/incidents`.
+    pub fn open(datadir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        open_sled_db(
+            datadir.as_ref().join("incidents"),
+            "incident",
+            Self::from_sled_db,
+        )
+    }
+
+    /// Open a temporary in-memory-like sled database deleted on drop.
+    #[cfg(test)]
+    pub fn open_temporary() -> anyhow::Result<Self> {
+        status_utils::db::open_temporary_sled_db("incident", Self::from_sled_db)
+    }
+
+    fn from_sled_db(sled_db: sled::Db) -> DbResult<Self> {
+        let db = SledDb::new(sled_db)?;
+
+        Ok(Self {
+            incidents: db.get_tree::<IncidentSchema>()?,
+            _db: db,
+        })
+    }
+
+    /// Returns the id after the highest stored one.
+    pub(crate) fn next_id(&self) -> DbResult<u64> {
+        next_id(&self.incidents, "incident")
+    }
+
+    pub(crate) fn get(&self, id: u64) -> DbResult<Option<Incident>> {
+        Ok(self.incidents.get(&id)?)
+    }
+
+    /// Inserts or replaces the incident under its id.
+    pub(crate) fn put(&self, incident: &Incident) -> DbResult<()> {
+        Ok(self.incidents.insert(&incident.id(), incident)?)
+    }
+
+    /// Fetches every incident, newest first.
+    pub(crate) fn incidents(&self) -> DbResult<Vec<Incident>> {
+        self.incidents
+            .iter()
+            .rev()
+            .map(|result| result.map(|(_, incident)| incident).map_err(DbError::from))
+            .collect()
+    }
+}
@@ -0,0 +1,18 @@
+//! Schema and codec definitions for the incident DB trees.
+
+use status_utils::impl_json_value_codec;
+use typed_sled::{schema::TreeName, Schema};
+
+use crate::types::Incident;
+
+/// Incidents keyed by id.
+#[derive(Debug)]
+pub(crate) struct IncidentSchema;
+
+impl Schema for IncidentSchema {
+    const TREE_NAME: TreeName = TreeName("incident");
+    type Key = u64;
+    type Value = Incident;
+}
+
+impl_json_value_codec!(IncidentSchema, Incident);
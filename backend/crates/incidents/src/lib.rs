@@ -0,0 +1,12 @@
+mod api;
+mod db;
+mod log;
+mod types;
+
+pub use api::{create_incident, get_incidents, resolve_incident, update_incident};
+pub use db::IncidentDbSled;
+pub use log::{IncidentError, IncidentLog, RECENT_INCIDENT_WINDOW_S};
+pub use types::{
+    Incident, IncidentList, IncidentSeverity, IncidentStatus, IncidentUpdate,
+    IncidentUpdateRequest, NewIncident, ResolveIncident,
+};
@@ -0,0 +1,294 @@
+//! Incidents posted through the admin API.
+
+use std::sync::Mutex;
+
+use status_utils::Component;
+
+use super::{
+    db::IncidentDbSled,
+    types::{
+        Incident, IncidentList, IncidentStatus, IncidentUpdateRequest, NewIncident, ResolveIncident,
+    },
+};
+
+/// Seconds a resolved incident stays listed as recent.
+pub const RECENT_INCIDENT_WINDOW_S: u64 = 7 * 24 * 60 * 60;
+
+/// Reason an incident cannot be created or updated.
+#[derive(Debug, thiserror::Error)]
+pub enum IncidentError {
+    #[error("incident {0} not found")]
+    NotFound(u64),
+
+    #[error("incident {0} is already resolved")]
+    AlreadyResolved(u64),
+
+    #[error("{0} must not be empty")]
+    Empty(&'static str),
+
+    #[error("unknown component {0:?}")]
+    UnknownComponent(Component),
+
+    #[error("failed to persist incident: {0}")]
+    Db(String),
+}
+
+fn db_error(e: impl ToString) -> IncidentError {
+    IncidentError::Db(e.to_string())
+}
+
+/// Persisted incidents and their timelines.
+#[derive(Debug)]
+pub struct IncidentLog {
+    db: IncidentDbSled,
+    /// Configured services and operators incidents can affect.
+    components: Vec<Component>,
+    /// Serializes the read-modify-write of updates.
+    write_lock: Mutex<()>,
+}
+
+impl IncidentLog {
+    pub fn new(db: IncidentDbSled, components: Vec<Component>) -> Self {
+        Self {
+            db,
+            components,
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// Unresolved incidents and the incidents resolved in the
+    /// [`RECENT_INCIDENT_WINDOW_S`] before `now`.
+    pub fn list(&self, now: u64) -> Result<IncidentList, IncidentError> {
+        let incidents = self.history()?;
+        let (active, resolved): (Vec<_>, Vec<_>) = incidents
+            .into_iter()
+            .partition(|incident| !incident.is_resolved());
+        let recent = resolved
+            .into_iter()
+            .filter(|incident| {
+                incident.resolved_at().is_some_and(|resolved_at| {
+                    now.saturating_sub(resolved_at) < RECENT_INCIDENT_WINDOW_S
+                })
+            })
+            .collect();
+        Ok(IncidentList::new(active, recent))
+    }
+
+    /// Every incident, most recently updated first.
+    pub fn history(&self) -> Result<Vec<Incident>, IncidentError> {
+        let mut incidents = self.db.incidents().map_err(db_error)?;
+        incidents.sort_by_key(|incident| std::cmp::Reverse(incident.updated_at()));
+        Ok(incidents)
+    }
+
+    pub fn create(&self, new: NewIncident, now: u64) -> Result<Incident, IncidentError> {
+        if new.title.trim().is_empty() {
+            return Err(IncidentError::Empty("title"));
+        }
+        if new.message.trim().is_empty() {
+            return Err(IncidentError::Empty("message"));
+        }
+        self.check_components(&new.components)?;
+
+        let _guard = self
+            .write_lock
+            .lock()
+            .expect("incident write lock poisoned");
+        let incident = Incident::new(self.db.next_id().map_err(db_error)?, new, now);
+        self.db.put(&incident).map_err(db_error)?;
+        Ok(incident)
+    }
+
+    /// Appends an update to the timeline of an unresolved incident.
+    pub fn update(
+        &self,
+        id: u64,
+        update: IncidentUpdateRequest,
+        now: u64,
+    ) -> Result<Incident, IncidentError> {
+        if update.message.trim().is_empty() {
+            return Err(IncidentError::Empty("message"));
+        }
+        if let Some(components) = &update.components {
+            self.check_components(components)?;
+        }
+
+        let _guard = self
+            .write_lock
+            .lock()
+            .expect("incident write lock poisoned");
+        let mut incident = self
+            .db
+            .get(id)
+            .map_err(db_error)?
+            .ok_or(IncidentError::NotFound(id))?;
+        if incident.is_resolved() {
+            return Err(IncidentError::AlreadyResolved(id));
+        }
+        incident.apply(update, now);
+        self.db.put(&incident).map_err(db_error)?;
+        Ok(incident)
+    }
+
+    pub fn resolve(
+        &self,
+        id: u64,
+        resolve: ResolveIncident,
+        now: u64,
+    ) -> Result<Incident, IncidentError> {
+        self.update(
+            id,
+            IncidentUpdateRequest {
+                status: Some(IncidentStatus::Resolved),
+                severity: None,
+                components: None,
+                message: resolve.message,
+            },
+            now,
+        )
+    }
+
+    fn check_components(&self, components: &[Component]) -> Result<(), IncidentError> {
+        match components
+            .iter()
+            .find(|component| !self.components.contains(component))
+        {
+            Some(unknown) => Err(IncidentError::UnknownComponent(unknown.clone())),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use status_utils::Component;
+
+    use super::*;
+    use crate::types::IncidentSeverity;
+
+    fn log() -> IncidentLog {
+        IncidentLog::new(
+            IncidentDbSled::open_temporary().expect("open db"),
+            vec![Component::Service("rpc".to_owned())],
+        )
+    }
+
+    fn new_incident(title: &str) -> NewIncident {
+        serde_json::from_value(serde_json::json!({
+            "title": title,
+            "severity": "major",
+            "components": [{ "service": "rpc" }],
+            "message": "We are investigating RPC errors."
+        }))
+        .expect("parse incident")
+    }
+
+    fn update(json: serde_json::Value) -> IncidentUpdateRequest {
+        serde_json::from_value(json).expect("parse update")
+    }
+
+    #[test]
+    fn incident_timeline_records_each_update() {
+        let log = log();
+        let incident = log.create(new_incident("RPC errors"), 100).expect("create");
+        assert_eq!(incident.id(), 0);
+        assert_eq!(incident.status(), IncidentStatus::Investigating);
+        assert_eq!(
+            incident.components(),
+            [Component::Service("rpc".to_owned())]
+        );
+
+        let incident = log
+            .update(
+                0,
+                update(serde_json::json!({
+                    "status": "identified",
+                    "severity": "critical",
+                    "message": "A node ran out of disk."
+                })),
+                200,
+            )
+            .expect("update");
+        assert_eq!(incident.severity(), IncidentSeverity::Critical);
+        assert_eq!(incident.updated_at(), 200);
+
+        let incident = log
+            .resolve(
+                0,
+                ResolveIncident {
+                    message: "Disk expanded.".to_owned(),
+                },
+                300,
+            )
+            .expect("resolve");
+        assert_eq!(incident.resolved_at(), Some(300));
+        let statuses = incident
+            .updates()
+            .iter()
+            .map(|update| update.status())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            [
+                IncidentStatus::Investigating,
+                IncidentStatus::Identified,
+                IncidentStatus::Resolved
+            ]
+        );
+
+        assert!(matches!(
+            log.update(0, update(serde_json::json!({ "message": "again" })), 400),
+            Err(IncidentError::AlreadyResolved(0))
+        ));
+        assert!(matches!(
+            log.update(7, update(serde_json::json!({ "message": "missing" })), 400),
+            Err(IncidentError::NotFound(7))
+        ));
+        assert!(matches!(
+            log.update(0, update(serde_json::json!({ "message": " " })), 400),
+            Err(IncidentError::Empty("message"))
+        ));
+    }
+
+    #[test]
+    fn create_validates_components_and_resolves_resolved_incidents() {
+        let log = log();
+        let mut unknown = new_incident("Bundler errors");
+        unknown.components = vec![Component::Service("bundler".to_owned())];
+        assert!(matches!(
+            log.create(unknown, 100),
+            Err(IncidentError::UnknownComponent(Component::Service(service))) if service == "bundler"
+        ));
+
+        let mut resolved = new_incident("Past RPC errors");
+        resolved.status = IncidentStatus::Resolved;
+        let incident = log.create(resolved, 100).expect("create");
+        assert!(incident.is_resolved());
+        assert_eq!(incident.resolved_at(), Some(100));
+        assert_eq!(log.list(100).expect("list").recent().len(), 1);
+    }
+
+    #[test]
+    fn list_splits_active_and_recently_resolved_incidents() {
+        let log = log();
+        log.create(new_incident("Old"), 0).expect("create");
+        log.create(new_incident("Resolved"), 10).expect("create");
+        log.create(new_incident("Active"), 20).expect("create");
+        let resolve = || ResolveIncident {
+            message: "Fixed.".to_owned(),
+        };
+        log.resolve(0, resolve(), 30).expect("resolve");
+        log.resolve(1, resolve(), 40 + RECENT_INCIDENT_WINDOW_S)
+            .expect("resolve");
+
+        let list = log.list(50 + RECENT_INCIDENT_WINDOW_S).expect("list");
+        let titles = |incidents: &[Incident]| {
+            incidents
+                .iter()
+                .map(|incident| incident.title().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(titles(list.active()), ["Active"]);
+        assert_eq!(titles(list.recent()), ["Resolved"]);
+    }
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+use status_utils::Component;
+
+/// How much of the network or bridge an incident affects.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentSeverity {
+    Minor,
+    Major,
+    Critical,
+}
+
+/// Progress of an incident, as reported by its latest update.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentStatus {
+    #[default]
+    Investigating,
+    Identified,
+    Monitoring,
+    Resolved,
+}
+
+impl IncidentStatus {
+    /// Returns the serialized name of the status.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Investigating => "investigating",
+            Self::Identified => "identified",
+            Self::Monitoring => "monitoring",
+            Self::Resolved => "resolved",
+        }
+    }
+}
+
+/// Entry of an incident's timeline.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct IncidentUpdate {
+    /// Unix time in seconds the update was posted.
+    at: u64,
+    status: IncidentStatus,
+    message: String,
+}
+
+impl IncidentUpdate {
+    pub(crate) fn new(at: u64, status: IncidentStatus, message: String) -> Self {
+        Self {
+            at,
+            status,
+            message,
+        }
+    }
+
+    pub fn at(&self) -> u64 {
+        self.at
+    }
+
+    pub fn status(&self) -> IncidentStatus {
+        self.status
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Human-written incident shown on the status page.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Incident {
+    id: u64,
+    title: String,
+    severity: IncidentSeverity,
+    status: IncidentStatus,
+    /// Network services and bridge operators affected by the incident.
+    components: Vec<Component>,
+    /// Unix time in seconds the incident was created.
+    created_at: u64,
+    /// Unix time in seconds of the latest update.
+    updated_at: u64,
+    /// Unix time in seconds the incident was resolved.
+    resolved_at: Option<u64>,
+    /// Updates in the order they were posted, starting with the creation.
+    updates: Vec<IncidentUpdate>,
+}
+
+impl Incident {
+    pub(crate) fn new(id: u64, new: NewIncident, at: u64) -> Self {
+        Self {
+            id,
+            title: new.title,
+            severity: new.severity,
+            status: new.status,
+            components: new.components,
+            created_at: at,
+            updated_at: at,
+            resolved_at: (new.status == IncidentStatus::Resolved).then_some(at),
+            updates: vec![IncidentUpdate::new(at, new.status, new.message)],
+        }
+    }
+
+    /// Appends `update` to the timeline and applies its changes.
+    pub(crate) fn apply(&mut self, update: IncidentUpdateRequest, at: u64) {
+        let status = update.status.unwrap_or(self.status);
+        if let Some(severity) = update.severity {
+            self.severity = severity;
+        }
+        if let Some(components) = update.components {
+            self.components = components;
+        }
+        self.status = status;
+        self.updated_at = at;
+        if status == IncidentStatus::Resolved {
+            self.resolved_at = Some(at);
+        }
+        self.updates
+            .push(IncidentUpdate::new(at, status, update.message));
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn severity(&self) -> IncidentSeverity {
+        self.severity
+    }
+
+    pub fn status(&self) -> IncidentStatus {
+        self.status
+    }
+
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> u64 {
+        self.updated_at
+    }
+
+    pub fn resolved_at(&self) -> Option<u64> {
+        self.resolved_at
+    }
+
+    pub fn updates(&self) -> &[IncidentUpdate] {
+        &self.updates
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.status == IncidentStatus::Resolved
+    }
+}
+
+/// Request body creating an incident.
+#[derive(Deserialize, Clone, Debug)]
+pub struct NewIncident {
+    pub(crate) title: String,
+    pub(crate) severity: IncidentSeverity,
+    #[serde(default)]
+    pub(crate) status: IncidentStatus,
+    #[serde(default)]
+    pub(crate) components: Vec<Component>,
+    /// First entry of the timeline.
+    pub(crate) message: String,
+}
+
+/// Request body posting an update to an incident.
+///
+/// Fields left out keep their current value.
+#[derive(Deserialize, Clone, Debug)]
+pub struct IncidentUpdateRequest {
+    pub(crate) status: Option<IncidentStatus>,
+    pub(crate) severity: Option<IncidentSeverity>,
+    pub(crate) components: Option<Vec<Component>>,
+    pub(crate) message: String,
+}
+
+/// Request body resolving an incident.
+#[derive(Deserialize, Clone, Debug)]
+pub struct ResolveIncident {
+    pub(crate) message: String,
+}
+
+/// Incidents listed on `/api/incidents`, most recently updated first.
+#[derive(Serialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct IncidentList {
+    active: Vec<Incident>,
+    /// Incidents resolved recently.
+    recent: Vec<Incident>,
+}
+
+impl IncidentList {
+    pub(crate) fn new(active: Vec<Incident>, recent: Vec<Incident>) -> Self {
+        Self { active, recent }
+    }
+
+    pub fn active(&self) -> &[Incident] {
+        &self.active
+    }
+
+    pub fn recent(&self) -> &[Incident] {
+        &self.recent
+    }
+}
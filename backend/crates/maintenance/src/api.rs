@@ -0,0 +1,58 @@
+//! Handlers of the maintenance endpoints.
+
+use std::sync::Arc;
+
+use axum::{extract::Path, http::StatusCode, Json};
+use status_config::MaintenanceWindowConfig;
+use status_utils::unix_time_s;
+use tracing::{info, warn};
+
+use super::{
+    schedule::{MaintenanceSchedule, ScheduleError},
+    types::MaintenanceWindow,
+};
+
+/// Handler to list the active and upcoming maintenance windows
+pub async fn get_maintenance(schedule: Arc<MaintenanceSchedule>) -> Json<Vec<MaintenanceWindow>> {
+    Json(schedule.upcoming(unix_time_s()))
+}
+
+/// Admin handler to schedule a maintenance window
+pub async fn schedule_maintenance(
+    schedule: Arc<MaintenanceSchedule>,
+    Json(config): Json<MaintenanceWindowConfig>,
+) -> Result<(StatusCode, Json<MaintenanceWindow>), (StatusCode, String)> {
+    match schedule.schedule(&config) {
+        Ok(window) => {
+            info!(id = ?window.id(), message = window.message(), "maintenance window scheduled");
+            Ok((StatusCode::CREATED, Json(window)))
+        }
+        Err(
+            e @ (ScheduleError::EmptyWindow
+            | ScheduleError::NoComponents
+            | ScheduleError::UnknownComponent(_)),
+        ) => Err((StatusCode::BAD_REQUEST, e.to_string())),
+        Err(e) => {
+            warn!(error = %e, "failed to schedule maintenance window");
+            Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+    }
+}
+
+/// Admin handler to cancel a scheduled maintenance window
+pub async fn cancel_maintenance(
+    schedule: Arc<MaintenanceSchedule>,
+    Path(id): Path<u64>,
+) -> StatusCode {
+    match schedule.cancel(id) {
+        Ok(true) => {
+            info!(id, "maintenance window cancelled");
+            StatusCode::NO_CONTENT
+        }
+        Ok(false) => StatusCode::NOT_FOUND,
+        Err(e) => {
+            warn!(error = %e, id, "failed to cancel maintenance window");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
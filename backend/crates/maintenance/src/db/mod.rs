@@ -0,0 +1,67 @@
+//! Persistence of the maintenance windows scheduled through the admin API.
+
+pub(crate) mod schema;
+
+use std::path::Path;
+
+use status_config::MaintenanceWindowConfig;
+use status_utils::db::{next_id, open_sled_db, open_temporary_sled_db, DbError, DbResult};
+use typed_sled::{SledDb, SledTree};
+
+use self::schema::MaintenanceWindowSchema;
+use crate::types::MaintenanceWindow;
+
+/// Sled-backed maintenance window database.
+#[derive(Debug)]
+pub struct MaintenanceDbSled {
+    _db: SledDb,
+    windows: SledTree<MaintenanceWindowSchema>,
+}
+
+impl MaintenanceDbSled {
+    /// Open the maintenance database under `{datadir}/maintenance`.
+    pub fn open(datadir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        open_sled_db(
+            datadir.as_ref().join("maintenance"),
+            "maintenance",
+            Self::from_sled_db,
+        )
+    }
+
+    /// Open a temporary in-memory-like sled database deleted on drop.
+    ///
+    /// Used by the tests of the crates that take a maintenance schedule.
+    pub fn open_temporary() -> anyhow::Result<Self> {
+        open_temporary_sled_db("maintenance", Self::from_sled_db)
+    }
+
+    fn from_sled_db(sled_db: sled::Db) -> DbResult<Self> {
+        let db = SledDb::new(sled_db)?;
+
+        Ok(Self {
+            windows: db.get_tree::<MaintenanceWindowSchema>()?,
+            _db: db,
+        })
+    }
+
+    /// Stores `config` under the id after the highest stored one.
+    pub(crate) fn insert(&self, config: &MaintenanceWindowConfig) -> DbResult<MaintenanceWindow> {
+        let id = next_id(&self.windows, "maintenance window")?;
+        let window = MaintenanceWindow::new(Some(id), config);
+        self.windows.insert(&id, &window)?;
+        Ok(window)
+    }
+
+    /// Deletes the window with `id`, returning it if it existed.
+    pub(crate) fn remove(&self, id: u64) -> DbResult<Option<MaintenanceWindow>> {
+        Ok(self.windows.take(&id)?)
+    }
+
+    /// Fetches every stored window in id order.
+    pub(crate) fn windows(&self) -> DbResult<Vec<MaintenanceWindow>> {
+        self.windows
+            .iter()
+            .map(|result| result.map(|(_, window)| window).map_err(DbError::from))
+            .collect()
+    }
+}
@@ -0,0 +1,18 @@
+//! Schema and codec definitions for the maintenance DB trees.
+
+use status_utils::impl_json_value_codec;
+use typed_sled::{schema::TreeName, Schema};
+
+use crate::types::MaintenanceWindow;
+
+/// Maintenance windows scheduled through the admin API, keyed by id.
+#[derive(Debug)]
+pub(crate) struct MaintenanceWindowSchema;
+
+impl Schema for MaintenanceWindowSchema {
+    const TREE_NAME: TreeName = TreeName("maintenance_window");
+    type Key = u64;
+    type Value = MaintenanceWindow;
+}
+
+impl_json_value_codec!(MaintenanceWindowSchema, MaintenanceWindow);
@@ -0,0 +1,9 @@
+mod api;
+mod db;
+mod schedule;
+mod types;
+
+pub use api::{cancel_maintenance, get_maintenance, schedule_maintenance};
+pub use db::MaintenanceDbSled;
+pub use schedule::{MaintenanceSchedule, ScheduleError};
+pub use types::MaintenanceWindow;
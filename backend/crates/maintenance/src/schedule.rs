@@ -0,0 +1,291 @@
+//! Maintenance windows declared in the config or scheduled through the admin
+//! API.
+
+use std::sync::RwLock;
+
+use status_config::MaintenanceWindowConfig;
+use status_utils::{unix_time_s, Component};
+
+use super::{db::MaintenanceDbSled, types::MaintenanceWindow};
+
+/// Reason a maintenance window cannot be scheduled.
+#[derive(Debug, thiserror::Error)]
+pub enum ScheduleError {
+    #[error("maintenance window must end after it starts")]
+    EmptyWindow,
+
+    #[error("maintenance window must cover at least one service or operator")]
+    NoComponents,
+
+    #[error("unknown component {0:?}")]
+    UnknownComponent(Component),
+
+    #[error("failed to persist maintenance window: {0}")]
+    Db(String),
+}
+
+/// Every known maintenance window, with the scheduled ones persisted.
+#[derive(Debug)]
+pub struct MaintenanceSchedule {
+    /// Configured services and operators windows can cover.
+    components: Vec<Component>,
+    configured: Vec<MaintenanceWindow>,
+    db: MaintenanceDbSled,
+    /// Copy of the windows in `db`, read on every alert evaluation.
+    scheduled: RwLock<Vec<MaintenanceWindow>>,
+}
+
+impl MaintenanceSchedule {
+    /// Loads the windows scheduled in `db` alongside the `configured` ones,
+    /// which are validated like scheduled ones.
+    pub fn load(
+        configured: &[MaintenanceWindowConfig],
+        components: Vec<Component>,
+        db: MaintenanceDbSled,
+    ) -> anyhow::Result<Self> {
+        for config in configured {
+            validate(&components, config)
+                .map_err(|e| anyhow::anyhow!("maintenance window {:?}: {e}", config.message()))?;
+        }
+        let configured = configured
+            .iter()
+            .enumerate()
+            .map(|(index, config)| MaintenanceWindow::configured(index, config))
+            .collect::<Vec<_>>();
+        let scheduled = db
+            .windows()
+            .map_err(|e| anyhow::anyhow!("load maintenance windows: {e}"))?;
+        Ok(Self {
+            components,
+            configured,
+            db,
+            scheduled: RwLock::new(scheduled),
+        })
+    }
+
+    /// Windows that have not ended at `now`, ordered by start time.
+    pub fn upcoming(&self, now: u64) -> Vec<MaintenanceWindow> {
+        let scheduled = self
+            .scheduled
+            .read()
+            .expect("maintenance windows lock poisoned");
+        let mut windows = self
+            .configured
+            .iter()
+            .chain(scheduled.iter())
+            .filter(|window| window.ends_at() > now)
+            .cloned()
+            .collect::<Vec<_>>();
+        windows.sort_by_key(|window| (window.starts_at(), window.id()));
+        windows
+    }
+
+    /// Windows active at `now`, ordered by start time.
+    pub fn active(&self, now: u64) -> Vec<MaintenanceWindow> {
+        let mut windows = self.upcoming(now);
+        windows.retain(|window| window.is_active(now));
+        windows
+    }
+
+    /// Windows active now that cover at least one network service.
+    pub fn active_for_services(&self) -> Vec<MaintenanceWindow> {
+        let mut windows = self.active(unix_time_s());
+        windows.retain(|window| !window.services().is_empty());
+        windows
+    }
+
+    /// Windows active now that cover at least one bridge operator.
+    pub fn active_for_operators(&self) -> Vec<MaintenanceWindow> {
+        let mut windows = self.active(unix_time_s());
+        windows.retain(|window| !window.operators().is_empty());
+        windows
+    }
+
+    /// Whether an active window covers `component` at `now`.
+    pub fn covers(&self, component: &Component, now: u64) -> bool {
+        self.active(now)
+            .iter()
+            .any(|window| window.covers(component))
+    }
+
+    /// Validates and persists a window.
+    pub fn schedule(
+        &self,
+        config: &MaintenanceWindowConfig,
+    ) -> Result<MaintenanceWindow, ScheduleError> {
+        validate(&self.components, config)?;
+
+        let mut scheduled = self
+            .scheduled
+            .write()
+            .expect("maintenance windows lock poisoned");
+        let window = self
+            .db
+            .insert(config)
+            .map_err(|e| ScheduleError::Db(e.to_string()))?;
+        scheduled.push(window.clone());
+        Ok(window)
+    }
+
+    /// Deletes the scheduled window with `id`. Returns whether it existed.
+    ///
+    /// Windows declared in the config cannot be cancelled.
+    pub fn cancel(&self, id: u64) -> Result<bool, ScheduleError> {
+        let mut scheduled = self
+            .scheduled
+            .write()
+            .expect("maintenance windows lock poisoned");
+        let removed = self
+            .db
+            .remove(id)
+            .map_err(|e| ScheduleError::Db(e.to_string()))?;
+        scheduled.retain(|window| window.id() != Some(id));
+        Ok(removed.is_some())
+    }
+}
+
+/// Checks that `config` ends after it starts and covers at least one of the
+/// `known` components, and no other.
+fn validate(known: &[Component], config: &MaintenanceWindowConfig) -> Result<(), ScheduleError> {
+    if config.ends_at() <= config.starts_at() {
+        return Err(ScheduleError::EmptyWindow);
+    }
+    if config.services().is_empty() && config.operators().is_empty() {
+        return Err(ScheduleError::NoComponents);
+    }
+    match MaintenanceWindow::new(None, config)
+        .components()
+        .find(|component| !known.contains(component))
+    {
+        Some(unknown) => Err(ScheduleError::UnknownComponent(unknown)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPERATOR_PK: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    fn window(
+        starts_at: u64,
+        ends_at: u64,
+        services: &str,
+        operators: &str,
+    ) -> MaintenanceWindowConfig {
+        toml::from_str(&format!(
+            r#"
+            starts_at = {starts_at}
+            ends_at = {ends_at}
+            message = "Node upgrade"
+            services = [{services}]
+            operators = [{operators}]
+            "#
+        ))
+        .expect("parse maintenance window")
+    }
+
+    fn components() -> Vec<Component> {
+        vec![
+            Component::Service("rpc".to_owned()),
+            Component::Service("bundler".to_owned()),
+            Component::Operator(OPERATOR_PK.to_owned()),
+        ]
+    }
+
+    #[test]
+    fn active_windows_cover_their_components() {
+        let schedule = MaintenanceSchedule::load(
+            &[window(100, 200, r#""rpc""#, "")],
+            components(),
+            MaintenanceDbSled::open_temporary().expect("open db"),
+        )
+        .expect("load schedule");
+        schedule
+            .schedule(&window(150, 300, "", &format!(r#""{OPERATOR_PK}""#)))
+            .expect("schedule window");
+
+        let rpc = Component::Service("rpc".to_owned());
+        let operator = Component::Operator(OPERATOR_PK.to_owned());
+        assert!(!schedule.covers(&rpc, 99));
+        assert!(schedule.covers(&rpc, 100));
+        assert!(!schedule.covers(&operator, 100));
+        assert!(schedule.covers(&operator, 200));
+        assert!(!schedule.covers(&rpc, 200));
+        assert!(!schedule.covers(&Component::Service("bundler".to_owned()), 150));
+
+        assert_eq!(schedule.upcoming(0).len(), 2);
+        assert_eq!(schedule.active(250).len(), 1);
+        assert!(schedule.upcoming(300).is_empty());
+    }
+
+    #[test]
+    fn configured_windows_keep_their_position() {
+        let schedule = MaintenanceSchedule::load(
+            &[
+                window(100, 200, r#""rpc""#, ""),
+                window(100, 200, "", &format!(r#""{OPERATOR_PK}""#)),
+            ],
+            components(),
+            MaintenanceDbSled::open_temporary().expect("open db"),
+        )
+        .expect("load schedule");
+
+        let indices = schedule
+            .upcoming(0)
+            .iter()
+            .map(MaintenanceWindow::config_index)
+            .collect::<Vec<_>>();
+        assert_eq!(indices, [Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn invalid_configured_windows_fail_to_load() {
+        for configured in [
+            window(200, 100, r#""rpc""#, ""),
+            window(100, 200, "", ""),
+            window(100, 200, r#""prover""#, ""),
+        ] {
+            assert!(MaintenanceSchedule::load(
+                &[configured],
+                components(),
+                MaintenanceDbSled::open_temporary().expect("open db"),
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn scheduled_windows_persist_until_cancelled() {
+        let db = MaintenanceDbSled::open_temporary().expect("open db");
+        let schedule = MaintenanceSchedule::load(&[], components(), db).expect("load schedule");
+
+        assert!(matches!(
+            schedule.schedule(&window(200, 200, r#""rpc""#, "")),
+            Err(ScheduleError::EmptyWindow)
+        ));
+        assert!(matches!(
+            schedule.schedule(&window(100, 200, "", "")),
+            Err(ScheduleError::NoComponents)
+        ));
+        assert!(matches!(
+            schedule.schedule(&window(100, 200, r#""prover""#, "")),
+            Err(ScheduleError::UnknownComponent(Component::Service(service))) if service == "prover"
+        ));
+
+        let first = schedule
+            .schedule(&window(100, 200, r#""rpc""#, ""))
+            .expect("schedule window");
+        let second = schedule
+            .schedule(&window(100, 200, r#""bundler""#, ""))
+            .expect("schedule window");
+        assert_eq!((first.id(), second.id()), (Some(0), Some(1)));
+        assert_eq!(schedule.db.windows().expect("stored windows").len(), 2);
+
+        assert!(schedule.cancel(0).expect("cancel window"));
+        assert!(!schedule.cancel(0).expect("cancel window"));
+        assert_eq!(schedule.upcoming(0), vec![second]);
+        assert_eq!(schedule.db.windows().expect("stored windows").len(), 1);
+    }
+}
@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+use status_config::MaintenanceWindowConfig;
+use status_utils::Component;
+
+/// Planned maintenance of network services and bridge operators.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MaintenanceWindow {
+    /// Identifies windows scheduled through the admin API. Windows declared
+    /// in the config have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<u64>,
+    /// Position of windows declared in the config in the configured list.
+    #[serde(skip)]
+    config_index: Option<usize>,
+    /// Unix time in seconds the window starts.
+    starts_at: u64,
+    /// Unix time in seconds the window ends.
+    ends_at: u64,
+    message: String,
+    /// Probe names of the covered network services.
+    services: Vec<String>,
+    /// Hex-encoded public keys of the covered bridge operators.
+    operators: Vec<String>,
+}
+
+impl MaintenanceWindow {
+    pub(crate) fn new(id: Option<u64>, config: &MaintenanceWindowConfig) -> Self {
+        Self {
+            id,
+            config_index: None,
+            starts_at: config.starts_at(),
+            ends_at: config.ends_at(),
+            message: config.message().to_owned(),
+            services: config.services().to_vec(),
+            operators: config.operators().iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    /// Window declared at `index` of the configured list.
+    pub(crate) fn configured(index: usize, config: &MaintenanceWindowConfig) -> Self {
+        Self {
+            config_index: Some(index),
+            ..Self::new(None, config)
+        }
+    }
+
+    pub fn id(&self) -> Option<u64> {
+        self.id
+    }
+
+    pub fn config_index(&self) -> Option<usize> {
+        self.config_index
+    }
+
+    pub fn starts_at(&self) -> u64 {
+        self.starts_at
+    }
+
+    pub fn ends_at(&self) -> u64 {
+        self.ends_at
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn services(&self) -> &[String] {
+        &self.services
+    }
+
+    pub fn operators(&self) -> &[String] {
+        &self.operators
+    }
+
+    /// Whether the window has started and not yet ended at `now`.
+    pub fn is_active(&self, now: u64) -> bool {
+        self.starts_at <= now && now < self.ends_at
+    }
+
+    /// Network services and bridge operators the window covers.
+    pub fn components(&self) -> impl Iterator<Item = Component> + '_ {
+        let services = self.services.iter().cloned().map(Component::Service);
+        let operators = self.operators.iter().cloned().map(Component::Operator);
+        services.chain(operators)
+    }
+
+    pub fn covers(&self, component: &Component) -> bool {
+        match component {
+            Component::Service(service) => self.services.contains(service),
+            Component::Operator(operator_pk) => self.operators.contains(operator_pk),
+        }
+    }
+}
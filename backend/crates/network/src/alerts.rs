@@ -0,0 +1,158 @@
+//! Alerts raised from the network status.
+
+use status_alerts::{Alert, MetricSample, Severity};
+use status_config::AlertMetric;
+use status_utils::Component;
+
+use super::{
+    l1::L1ReaderLag,
+    types::{NetworkStatus, Status},
+};
+
+/// Source the network alerts are reported under.
+pub(crate) const ALERT_SOURCE: &str = "network";
+
+/// Source the alerts of the configured rules on network metrics are
+/// reported under.
+pub(crate) const RULE_ALERT_SOURCE: &str = "network_rules";
+
+/// Returns an alert for each offline service, and one if the sequencer's L1
+/// reader lags behind the configured threshold.
+pub(crate) fn network_alerts(status: &NetworkStatus) -> Vec<Alert> {
+    let offline = status
+        .services()
+        .iter()
+        .filter(|(_, service_status)| service_status.status() == Status::Offline)
+        .map(|(service, _)| {
+            Alert::new(
+                format!("service_offline:{service}"),
+                Severity::Critical,
+                format!("Service {service} is offline"),
+            )
+            .for_component(Component::Service(service.clone()))
+        });
+    let reader = status
+        .l1()
+        .and_then(|l1| l1.reader())
+        .and_then(l1_reader_alert);
+    offline.chain(reader).collect()
+}
+
+fn l1_reader_alert(reader: &L1ReaderLag) -> Option<Alert> {
+    reader.is_lagging().then(|| {
+        Alert::new(
+            "l1_reader_lagging",
+            Severity::Warning,
+            format!(
+                "Sequencer L1 reader is {} blocks behind the tip, at height {}",
+                reader.lag_blocks(),
+                reader.sequencer_l1_height()
+            ),
+        )
+    })
+}
+
+/// Returns the samples of `metric` in `status`, or [`None`] if it is not a
+/// network metric.
+pub(crate) fn rule_samples(
+    status: &NetworkStatus,
+    metric: AlertMetric,
+) -> Option<Vec<MetricSample>> {
+    let samples = match metric {
+        AlertMetric::ServiceOffline => status
+            .services()
+            .iter()
+            .map(|(service, service_status)| {
+                let offline = service_status.status() == Status::Offline;
+                MetricSample::for_subject(service, f64::from(u8::from(offline)))
+                    .for_component(Component::Service(service.clone()))
+            })
+            .collect(),
+        AlertMetric::ServiceLatencyP95Ms => status
+            .services()
+            .iter()
+            .filter_map(|(service, service_status)| {
+                let latency = service_status.latency()?;
+                Some(
+                    MetricSample::for_subject(service, latency.p95_ms() as f64)
+                        .for_component(Component::Service(service.clone())),
+                )
+            })
+            .collect(),
+        AlertMetric::L1TipAgeS => status
+            .l1()
+            .map(|l1| MetricSample::new(l1.tip_age_s() as f64))
+            .into_iter()
+            .collect(),
+        AlertMetric::L1ReaderLagBlocks => status
+            .l1()
+            .and_then(|l1| l1.reader())
+            .map(|reader| MetricSample::new(reader.lag_blocks() as f64))
+            .into_iter()
+            .collect(),
+        AlertMetric::CheckpointAgeS => status
+            .checkpoint()
+            .and_then(|checkpoint| checkpoint.landed())
+            .and_then(|landed| landed.since_landed_s())
+            .map(|since_landed_s| MetricSample::new(since_landed_s as f64))
+            .into_iter()
+            .collect(),
+        AlertMetric::OperatorOffline
+        | AlertMetric::DepositInProgress
+        | AlertMetric::WithdrawalInProgress
+        | AlertMetric::WithdrawalQueueDepth
+        | AlertMetric::IndexerLagBlocks => return None,
+    };
+    Some(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ServiceStatus;
+
+    #[test]
+    fn only_offline_services_alert() {
+        let mut status = NetworkStatus::default();
+        status.set_service("rpc", ServiceStatus::new(Status::Offline, None));
+        status.set_service("sequencer", ServiceStatus::new(Status::Online, None));
+        status.set_service(
+            "bundler",
+            ServiceStatus::misconfigured("chain id 1, expected 8150".to_owned(), None),
+        );
+
+        let alerts = network_alerts(&status);
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].key(), "service_offline:rpc");
+        assert_eq!(alerts[0].severity(), Severity::Critical);
+    }
+
+    #[test]
+    fn lagging_l1_reader_alerts() {
+        let alert = l1_reader_alert(&L1ReaderLag::new(100, 90, 3)).expect("reader lags");
+
+        assert_eq!(alert.key(), "l1_reader_lagging");
+        assert_eq!(alert.severity(), Severity::Warning);
+        assert!(l1_reader_alert(&L1ReaderLag::new(100, 98, 3)).is_none());
+    }
+
+    #[test]
+    fn rule_samples_cover_network_metrics_only() {
+        let mut status = NetworkStatus::default();
+        status.set_service("rpc", ServiceStatus::new(Status::Offline, None));
+        status.set_service("sequencer", ServiceStatus::new(Status::Online, None));
+
+        assert_eq!(
+            rule_samples(&status, AlertMetric::ServiceOffline),
+            Some(vec![
+                MetricSample::for_subject("rpc", 1.0)
+                    .for_component(Component::Service("rpc".to_owned())),
+                MetricSample::for_subject("sequencer", 0.0)
+                    .for_component(Component::Service("sequencer".to_owned())),
+            ])
+        );
+        assert_eq!(rule_samples(&status, AlertMetric::L1TipAgeS), Some(vec![]));
+        assert_eq!(rule_samples(&status, AlertMetric::OperatorOffline), None);
+    }
+}
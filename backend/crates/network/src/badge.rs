@@ -0,0 +1,66 @@
+//! SVG badges of the network services.
+
+use std::sync::Arc;
+
+use axum::{extract::Path, http::StatusCode, response::Response};
+use status_utils::{badge_name, unix_time_ms, Badge, BadgeColor};
+use tracing::warn;
+
+use super::{
+    types::{NetworkMonitoringContext, Status},
+    uptime::service_uptime_pct,
+};
+
+/// Badge of a service in `status`, or of a service not probed yet.
+fn service_badge(service: &str, status: Option<Status>, uptime_pct: Option<f64>) -> Badge {
+    let (message, color) = match status {
+        Some(Status::Online) => ("online", BadgeColor::Green),
+        Some(Status::Degraded) => ("degraded", BadgeColor::Yellow),
+        Some(Status::Misconfigured) => ("misconfigured", BadgeColor::Orange),
+        Some(Status::Offline) => ("offline", BadgeColor::Red),
+        None => ("unknown", BadgeColor::Grey),
+    };
+    Badge::new(service, message, color).with_uptime(uptime_pct)
+}
+
+/// Handler to get the badge of a service, e.g. `/badge/sequencer.svg`
+pub async fn get_service_badge(
+    context: Arc<NetworkMonitoringContext>,
+    Path(file_name): Path<String>,
+) -> Result<Response, StatusCode> {
+    let service = badge_name(&file_name).ok_or(StatusCode::NOT_FOUND)?;
+    if !context.probes().iter().any(|probe| probe.name() == service) {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let status = context
+        .status()
+        .await
+        .services()
+        .get(service)
+        .map(|service_status| service_status.status());
+    let uptime_pct = service_uptime_pct(context.history_db(), service, unix_time_ms())
+        .unwrap_or_else(|e| {
+            warn!(error = %e, %service, "could not read service uptime");
+            None
+        });
+
+    Ok(service_badge(service, status, uptime_pct)
+        .into_response(context.config().status_refetch_interval()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn service_badges_follow_status() {
+        let badge = service_badge("sequencer", Some(Status::Degraded), Some(99.5));
+        assert_eq!(badge.message(), "degraded | 99.50%");
+        assert_eq!(badge.color(), BadgeColor::Yellow);
+
+        let badge = service_badge("sequencer", None, None);
+        assert_eq!(badge.message(), "unknown");
+        assert_eq!(badge.color(), BadgeColor::Grey);
+    }
+}
@@ -0,0 +1,142 @@
+//! Functional checks for an ERC-4337 bundler.
+
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::HttpClient;
+
+use super::probe::{is_healthy, parse_hex_quantity};
+
+const ETH_SUPPORTED_ENTRY_POINTS_METHOD: &str = "eth_supportedEntryPoints";
+const ETH_CHAIN_ID_METHOD: &str = "eth_chainId";
+
+/// Outcome of each bundler check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BundlerReport {
+    healthy: bool,
+    entry_point_supported: bool,
+    chain_id_matches: bool,
+}
+
+impl BundlerReport {
+    pub(crate) fn is_ok(&self) -> bool {
+        self.healthy && self.entry_point_supported && self.chain_id_matches
+    }
+
+    /// Why the bundler is serving the wrong entry point or chain, if it is.
+    pub(crate) fn misconfiguration(&self) -> Option<String> {
+        let mut reasons = Vec::new();
+        if !self.entry_point_supported {
+            reasons.push("bundler does not support the configured entry point");
+        }
+        if !self.chain_id_matches {
+            reasons.push("bundler chain id differs from the RPC endpoint's");
+        }
+        (!reasons.is_empty()).then(|| reasons.join("; "))
+    }
+}
+
+/// RPC clients and expectations for a bundler probe.
+pub(crate) struct BundlerChecks<'a> {
+    pub(crate) health_url: &'a str,
+    pub(crate) bundler: &'a HttpClient,
+    pub(crate) chain: &'a HttpClient,
+    pub(crate) entry_point: &'a str,
+}
+
+impl BundlerChecks<'_> {
+    /// Runs every check. Transport and RPC errors are returned as errors so
+    /// the probe can retry them; failed checks are reported in the result.
+    pub(crate) async fn run(&self, http_client: &reqwest::Client) -> anyhow::Result<BundlerReport> {
+        let response = http_client.get(self.health_url).send().await?;
+        let healthy = response.status().is_success() && {
+            let body = response.text().await?;
+            is_healthy(serde_json::from_str(&body).ok().as_ref(), Some(&body))
+        };
+
+        let entry_points: Vec<String> = self
+            .bundler
+            .request(ETH_SUPPORTED_ENTRY_POINTS_METHOD, Vec::<()>::new())
+            .await?;
+        let bundler_chain_id: String = self
+            .bundler
+            .request(ETH_CHAIN_ID_METHOD, Vec::<()>::new())
+            .await?;
+        let chain_id: String = self
+            .chain
+            .request(ETH_CHAIN_ID_METHOD, Vec::<()>::new())
+            .await?;
+
+        Ok(BundlerReport {
+            healthy,
+            entry_point_supported: supports_entry_point(&entry_points, self.entry_point),
+            chain_id_matches: chain_ids_match(&bundler_chain_id, &chain_id),
+        })
+    }
+}
+
+/// Addresses are compared case-insensitively since checksummed and lowercase
+/// forms are both common.
+fn supports_entry_point(entry_points: &[String], entry_point: &str) -> bool {
+    entry_points
+        .iter()
+        .any(|supported| supported.eq_ignore_ascii_case(entry_point))
+}
+
+fn chain_ids_match(bundler_chain_id: &str, chain_id: &str) -> bool {
+    matches!(
+        (parse_hex_quantity(bundler_chain_id), parse_hex_quantity(chain_id)),
+        (Some(bundler), Some(chain)) if bundler == chain
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_point_comparison_ignores_case() {
+        let entry_points = vec!["0x0000000071727de22e5e9d8baf0edac6f37da032".to_owned()];
+
+        assert!(supports_entry_point(
+            &entry_points,
+            "0x0000000071727De22E5E9d8BAf0edAc6f37da032"
+        ));
+        assert!(!supports_entry_point(
+            &entry_points,
+            "0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789"
+        ));
+    }
+
+    #[test]
+    fn failed_checks_are_reported_as_misconfiguration() {
+        let report = BundlerReport {
+            healthy: true,
+            entry_point_supported: false,
+            chain_id_matches: false,
+        };
+
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.misconfiguration().as_deref(),
+            Some(
+                "bundler does not support the configured entry point; \
+                 bundler chain id differs from the RPC endpoint's"
+            )
+        );
+        assert_eq!(
+            BundlerReport {
+                healthy: false,
+                entry_point_supported: true,
+                chain_id_matches: true,
+            }
+            .misconfiguration(),
+            None
+        );
+    }
+
+    #[test]
+    fn chain_ids_compare_numerically() {
+        assert!(chain_ids_match("0x2104", "0x02104"));
+        assert!(!chain_ids_match("0x2104", "0x1"));
+        assert!(!chain_ids_match("8452", "8452"));
+    }
+}
@@ -0,0 +1,151 @@
+//! Typed model of the OL `strata_getChainStatus` response.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// OL chain status as returned by `strata_getChainStatus`.
+///
+/// Only the fields the dashboard reads are kept; deserialization tolerates the
+/// extra fields the sequencer includes (`latest`, etc.).
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RpcChainStatus {
+    pub(crate) tip: RpcChainTip,
+
+    #[serde(default, deserialize_with = "deserialize_epoch_commitment")]
+    pub(crate) confirmed: Option<RpcEpochCommitment>,
+
+    #[serde(default, deserialize_with = "deserialize_epoch_commitment")]
+    pub(crate) finalized: Option<RpcEpochCommitment>,
+}
+
+/// Current OL chain tip.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RpcChainTip {
+    pub(crate) blkid: String,
+    pub(crate) slot: u64,
+    pub(crate) epoch: u64,
+}
+
+/// Commitment to the terminal block of an OL epoch.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct RpcEpochCommitment {
+    pub(crate) epoch: u64,
+}
+
+/// Epoch commitments are null or empty until the first epoch is confirmed, so
+/// an unparseable commitment is treated as absent rather than failing the
+/// whole chain-status response.
+fn deserialize_epoch_commitment<'de, D>(
+    deserializer: D,
+) -> Result<Option<RpcEpochCommitment>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| serde_json::from_value(value).ok()))
+}
+
+/// Parses a `strata_getChainStatus` response. Returns [`None`] if the
+/// response has no well-formed `tip`.
+pub(crate) fn parse_chain_status(json: &serde_json::Value) -> Option<RpcChainStatus> {
+    RpcChainStatus::deserialize(json).ok()
+}
+
+/// OL chain progress reported in the network status.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ChainProgress {
+    tip_slot: u64,
+    tip_epoch: u64,
+    tip_blkid: String,
+    confirmed_epoch: Option<u64>,
+    finalized_epoch: Option<u64>,
+    /// Number of epochs the finalized epoch trails the tip epoch.
+    finalization_lag_epochs: Option<u64>,
+}
+
+impl From<&RpcChainStatus> for ChainProgress {
+    fn from(status: &RpcChainStatus) -> Self {
+        let finalized_epoch = status.finalized.as_ref().map(|c| c.epoch);
+        Self {
+            tip_slot: status.tip.slot,
+            tip_epoch: status.tip.epoch,
+            tip_blkid: status.tip.blkid.clone(),
+            confirmed_epoch: status.confirmed.as_ref().map(|c| c.epoch),
+            finalized_epoch,
+            finalization_lag_epochs: finalized_epoch
+                .map(|finalized| status.tip.epoch.saturating_sub(finalized)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_full_chain_status() {
+        let status = parse_chain_status(&json!({
+            "tip": {
+                "blkid": "0xab",
+                "slot": 1234,
+                "epoch": 12,
+                "is_terminal": false
+            },
+            "confirmed": {
+                "epoch": 11,
+                "last_slot": 1199,
+                "last_blkid": "0xcd"
+            },
+            "finalized": {
+                "epoch": 9,
+                "last_slot": 999,
+                "last_blkid": "0xef"
+            },
+            "latest": {}
+        }))
+        .expect("chain status should parse");
+
+        assert_eq!(
+            ChainProgress::from(&status),
+            ChainProgress {
+                tip_slot: 1234,
+                tip_epoch: 12,
+                tip_blkid: "0xab".to_owned(),
+                confirmed_epoch: Some(11),
+                finalized_epoch: Some(9),
+                finalization_lag_epochs: Some(3),
+            }
+        );
+    }
+
+    #[test]
+    fn empty_epoch_commitments_are_absent() {
+        let status = parse_chain_status(&json!({
+            "tip": {
+                "blkid": "0x00",
+                "slot": 1,
+                "epoch": 0,
+                "is_terminal": false
+            },
+            "confirmed": {},
+            "finalized": null
+        }))
+        .expect("chain status should parse");
+
+        let progress = ChainProgress::from(&status);
+        assert_eq!(progress.confirmed_epoch, None);
+        assert_eq!(progress.finalized_epoch, None);
+        assert_eq!(progress.finalization_lag_epochs, None);
+    }
+
+    #[test]
+    fn chain_status_with_malformed_tip_is_rejected() {
+        assert!(parse_chain_status(&json!({
+            "tip": {
+                "blkid": "0x00"
+            }
+        }))
+        .is_none());
+    }
+}
@@ -0,0 +1,337 @@
+//! OL checkpoint tracking and the checkpoints' inclusion on Bitcoin.
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::rpc_params;
+use serde::{Deserialize, Serialize};
+use status_utils::{create_rpc_client, unix_time_s, EsploraClient};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use super::types::{Degradation, NetworkMonitoringContext, Status};
+
+const STRATA_LATEST_CHECKPOINT_INDEX_METHOD: &str = "strata_getLatestCheckpointIndex";
+const STRATA_CHECKPOINT_INFO_METHOD: &str = "strata_getCheckpointInfo";
+
+/// Checkpoints before the latest one that are searched for one with an L1
+/// commitment, since the latest checkpoint may not be posted yet.
+const CHECKPOINT_LOOKBACK: u64 = 4;
+
+/// Checkpoint fields from `strata_getCheckpointInfo`.
+#[derive(Deserialize)]
+struct RpcCheckpointInfo {
+    /// Checkpoint index, which is the epoch it commits to.
+    idx: u64,
+    /// Commitment transaction, once the checkpoint is posted to L1.
+    l1_reference: Option<RpcCheckpointL1Ref>,
+}
+
+#[derive(Deserialize)]
+struct RpcCheckpointL1Ref {
+    txid: String,
+}
+
+/// Transaction status from Esplora's `/tx/{txid}/status`.
+#[derive(Deserialize)]
+struct EsploraTxStatus {
+    confirmed: bool,
+    block_height: Option<u64>,
+    /// Header time of the including block in unix seconds.
+    block_time: Option<u64>,
+}
+
+/// Latest checkpoint posted to L1.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct LandedCheckpoint {
+    epoch: u64,
+    l1_txid: String,
+    /// Confirmations of the commitment transaction, once it is mined.
+    confirmations: Option<u64>,
+    /// Seconds since the block including the latest mined commitment
+    /// transaction, which is an earlier checkpoint's while this one is unmined.
+    since_landed_s: Option<u64>,
+}
+
+impl LandedCheckpoint {
+    pub(crate) fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    pub(crate) fn confirmations(&self) -> Option<u64> {
+        self.confirmations
+    }
+
+    pub(crate) fn since_landed_s(&self) -> Option<u64> {
+        self.since_landed_s
+    }
+}
+
+/// OL checkpoint progress reported in the network status.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct CheckpointStatus {
+    status: Status,
+    /// Why checkpoints are reported as [`Status::Degraded`], if they are.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    degradation: Option<Degradation>,
+    /// Epoch of the latest checkpoint the sequencer has produced.
+    latest_epoch: u64,
+    /// Latest checkpoint with an L1 commitment transaction, if any.
+    landed: Option<LandedCheckpoint>,
+}
+
+impl CheckpointStatus {
+    /// Reports checkpoints as degraded if none has been posted or mined on L1
+    /// recently, or if the latest mined one landed `stale_threshold_s` or more
+    /// ago. A zero threshold disables the age check.
+    fn new(latest_epoch: u64, landed: Option<LandedCheckpoint>, stale_threshold_s: u64) -> Self {
+        let degradation = match landed.as_ref().map(|landed| landed.since_landed_s) {
+            None => Some(Degradation::no_checkpoint()),
+            Some(None) => Some(Degradation::unmined_checkpoint()),
+            Some(Some(since_landed_s))
+                if stale_threshold_s > 0 && since_landed_s >= stale_threshold_s =>
+            {
+                Some(Degradation::stale_checkpoint(since_landed_s))
+            }
+            Some(Some(_)) => None,
+        };
+        Self {
+            status: match degradation {
+                Some(_) => Status::Degraded,
+                None => Status::Online,
+            },
+            degradation,
+            latest_epoch,
+            landed,
+        }
+    }
+
+    pub(crate) fn status(&self) -> Status {
+        self.status
+    }
+
+    pub(crate) fn latest_epoch(&self) -> u64 {
+        self.latest_epoch
+    }
+
+    pub(crate) fn landed(&self) -> Option<&LandedCheckpoint> {
+        self.landed.as_ref()
+    }
+}
+
+async fn fetch_checkpoint_info(
+    sequencer: &HttpClient,
+    idx: u64,
+) -> anyhow::Result<Option<RpcCheckpointInfo>> {
+    sequencer
+        .request(STRATA_CHECKPOINT_INFO_METHOD, rpc_params![idx])
+        .await
+        .context(STRATA_CHECKPOINT_INFO_METHOD)
+}
+
+/// Locates the commitment transaction of `checkpoint` on L1, with an Esplora
+/// tip at `tip_height`.
+async fn fetch_landed_checkpoint(
+    esplora: &EsploraClient,
+    checkpoint: RpcCheckpointInfo,
+    l1_ref: RpcCheckpointL1Ref,
+    tip_height: u64,
+    now_s: u64,
+) -> anyhow::Result<LandedCheckpoint> {
+    let tx_status: EsploraTxStatus = esplora
+        .get_json(&format!("/tx/{}/status", l1_ref.txid))
+        .await
+        .context("fetch checkpoint tx status")?;
+
+    let mined = tx_status.confirmed.then_some(tx_status);
+    Ok(LandedCheckpoint {
+        epoch: checkpoint.idx,
+        l1_txid: l1_ref.txid,
+        confirmations: mined
+            .as_ref()
+            .and_then(|status| status.block_height)
+            .map(|height| tip_height.saturating_sub(height) + 1),
+        since_landed_s: mined
+            .as_ref()
+            .and_then(|status| status.block_time)
+            .map(|block_time| now_s.saturating_sub(block_time)),
+    })
+}
+
+/// Fetches the latest checkpoint and the latest one posted to L1.
+async fn fetch_checkpoint_status(
+    sequencer: &HttpClient,
+    esplora: &EsploraClient,
+    stale_threshold_s: u64,
+) -> anyhow::Result<Option<CheckpointStatus>> {
+    let latest_idx: Option<u64> = sequencer
+        .request(
+            STRATA_LATEST_CHECKPOINT_INDEX_METHOD,
+            rpc_params![Option::<bool>::None],
+        )
+        .await
+        .context(STRATA_LATEST_CHECKPOINT_INDEX_METHOD)?;
+    let Some(latest_idx) = latest_idx else {
+        return Ok(None);
+    };
+
+    let tip_height = esplora
+        .get_tip_height()
+        .await
+        .context("fetch tip height")?
+        .trim()
+        .parse::<u64>()
+        .context("parse tip height")?;
+
+    // The latest posted checkpoint is aged from the latest mined one, so a
+    // commitment stuck in the mempool still goes stale.
+    let mut landed: Option<LandedCheckpoint> = None;
+    for idx in (latest_idx.saturating_sub(CHECKPOINT_LOOKBACK)..=latest_idx).rev() {
+        let Some(mut checkpoint) = fetch_checkpoint_info(sequencer, idx).await? else {
+            continue;
+        };
+        if let Some(l1_ref) = checkpoint.l1_reference.take() {
+            let posted =
+                fetch_landed_checkpoint(esplora, checkpoint, l1_ref, tip_height, unix_time_s())
+                    .await?;
+            let since_landed_s = posted.since_landed_s;
+            landed.get_or_insert(posted).since_landed_s = since_landed_s;
+            if since_landed_s.is_some() {
+                break;
+            }
+        }
+    }
+
+    Ok(Some(CheckpointStatus::new(
+        latest_idx,
+        landed,
+        stale_threshold_s,
+    )))
+}
+
+/// Polls the sequencer's checkpoints and their L1 inclusion on the status
+/// refetch interval and publishes the checkpoint status.
+///
+/// Never returns; the monitoring task aborts it on shutdown.
+///
+/// # Panics
+///
+/// Panics if the sequencer URL is invalid.
+pub(crate) async fn run_checkpoint_monitor(context: Arc<NetworkMonitoringContext>) {
+    let config = context.config();
+    let sequencer = create_rpc_client(config.sequencer_url());
+    let mut interval = interval(Duration::from_secs(config.status_refetch_interval().max(1)));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        let checkpoint = match fetch_checkpoint_status(
+            &sequencer,
+            context.esplora(),
+            config.checkpoint_stale_threshold_s(),
+        )
+        .await
+        {
+            Ok(checkpoint) => {
+                info!(?checkpoint, "updated checkpoint status");
+                checkpoint
+            }
+            Err(e) => {
+                warn!(error = %e, "could not get checkpoint status");
+                None
+            }
+        };
+        context
+            .update_status(|network_status| network_status.set_checkpoint(checkpoint))
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn landed(since_landed_s: Option<u64>) -> LandedCheckpoint {
+        LandedCheckpoint {
+            epoch: 41,
+            l1_txid: "00ab".to_owned(),
+            confirmations: since_landed_s.map(|_| 3),
+            since_landed_s,
+        }
+    }
+
+    fn unmined(since_earlier_landed_s: Option<u64>) -> LandedCheckpoint {
+        LandedCheckpoint {
+            confirmations: None,
+            since_landed_s: since_earlier_landed_s,
+            ..landed(None)
+        }
+    }
+
+    #[test]
+    fn checkpoint_info_deserializes() {
+        let posted: RpcCheckpointInfo = serde_json::from_str(
+            r#"{
+                "idx": 42,
+                "l1_range": [{"height": 100, "blkid": "aa"}, {"height": 110, "blkid": "bb"}],
+                "l2_range": [{"slot": 4000, "blkid": "cc"}, {"slot": 4200, "blkid": "dd"}],
+                "l1_reference": {
+                    "l1_commitment": {"height": 111, "blkid": "ee"},
+                    "txid": "00ab",
+                    "wtxid": "00cd"
+                },
+                "confirmation_status": "confirmed"
+            }"#,
+        )
+        .expect("parse checkpoint info");
+        let pending: RpcCheckpointInfo =
+            serde_json::from_str(r#"{"idx": 43, "l1_reference": null}"#)
+                .expect("parse checkpoint info");
+
+        assert_eq!(posted.idx, 42);
+        assert_eq!(
+            posted.l1_reference.map(|l1_ref| l1_ref.txid).as_deref(),
+            Some("00ab")
+        );
+        assert!(pending.l1_reference.is_none());
+    }
+
+    #[test]
+    fn recent_checkpoint_is_online() {
+        let status = CheckpointStatus::new(42, Some(landed(Some(600))), 3600);
+
+        assert_eq!(status.status, Status::Online);
+        assert_eq!(status.degradation, None);
+    }
+
+    #[test]
+    fn unmined_checkpoint_ages_from_the_latest_mined_one() {
+        let recent = CheckpointStatus::new(42, Some(unmined(Some(600))), 3600);
+        assert_eq!(recent.status, Status::Online);
+
+        let stuck = CheckpointStatus::new(42, Some(unmined(Some(3600))), 3600);
+        assert_eq!(stuck.status, Status::Degraded);
+
+        let none_mined = CheckpointStatus::new(42, Some(unmined(None)), 3600);
+        assert_eq!(none_mined.status, Status::Degraded);
+        assert_eq!(
+            none_mined.degradation,
+            Some(Degradation::unmined_checkpoint())
+        );
+    }
+
+    #[test]
+    fn checkpoints_that_stop_landing_are_degraded() {
+        let stale = CheckpointStatus::new(42, Some(landed(Some(3600))), 3600);
+        assert_eq!(stale.status, Status::Degraded);
+
+        let never_landed = CheckpointStatus::new(42, None, 3600);
+        assert_eq!(never_landed.status, Status::Degraded);
+
+        let disabled = CheckpointStatus::new(42, Some(landed(Some(3600))), 0);
+        assert_eq!(disabled.status, Status::Online);
+    }
+}
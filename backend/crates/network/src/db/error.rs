@@ -0,0 +1,13 @@
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum DbError {
+    #[error(transparent)]
+    Sled(#[from] typed_sled::error::Error),
+}
+
+impl From<sled::Error> for DbError {
+    fn from(value: sled::Error) -> Self {
+        Self::Sled(value.into())
+    }
+}
+
+pub(crate) type DbResult<T> = Result<T, DbError>;
@@ -0,0 +1,302 @@
+use std::path::Path;
+
+use status_utils::db::open_sled_db;
+use typed_sled::{SledDb, SledTree};
+
+use crate::db::{
+    error::{DbError, DbResult},
+    traits::NetworkHistoryDb,
+    types::{DbLatestStatus, DbStatusSample, DbUptimeBucket},
+};
+
+use super::schema::{LatestStatusSchema, StatusSampleSchema, UptimeBucketSchema};
+
+const LATEST_STATUS_KEY: u8 = 0;
+
+/// Sled-backed network status history database.
+#[derive(Debug)]
+pub struct NetworkHistoryDbSled {
+    _db: SledDb,
+    samples: SledTree<StatusSampleSchema>,
+    buckets: SledTree<UptimeBucketSchema>,
+    latest_status: SledTree<LatestStatusSchema>,
+}
+
+impl NetworkHistoryDbSled {
+    /// Open the history database under `{datadir}/network_history`.
+    pub fn open(datadir: impl AsRef<Path>) -> anyhow::Result<Self> {
+        open_sled_db(
+            datadir.as_ref().join("network_history"),
+            "network history",
+            Self::from_sled_db,
+        )
+    }
+
+    /// Open a temporary in-memory-like sled database deleted on drop.
+    #[cfg(test)]
+    pub fn open_temporary() -> anyhow::Result<Self> {
+        status_utils::db::open_temporary_sled_db("network history", Self::from_sled_db)
+    }
+
+    fn from_sled_db(sled_db: sled::Db) -> DbResult<Self> {
+        let db = SledDb::new(sled_db)?;
+
+        Ok(Self {
+            samples: db.get_tree::<StatusSampleSchema>()?,
+            buckets: db.get_tree::<UptimeBucketSchema>()?,
+            latest_status: db.get_tree::<LatestStatusSchema>()?,
+            _db: db,
+        })
+    }
+}
+
+impl NetworkHistoryDb for NetworkHistoryDbSled {
+    fn put_sample(
+        &self,
+        timestamp_ms: u64,
+        bucket_start_ms: u64,
+        sample: &DbStatusSample,
+    ) -> DbResult<()> {
+        // The monitoring task is the only writer, so the bucket read-modify-write
+        // does not race.
+        let mut bucket = self.buckets.get(&bucket_start_ms)?.unwrap_or_default();
+        bucket.record(sample);
+        self.samples.insert(&timestamp_ms, sample)?;
+        self.buckets.insert(&bucket_start_ms, &bucket)?;
+        Ok(())
+    }
+
+    fn get_latest_samples(
+        &self,
+        since_ms: u64,
+        limit: usize,
+    ) -> DbResult<Vec<(u64, DbStatusSample)>> {
+        let mut samples = self
+            .samples
+            .range(since_ms..)?
+            .rev()
+            .take(limit)
+            .map(|result| result.map_err(DbError::from))
+            .collect::<DbResult<Vec<_>>>()?;
+        samples.reverse();
+        Ok(samples)
+    }
+
+    fn get_buckets_since(&self, since_ms: u64) -> DbResult<Vec<(u64, DbUptimeBucket)>> {
+        self.buckets
+            .range(since_ms..)?
+            .map(|result| result.map_err(DbError::from))
+            .collect()
+    }
+
+    fn prune_before(&self, cutoff_ms: u64) -> DbResult<()> {
+        let sample_keys = self
+            .samples
+            .range(..cutoff_ms)?
+            .map(|result| result.map(|(key, _)| key).map_err(DbError::from))
+            .collect::<DbResult<Vec<_>>>()?;
+        for key in sample_keys {
+            self.samples.remove(&key)?;
+        }
+
+        let bucket_keys = self
+            .buckets
+            .range(..cutoff_ms)?
+            .map(|result| result.map(|(key, _)| key).map_err(DbError::from))
+            .collect::<DbResult<Vec<_>>>()?;
+        for key in bucket_keys {
+            self.buckets.remove(&key)?;
+        }
+        Ok(())
+    }
+
+    fn put_latest_status(&self, latest: &DbLatestStatus) -> DbResult<()> {
+        self.latest_status.insert(&LATEST_STATUS_KEY, latest)?;
+        Ok(())
+    }
+
+    fn get_latest_status(&self) -> DbResult<Option<DbLatestStatus>> {
+        Ok(self.latest_status.get(&LATEST_STATUS_KEY)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::PathBuf,
+        time::{SystemTime, UNIX_EPOCH},
+    };
+
+    use super::*;
+    use crate::{
+        db::{history::mock::MockNetworkHistoryDb, types::DbStatusCounts},
+        types::{NetworkStatus, ServiceStatus, Status},
+    };
+
+    fn make_unique_db_path(test_name: &str) -> PathBuf {
+        let now_nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("current time must be >= UNIX_EPOCH")
+            .as_nanos();
+        std::env::temp_dir().join(format!(
+            "network_history_db_{test_name}_{}_{}",
+            std::process::id(),
+            now_nanos
+        ))
+    }
+
+    fn sample(sequencer: Status) -> DbStatusSample {
+        DbStatusSample {
+            services: [("sequencer".to_owned(), sequencer)].into(),
+        }
+    }
+
+    fn assert_samples_and_buckets(db: &impl NetworkHistoryDb) {
+        db.put_sample(1_000, 0, &sample(Status::Online))
+            .expect("put sample");
+        db.put_sample(2_000, 0, &sample(Status::Degraded))
+            .expect("put sample");
+        db.put_sample(3_000, 0, &sample(Status::Offline))
+            .expect("put sample");
+        db.put_sample(4_000, 3_600_000, &sample(Status::Online))
+            .expect("put sample");
+
+        assert_eq!(
+            db.get_latest_samples(0, 2).expect("latest samples"),
+            vec![
+                (3_000, sample(Status::Offline)),
+                (4_000, sample(Status::Online))
+            ]
+        );
+        assert_eq!(
+            db.get_latest_samples(2_000, 10)
+                .expect("latest samples")
+                .len(),
+            3
+        );
+
+        let buckets = db.get_buckets_since(0).expect("buckets");
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(
+            buckets[0].1.services["sequencer"],
+            DbStatusCounts {
+                online: 1,
+                degraded: 1,
+                offline: 1,
+                misconfigured: 0,
+            }
+        );
+        assert_eq!(buckets[1].0, 3_600_000);
+    }
+
+    fn assert_prune(db: &impl NetworkHistoryDb) {
+        db.put_sample(1_000, 0, &sample(Status::Online))
+            .expect("put sample");
+        db.put_sample(3_601_000, 3_600_000, &sample(Status::Online))
+            .expect("put sample");
+
+        db.prune_before(3_600_000).expect("prune");
+
+        assert_eq!(
+            db.get_latest_samples(0, 10).expect("latest samples"),
+            vec![(3_601_000, sample(Status::Online))]
+        );
+        assert_eq!(
+            db.get_buckets_since(0)
+                .expect("buckets")
+                .into_iter()
+                .map(|(start, _)| start)
+                .collect::<Vec<_>>(),
+            vec![3_600_000]
+        );
+    }
+
+    fn latest_status(polled_at: u64, sequencer: Status) -> DbLatestStatus {
+        let mut status = NetworkStatus::default();
+        status.set_service("sequencer", ServiceStatus::new(sequencer, None));
+        DbLatestStatus { polled_at, status }
+    }
+
+    fn assert_latest_status(db: &impl NetworkHistoryDb) {
+        assert!(db.get_latest_status().expect("latest status").is_none());
+
+        db.put_latest_status(&latest_status(1, Status::Online))
+            .expect("put latest status");
+        db.put_latest_status(&latest_status(2, Status::Offline))
+            .expect("put latest status");
+
+        let latest = db
+            .get_latest_status()
+            .expect("latest status")
+            .expect("stored latest status");
+        assert_eq!(latest.polled_at, 2);
+        assert_eq!(
+            latest.status.service_statuses(),
+            [("sequencer".to_owned(), Status::Offline)].into()
+        );
+    }
+
+    #[test]
+    fn history_db_samples_and_buckets_sled() {
+        let db = NetworkHistoryDbSled::open_temporary().expect("open db");
+        assert_samples_and_buckets(&db);
+    }
+
+    #[test]
+    fn history_db_samples_and_buckets_mock() {
+        assert_samples_and_buckets(&MockNetworkHistoryDb::default());
+    }
+
+    #[test]
+    fn history_db_prune_sled() {
+        let db = NetworkHistoryDbSled::open_temporary().expect("open db");
+        assert_prune(&db);
+    }
+
+    #[test]
+    fn history_db_prune_mock() {
+        assert_prune(&MockNetworkHistoryDb::default());
+    }
+
+    #[test]
+    fn history_db_latest_status_sled() {
+        let db = NetworkHistoryDbSled::open_temporary().expect("open db");
+        assert_latest_status(&db);
+    }
+
+    #[test]
+    fn history_db_latest_status_mock() {
+        assert_latest_status(&MockNetworkHistoryDb::default());
+    }
+
+    #[test]
+    fn history_persists_across_reopen() {
+        let path = make_unique_db_path("reopen");
+
+        {
+            let db = NetworkHistoryDbSled::open(&path).expect("open db");
+            db.put_sample(1_000, 0, &sample(Status::Online))
+                .expect("put sample");
+            db.put_latest_status(&latest_status(1, Status::Online))
+                .expect("put latest status");
+        }
+
+        {
+            let db = NetworkHistoryDbSled::open(&path).expect("reopen db");
+            assert_eq!(
+                db.get_latest_samples(0, 10).expect("latest samples"),
+                vec![(1_000, sample(Status::Online))]
+            );
+            assert_eq!(db.get_buckets_since(0).expect("buckets").len(), 1);
+            assert_eq!(
+                db.get_latest_status()
+                    .expect("latest status")
+                    .map(|latest| latest.polled_at),
+                Some(1)
+            );
+        }
+
+        fs::remove_dir_all(path).expect("remove db dir");
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use crate::db::{
+    error::DbResult,
+    traits::NetworkHistoryDb,
+    types::{DbLatestStatus, DbStatusSample, DbUptimeBucket},
+};
+
+/// In-memory network history database for tests.
+#[derive(Debug, Default)]
+pub(crate) struct MockNetworkHistoryDb {
+    samples: RwLock<BTreeMap<u64, DbStatusSample>>,
+    buckets: RwLock<BTreeMap<u64, DbUptimeBucket>>,
+    latest_status: RwLock<Option<DbLatestStatus>>,
+}
+
+impl NetworkHistoryDb for MockNetworkHistoryDb {
+    fn put_sample(
+        &self,
+        timestamp_ms: u64,
+        bucket_start_ms: u64,
+        sample: &DbStatusSample,
+    ) -> DbResult<()> {
+        self.samples
+            .write()
+            .expect("mock samples lock poisoned")
+            .insert(timestamp_ms, sample.clone());
+        self.buckets
+            .write()
+            .expect("mock buckets lock poisoned")
+            .entry(bucket_start_ms)
+            .or_default()
+            .record(sample);
+        Ok(())
+    }
+
+    fn get_latest_samples(
+        &self,
+        since_ms: u64,
+        limit: usize,
+    ) -> DbResult<Vec<(u64, DbStatusSample)>> {
+        let mut samples = self
+            .samples
+            .read()
+            .expect("mock samples lock poisoned")
+            .range(since_ms..)
+            .rev()
+            .take(limit)
+            .map(|(timestamp_ms, sample)| (*timestamp_ms, sample.clone()))
+            .collect::<Vec<_>>();
+        samples.reverse();
+        Ok(samples)
+    }
+
+    fn get_buckets_since(&self, since_ms: u64) -> DbResult<Vec<(u64, DbUptimeBucket)>> {
+        Ok(self
+            .buckets
+            .read()
+            .expect("mock buckets lock poisoned")
+            .range(since_ms..)
+            .map(|(start_ms, bucket)| (*start_ms, bucket.clone()))
+            .collect())
+    }
+
+    fn prune_before(&self, cutoff_ms: u64) -> DbResult<()> {
+        self.samples
+            .write()
+            .expect("mock samples lock poisoned")
+            .retain(|timestamp_ms, _| *timestamp_ms >= cutoff_ms);
+        self.buckets
+            .write()
+            .expect("mock buckets lock poisoned")
+            .retain(|start_ms, _| *start_ms >= cutoff_ms);
+        Ok(())
+    }
+
+    fn put_latest_status(&self, latest: &DbLatestStatus) -> DbResult<()> {
+        *self
+            .latest_status
+            .write()
+            .expect("mock latest_status lock poisoned") = Some(latest.clone());
+        Ok(())
+    }
+
+    fn get_latest_status(&self) -> DbResult<Option<DbLatestStatus>> {
+        Ok(self
+            .latest_status
+            .read()
+            .expect("mock latest_status lock poisoned")
+            .clone())
+    }
+}
@@ -0,0 +1,7 @@
+//! Persistence for the network status time series.
+
+pub(crate) mod db;
+pub(crate) mod schema;
+
+#[cfg(test)]
+pub(crate) mod mock;
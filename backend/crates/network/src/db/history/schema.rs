@@ -0,0 +1,42 @@
+//! Schema and codec definitions for the network-history DB trees.
+
+use status_utils::impl_json_value_codec;
+use typed_sled::{schema::TreeName, Schema};
+
+use crate::db::types::{DbLatestStatus, DbStatusSample, DbUptimeBucket};
+
+/// Per-poll service statuses keyed by unix timestamp in milliseconds.
+#[derive(Debug)]
+pub(crate) struct StatusSampleSchema;
+
+impl Schema for StatusSampleSchema {
+    const TREE_NAME: TreeName = TreeName("status_sample");
+    type Key = u64;
+    type Value = DbStatusSample;
+}
+
+/// Hourly status counts keyed by bucket start in unix milliseconds.
+#[derive(Debug)]
+pub(crate) struct UptimeBucketSchema;
+
+impl Schema for UptimeBucketSchema {
+    const TREE_NAME: TreeName = TreeName("uptime_bucket");
+    type Key = u64;
+    type Value = DbUptimeBucket;
+}
+
+/// Latest combined network status cell.
+#[derive(Debug)]
+pub(crate) struct LatestStatusSchema;
+
+impl Schema for LatestStatusSchema {
+    const TREE_NAME: TreeName = TreeName("latest_status");
+    type Key = u8;
+    type Value = DbLatestStatus;
+}
+
+// ---- Value codecs ----
+
+impl_json_value_codec!(StatusSampleSchema, DbStatusSample);
+impl_json_value_codec!(UptimeBucketSchema, DbUptimeBucket);
+impl_json_value_codec!(LatestStatusSchema, DbLatestStatus);
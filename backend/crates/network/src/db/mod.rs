@@ -0,0 +1,8 @@
+//! Persistence for the network crate.
+
+pub(crate) mod error;
+pub(crate) mod history;
+pub(crate) mod traits;
+pub(crate) mod types;
+
+pub use history::db::NetworkHistoryDbSled;
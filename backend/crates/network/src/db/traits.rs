@@ -0,0 +1,40 @@
+use crate::db::{
+    error::DbResult,
+    types::{DbLatestStatus, DbStatusSample, DbUptimeBucket},
+};
+
+/// Storage contract for the network status time series.
+///
+/// Samples and hourly buckets are keyed by unix timestamp in milliseconds.
+pub(crate) trait NetworkHistoryDb: Send + Sync {
+    /// Stores one poll result and folds it into the bucket starting at
+    /// `bucket_start_ms`.
+    fn put_sample(
+        &self,
+        timestamp_ms: u64,
+        bucket_start_ms: u64,
+        sample: &DbStatusSample,
+    ) -> DbResult<()>;
+
+    /// Fetches the latest samples at or after `since_ms`, in ascending
+    /// timestamp order.
+    ///
+    /// At most `limit` rows are returned.
+    fn get_latest_samples(
+        &self,
+        since_ms: u64,
+        limit: usize,
+    ) -> DbResult<Vec<(u64, DbStatusSample)>>;
+
+    /// Fetches all buckets starting at or after `since_ms`, in ascending order.
+    fn get_buckets_since(&self, since_ms: u64) -> DbResult<Vec<(u64, DbUptimeBucket)>>;
+
+    /// Deletes samples and buckets keyed before `cutoff_ms`.
+    fn prune_before(&self, cutoff_ms: u64) -> DbResult<()>;
+
+    /// Replaces the stored latest status.
+    fn put_latest_status(&self, latest: &DbLatestStatus) -> DbResult<()>;
+
+    /// Returns the stored latest status, if any.
+    fn get_latest_status(&self) -> DbResult<Option<DbLatestStatus>>;
+}
@@ -0,0 +1,71 @@
+//! Types shared by DB traits and implementations.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{NetworkStatus, Status};
+
+/// Latest combined network status and when it was last successfully polled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DbLatestStatus {
+    /// Unix timestamp in seconds up to which both a probed service and Esplora
+    /// had answered.
+    pub(crate) polled_at: u64,
+    pub(crate) status: NetworkStatus,
+}
+
+/// Status of every monitored service in one poll, keyed by service name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DbStatusSample {
+    pub(crate) services: BTreeMap<String, Status>,
+}
+
+/// Number of polls that observed a service in each state.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DbStatusCounts {
+    pub(crate) online: u64,
+    pub(crate) degraded: u64,
+    pub(crate) offline: u64,
+    #[serde(default)]
+    pub(crate) misconfigured: u64,
+}
+
+impl DbStatusCounts {
+    pub(crate) fn record(&mut self, status: Status) {
+        match status {
+            Status::Online => self.online += 1,
+            Status::Degraded => self.degraded += 1,
+            Status::Offline => self.offline += 1,
+            Status::Misconfigured => self.misconfigured += 1,
+        }
+    }
+
+    pub(crate) fn merge(&mut self, other: &Self) {
+        self.online += other.online;
+        self.degraded += other.degraded;
+        self.offline += other.offline;
+        self.misconfigured += other.misconfigured;
+    }
+
+    pub(crate) fn total(&self) -> u64 {
+        self.online + self.degraded + self.offline + self.misconfigured
+    }
+}
+
+/// Aggregated poll results for a fixed time bucket, keyed by service name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct DbUptimeBucket {
+    pub(crate) services: BTreeMap<String, DbStatusCounts>,
+}
+
+impl DbUptimeBucket {
+    pub(crate) fn record(&mut self, sample: &DbStatusSample) {
+        for (service, status) in &sample.services {
+            self.services
+                .entry(service.clone())
+                .or_default()
+                .record(*status);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Checks that EVM JSON-RPC endpoints serve the expected chain.
+
+use anyhow::Context;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::rpc_params;
+use serde_json::Value;
+
+use super::probe::parse_hex_quantity;
+use status_config::NetworkMonitoringConfig;
+
+/// Chain id and genesis hash an endpoint must report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ExpectedIdentity {
+    chain_id: Option<u64>,
+    genesis_hash: Option<String>,
+}
+
+impl ExpectedIdentity {
+    /// Returns `None` when neither a chain id nor a genesis hash is configured.
+    pub(crate) fn from_config(config: &NetworkMonitoringConfig) -> Option<Self> {
+        let identity = Self {
+            chain_id: config.expected_chain_id(),
+            genesis_hash: config.expected_genesis_hash().map(str::to_owned),
+        };
+        (identity.chain_id.is_some() || identity.genesis_hash.is_some()).then_some(identity)
+    }
+
+    /// Compares the endpoint's `eth_chainId` and, if `check_genesis` is set,
+    /// the hash of block 0 against the expected values.
+    ///
+    /// Returns a description of the first mismatch, if any.
+    pub(crate) async fn check(
+        &self,
+        client: &HttpClient,
+        check_genesis: bool,
+    ) -> anyhow::Result<Option<String>> {
+        if let Some(expected) = self.chain_id {
+            let reported: String = client
+                .request("eth_chainId", rpc_params![])
+                .await
+                .context("eth_chainId")?;
+            if let Some(mismatch) = chain_id_mismatch(expected, &reported) {
+                return Ok(Some(mismatch));
+            }
+        }
+
+        if let Some(expected) = self.genesis_hash.as_deref().filter(|_| check_genesis) {
+            let genesis: Value = client
+                .request("eth_getBlockByNumber", rpc_params!["0x0", false])
+                .await
+                .context("eth_getBlockByNumber")?;
+            if let Some(mismatch) = genesis_mismatch(expected, &genesis) {
+                return Ok(Some(mismatch));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+fn chain_id_mismatch(expected: u64, reported: &str) -> Option<String> {
+    match parse_hex_quantity(reported) {
+        Some(chain_id) if chain_id == expected => None,
+        Some(chain_id) => Some(format!("chain id {chain_id}, expected {expected}")),
+        None => Some(format!(
+            "invalid chain id {reported:?}, expected {expected}"
+        )),
+    }
+}
+
+fn genesis_mismatch(expected: &str, block: &Value) -> Option<String> {
+    match block.get("hash").and_then(Value::as_str) {
+        Some(hash) if hash.eq_ignore_ascii_case(expected) => None,
+        Some(hash) => Some(format!("genesis hash {hash}, expected {expected}")),
+        None => Some(format!("no genesis block, expected {expected}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn chain_id_is_compared_as_quantity() {
+        assert_eq!(chain_id_mismatch(8150, "0x1fd6"), None);
+        assert_eq!(
+            chain_id_mismatch(8150, "0x1"),
+            Some("chain id 1, expected 8150".to_owned())
+        );
+        assert!(chain_id_mismatch(8150, "testnet").is_some());
+    }
+
+    #[test]
+    fn genesis_hash_is_compared_ignoring_case() {
+        assert_eq!(
+            genesis_mismatch("0xABCD", &json!({ "hash": "0xabcd" })),
+            None
+        );
+        assert_eq!(
+            genesis_mismatch("0xabcd", &json!({ "hash": "0x1234" })),
+            Some("genesis hash 0x1234, expected 0xabcd".to_owned())
+        );
+        assert!(genesis_mismatch("0xabcd", &Value::Null).is_some());
+    }
+}
@@ -0,0 +1,323 @@
+//! Bitcoin L1 progress read from Esplora.
+
+use std::sync::Arc;
+
+use anyhow::Context;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::rpc_params;
+use serde::{Deserialize, Serialize};
+use status_config::NetworkMonitoringConfig;
+use status_utils::{create_rpc_client, unix_time_s, EsploraClient};
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use super::types::NetworkMonitoringContext;
+
+/// Block fields read from Esplora's `/block/{hash}`.
+#[derive(Deserialize)]
+struct EsploraBlock {
+    height: u64,
+    /// Block header time in unix seconds.
+    timestamp: u64,
+}
+
+/// Mempool summary from Esplora's `/mempool`.
+#[derive(Deserialize)]
+struct EsploraMempool {
+    count: u64,
+    vsize: u64,
+}
+
+/// JSON-RPC method whose result carries the sequencer's L1 reader status.
+const STRATA_L1_STATUS_METHOD: &str = "strata_getL1status";
+
+/// Sequencer L1 reader status fields from `strata_getL1status`.
+#[derive(Deserialize)]
+struct RpcL1Status {
+    /// Height of the latest L1 block the sequencer has ingested.
+    cur_height: u64,
+}
+
+/// How far the sequencer's L1 reader trails the Esplora tip.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct L1ReaderLag {
+    /// Height of the latest L1 block the sequencer has ingested.
+    sequencer_l1_height: u64,
+    /// Number of blocks the sequencer is behind the Esplora tip.
+    lag_blocks: u64,
+    /// Whether the lag is above the configured threshold.
+    lagging: bool,
+}
+
+impl L1ReaderLag {
+    pub(crate) fn new(
+        tip_height: u64,
+        sequencer_l1_height: u64,
+        lag_threshold_blocks: u64,
+    ) -> Self {
+        let lag_blocks = tip_height.saturating_sub(sequencer_l1_height);
+        Self {
+            sequencer_l1_height,
+            lag_blocks,
+            lagging: lag_threshold_blocks > 0 && lag_blocks > lag_threshold_blocks,
+        }
+    }
+
+    pub(crate) fn sequencer_l1_height(&self) -> u64 {
+        self.sequencer_l1_height
+    }
+
+    pub(crate) fn lag_blocks(&self) -> u64 {
+        self.lag_blocks
+    }
+
+    pub(crate) fn is_lagging(&self) -> bool {
+        self.lagging
+    }
+}
+
+/// Bitcoin L1 progress as seen by Esplora.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct L1Status {
+    tip_height: u64,
+    tip_hash: String,
+    /// Header time of the tip block in unix seconds.
+    tip_timestamp: u64,
+    /// Seconds since the tip block's header time.
+    tip_age_s: u64,
+    /// Number of transactions in the mempool.
+    mempool_tx_count: u64,
+    /// Total virtual size of the mempool in vbytes.
+    mempool_vsize: u64,
+    /// Whether the tip is older than the configured no-new-block threshold.
+    no_new_block: bool,
+    /// Sequencer L1 reader lag, if the sequencer reported its L1 height.
+    reader: Option<L1ReaderLag>,
+}
+
+impl L1Status {
+    fn new(
+        tip_hash: String,
+        tip: &EsploraBlock,
+        mempool: &EsploraMempool,
+        now_s: u64,
+        no_new_block_threshold_s: u64,
+    ) -> Self {
+        // Header times may run ahead of the local clock by up to two hours.
+        let tip_age_s = now_s.saturating_sub(tip.timestamp);
+        Self {
+            tip_height: tip.height,
+            tip_hash,
+            tip_timestamp: tip.timestamp,
+            tip_age_s,
+            mempool_tx_count: mempool.count,
+            mempool_vsize: mempool.vsize,
+            no_new_block: no_new_block_threshold_s > 0 && tip_age_s >= no_new_block_threshold_s,
+            reader: None,
+        }
+    }
+
+    pub(crate) fn tip_height(&self) -> u64 {
+        self.tip_height
+    }
+
+    pub(crate) fn tip_age_s(&self) -> u64 {
+        self.tip_age_s
+    }
+
+    pub(crate) fn mempool_tx_count(&self) -> u64 {
+        self.mempool_tx_count
+    }
+
+    pub(crate) fn mempool_vsize(&self) -> u64 {
+        self.mempool_vsize
+    }
+
+    pub(crate) fn reader(&self) -> Option<&L1ReaderLag> {
+        self.reader.as_ref()
+    }
+}
+
+/// Fetches the height of the latest L1 block the sequencer has ingested.
+async fn fetch_sequencer_l1_height(sequencer: &HttpClient) -> anyhow::Result<u64> {
+    let status: RpcL1Status = sequencer
+        .request(STRATA_L1_STATUS_METHOD, rpc_params![])
+        .await
+        .context(STRATA_L1_STATUS_METHOD)?;
+    Ok(status.cur_height)
+}
+
+/// Fetches the current L1 tip and mempool from Esplora, and the sequencer's
+/// L1 reader lag behind that tip.
+///
+/// Fails only if Esplora does; an unavailable sequencer leaves the reader lag
+/// unset.
+async fn fetch_l1_status(
+    esplora: &EsploraClient,
+    sequencer: &HttpClient,
+    config: &NetworkMonitoringConfig,
+) -> anyhow::Result<L1Status> {
+    let tip_hash = esplora
+        .get_text("/blocks/tip/hash")
+        .await
+        .context("fetch tip hash")?
+        .trim()
+        .to_owned();
+    // The height is read from the block itself so it matches the hash even
+    // if a new block arrives in between.
+    let tip: EsploraBlock = esplora
+        .get_json(&format!("/block/{tip_hash}"))
+        .await
+        .context("fetch tip block")?;
+    let mempool: EsploraMempool = esplora
+        .get_json("/mempool")
+        .await
+        .context("fetch mempool")?;
+
+    let mut l1 = L1Status::new(
+        tip_hash,
+        &tip,
+        &mempool,
+        unix_time_s(),
+        config.l1_no_new_block_threshold_s(),
+    );
+    l1.reader = match fetch_sequencer_l1_height(sequencer).await {
+        Ok(height) => {
+            let reader = L1ReaderLag::new(
+                l1.tip_height,
+                height,
+                config.l1_reader_lag_threshold_blocks(),
+            );
+            if reader.lagging {
+                warn!(
+                    lag_blocks = reader.lag_blocks,
+                    sequencer_l1_height = height,
+                    tip_height = l1.tip_height,
+                    "sequencer l1 reader is behind the esplora tip"
+                );
+            }
+            Some(reader)
+        }
+        Err(e) => {
+            warn!(error = %e, "could not get sequencer l1 status");
+            None
+        }
+    };
+
+    Ok(l1)
+}
+
+/// Polls Esplora and the sequencer's L1 reader on the status refetch interval
+/// and publishes the L1 status.
+///
+/// Never returns; the monitoring task aborts it on shutdown.
+///
+/// # Panics
+///
+/// Panics if the sequencer URL is invalid.
+pub(crate) async fn run_l1_monitor(context: Arc<NetworkMonitoringContext>) {
+    let config = context.config();
+    let sequencer = create_rpc_client(config.sequencer_url());
+    let mut interval = interval(Duration::from_secs(config.status_refetch_interval().max(1)));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        let l1 = match fetch_l1_status(context.esplora(), &sequencer, config).await {
+            Ok(l1) => {
+                info!(?l1, "updated l1 status");
+                context.record_l1_poll(unix_time_s());
+                Some(l1)
+            }
+            Err(e) => {
+                warn!(error = %e, "could not get l1 status from esplora");
+                None
+            }
+        };
+        context
+            .update_status(|network_status| network_status.set_l1(l1))
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mempool() -> EsploraMempool {
+        EsploraMempool {
+            count: 42,
+            vsize: 12_345,
+        }
+    }
+
+    #[test]
+    fn esplora_responses_deserialize() {
+        let block: EsploraBlock = serde_json::from_str(
+            r#"{"id":"00ab","height":100,"timestamp":1700000000,"tx_count":1}"#,
+        )
+        .expect("parse block");
+        let mempool: EsploraMempool = serde_json::from_str(
+            r#"{"count":42,"vsize":12345,"total_fee":1000,"fee_histogram":[[1.0,12345]]}"#,
+        )
+        .expect("parse mempool");
+
+        assert_eq!((block.height, block.timestamp), (100, 1_700_000_000));
+        assert_eq!((mempool.count, mempool.vsize), (42, 12_345));
+    }
+
+    #[test]
+    fn old_tip_is_flagged_as_no_new_block() {
+        let tip = EsploraBlock {
+            height: 100,
+            timestamp: 1_000,
+        };
+
+        let fresh = L1Status::new("00ab".to_owned(), &tip, &mempool(), 1_000 + 599, 600);
+        assert_eq!(fresh.tip_age_s, 599);
+        assert!(!fresh.no_new_block);
+
+        let stale = L1Status::new("00ab".to_owned(), &tip, &mempool(), 1_000 + 600, 600);
+        assert!(stale.no_new_block);
+
+        let disabled = L1Status::new("00ab".to_owned(), &tip, &mempool(), 1_000 + 600, 0);
+        assert!(!disabled.no_new_block);
+    }
+
+    #[test]
+    fn sequencer_l1_status_deserializes() {
+        let status: RpcL1Status = serde_json::from_str(
+            r#"{"bitcoin_rpc_connected":true,"cur_height":812,"cur_tip_blkid":"00ab","last_update":1700000000,"network":"signet"}"#,
+        )
+        .expect("parse l1 status");
+
+        assert_eq!(status.cur_height, 812);
+    }
+
+    #[test]
+    fn reader_lag_above_threshold_is_flagged() {
+        let within = L1ReaderLag::new(100, 97, 3);
+        assert_eq!(within.lag_blocks, 3);
+        assert!(!within.lagging);
+
+        assert!(L1ReaderLag::new(100, 96, 3).lagging);
+        assert!(!L1ReaderLag::new(100, 90, 0).lagging);
+        // A sequencer ahead of a lagging Esplora is not behind.
+        assert_eq!(L1ReaderLag::new(100, 101, 3).lag_blocks, 0);
+    }
+
+    #[test]
+    fn tip_from_the_future_has_zero_age() {
+        let tip = EsploraBlock {
+            height: 100,
+            timestamp: 2_000,
+        };
+
+        let l1 = L1Status::new("00ab".to_owned(), &tip, &mempool(), 1_000, 600);
+        assert_eq!(l1.tip_height, 100);
+        assert_eq!(l1.tip_age_s, 0);
+        assert!(!l1.no_new_block);
+    }
+}
@@ -0,0 +1,139 @@
+//! Rolling round-trip latency statistics for probes.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use tokio::time::Duration;
+
+/// Latency of a service's latest probe and percentiles over recent probes,
+/// in milliseconds.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ServiceLatency {
+    /// Round trip of the latest probe, or `None` if it got no response.
+    latest_ms: Option<u64>,
+    p50_ms: u64,
+    p95_ms: u64,
+    p99_ms: u64,
+}
+
+impl ServiceLatency {
+    pub(crate) fn latest_ms(&self) -> Option<u64> {
+        self.latest_ms
+    }
+
+    pub(crate) fn p95_ms(&self) -> u64 {
+        self.p95_ms
+    }
+
+    /// Returns the 50th, 95th and 99th percentiles, labelled by quantile.
+    pub(crate) fn percentiles_ms(&self) -> [(&'static str, u64); 3] {
+        [
+            ("0.5", self.p50_ms),
+            ("0.95", self.p95_ms),
+            ("0.99", self.p99_ms),
+        ]
+    }
+}
+
+/// Round-trip times of the most recent probes that got a response.
+#[derive(Debug)]
+pub(crate) struct LatencyWindow {
+    samples: VecDeque<u64>,
+    capacity: usize,
+}
+
+impl LatencyWindow {
+    pub(crate) fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Records the latest probe's round trip, if it got a response, and
+    /// returns the updated statistics. Returns [`None`] until a probe has
+    /// responded.
+    pub(crate) fn record(&mut self, latency: Option<Duration>) -> Option<ServiceLatency> {
+        let latest_ms = latency.map(|latency| latency.as_millis() as u64);
+        if let Some(latest_ms) = latest_ms {
+            if self.samples.len() == self.capacity {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(latest_ms);
+        }
+
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = self.samples.iter().copied().collect::<Vec<_>>();
+        sorted.sort_unstable();
+        Some(ServiceLatency {
+            latest_ms,
+            p50_ms: percentile(&sorted, 50),
+            p95_ms: percentile(&sorted, 95),
+            p99_ms: percentile(&sorted, 99),
+        })
+    }
+}
+
+/// Nearest-rank percentile of a non-empty, ascending slice.
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    let rank = (sorted.len() * pct).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let mut window = LatencyWindow::new(100);
+        let mut latency = None;
+        for ms in (1..=100).rev() {
+            latency = window.record(Some(Duration::from_millis(ms)));
+        }
+
+        assert_eq!(
+            latency,
+            Some(ServiceLatency {
+                latest_ms: Some(1),
+                p50_ms: 50,
+                p95_ms: 95,
+                p99_ms: 99,
+            })
+        );
+    }
+
+    #[test]
+    fn window_keeps_only_most_recent_samples() {
+        let mut window = LatencyWindow::new(2);
+        window.record(Some(Duration::from_millis(1_000)));
+        window.record(Some(Duration::from_millis(10)));
+        let latency = window
+            .record(Some(Duration::from_millis(20)))
+            .expect("latency");
+
+        assert_eq!(latency.p99_ms, 20);
+        assert_eq!(latency.p50_ms, 10);
+    }
+
+    #[test]
+    fn failed_probe_keeps_previous_percentiles() {
+        let mut window = LatencyWindow::new(10);
+        assert_eq!(window.record(None), None);
+
+        window.record(Some(Duration::from_millis(30)));
+        assert_eq!(
+            window.record(None),
+            Some(ServiceLatency {
+                latest_ms: None,
+                p50_ms: 30,
+                p95_ms: 30,
+                p99_ms: 30,
+            })
+        );
+    }
+}
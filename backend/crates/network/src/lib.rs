@@ -1,5 +1,24 @@
+mod alerts;
+mod badge;
+mod bundler;
+mod chain_status;
+mod checkpoint;
+mod db;
+mod identity;
+mod l1;
+mod latency;
+mod metrics;
+mod probe;
+mod prometheus;
+mod stall;
 mod status;
 mod types;
+mod uptime;
 
+pub use badge::get_service_badge;
+pub use db::NetworkHistoryDbSled;
+pub use metrics::{get_network_metrics, ChainMetrics};
+pub use prometheus::encode_network_metrics;
 pub use status::{get_network_status, network_monitoring_task};
 pub use types::{NetworkMonitoringContext, NetworkStatus};
+pub use uptime::{get_network_status_history, HistoryQuery, NetworkStatusHistory};
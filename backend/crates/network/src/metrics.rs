@@ -0,0 +1,326 @@
+//! L2 throughput and fee metrics over a rolling window of recent blocks.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::http::StatusCode;
+use axum::Json;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::rpc_params;
+use serde::Serialize;
+use serde_json::Value;
+use status_utils::create_rpc_client;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+use tracing::{info, warn};
+
+use super::{probe::parse_hex_quantity, types::NetworkMonitoringContext};
+
+/// Block fields the metrics are computed from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BlockSample {
+    number: u64,
+    /// Block timestamp in unix seconds.
+    timestamp: u64,
+    tx_count: u64,
+    gas_used: u64,
+    gas_limit: u64,
+    /// Base fee in wei, absent before EIP-1559.
+    base_fee_per_gas: Option<u64>,
+}
+
+impl BlockSample {
+    /// Parses an `eth_getBlockByNumber` result. Returns [`None`] if a required
+    /// field is missing or malformed.
+    fn from_rpc(block: &Value) -> Option<Self> {
+        let quantity = |field: &str| block.get(field)?.as_str().and_then(parse_hex_quantity);
+        Some(Self {
+            number: quantity("number")?,
+            timestamp: quantity("timestamp")?,
+            tx_count: block.get("transactions")?.as_array()?.len() as u64,
+            gas_used: quantity("gasUsed")?,
+            gas_limit: quantity("gasLimit")?,
+            base_fee_per_gas: quantity("baseFeePerGas"),
+        })
+    }
+}
+
+/// L2 activity over the most recent blocks.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ChainMetrics {
+    /// Number of blocks the metrics cover.
+    window_blocks: usize,
+    from_block: u64,
+    to_block: u64,
+    /// Mean time between consecutive blocks in seconds, once the window holds
+    /// two blocks.
+    avg_block_time_s: Option<f64>,
+    avg_txs_per_block: f64,
+    /// Mean ratio of gas used to gas limit over the blocks with a gas limit,
+    /// between 0 and 1.
+    avg_gas_used_ratio: f64,
+    /// Base fee of the latest block in wei.
+    latest_base_fee_wei: Option<u64>,
+    /// Mean base fee over the blocks that have one, in wei.
+    avg_base_fee_wei: Option<f64>,
+}
+
+/// Rolling window of the most recent consecutive blocks.
+#[derive(Debug)]
+pub(crate) struct BlockWindow {
+    capacity: usize,
+    blocks: VecDeque<BlockSample>,
+}
+
+impl BlockWindow {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            blocks: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Number of the latest block in the window.
+    fn last_number(&self) -> Option<u64> {
+        self.blocks.back().map(|block| block.number)
+    }
+
+    /// Appends `block`. Blocks at or above its number are dropped first, so
+    /// a reorg replaces the blocks it orphaned.
+    fn push(&mut self, block: BlockSample) {
+        while self
+            .blocks
+            .back()
+            .is_some_and(|last| last.number >= block.number)
+        {
+            self.blocks.pop_back();
+        }
+        self.blocks.push_back(block);
+        while self.blocks.len() > self.capacity {
+            self.blocks.pop_front();
+        }
+    }
+
+    fn metrics(&self) -> Option<ChainMetrics> {
+        let first = self.blocks.front()?;
+        let last = self.blocks.back()?;
+        let count = self.blocks.len();
+        let mean = |sum: f64, n: usize| sum / n as f64;
+
+        let base_fees = self
+            .blocks
+            .iter()
+            .filter_map(|block| block.base_fee_per_gas)
+            .collect::<Vec<_>>();
+        let gas_used_ratios = self
+            .blocks
+            .iter()
+            .filter(|block| block.gas_limit > 0)
+            .map(|block| block.gas_used as f64 / block.gas_limit as f64)
+            .collect::<Vec<_>>();
+
+        Some(ChainMetrics {
+            window_blocks: count,
+            from_block: first.number,
+            to_block: last.number,
+            avg_block_time_s: (last.number > first.number).then(|| {
+                last.timestamp.saturating_sub(first.timestamp) as f64
+                    / (last.number - first.number) as f64
+            }),
+            avg_txs_per_block: mean(
+                self.blocks.iter().map(|block| block.tx_count as f64).sum(),
+                count,
+            ),
+            avg_gas_used_ratio: if gas_used_ratios.is_empty() {
+                0.0
+            } else {
+                mean(gas_used_ratios.iter().sum(), gas_used_ratios.len())
+            },
+            latest_base_fee_wei: last.base_fee_per_gas,
+            avg_base_fee_wei: (!base_fees.is_empty()).then(|| {
+                mean(
+                    base_fees.iter().map(|fee| *fee as f64).sum(),
+                    base_fees.len(),
+                )
+            }),
+        })
+    }
+}
+
+async fn fetch_block(rpc: &HttpClient, number: u64) -> anyhow::Result<BlockSample> {
+    let block: Value = rpc
+        .request(
+            "eth_getBlockByNumber",
+            rpc_params![format!("{number:#x}"), false],
+        )
+        .await
+        .with_context(|| format!("eth_getBlockByNumber {number}"))?;
+    BlockSample::from_rpc(&block).with_context(|| format!("malformed block {number}"))
+}
+
+/// Fetches the blocks after the latest one in `window` up to the chain head,
+/// at most a window's worth.
+async fn sample_new_blocks(rpc: &HttpClient, window: &mut BlockWindow) -> anyhow::Result<()> {
+    let head: String = rpc
+        .request("eth_blockNumber", rpc_params![])
+        .await
+        .context("eth_blockNumber")?;
+    let head = parse_hex_quantity(&head).context("malformed block number")?;
+
+    let oldest_in_window = head.saturating_sub(window.capacity as u64 - 1);
+    let start = match window.last_number() {
+        // Resample the head after a reorg to a shorter chain.
+        Some(last) if last >= head => head,
+        Some(last) => (last + 1).max(oldest_in_window),
+        None => oldest_in_window,
+    };
+    for number in start..=head {
+        window.push(fetch_block(rpc, number).await?);
+    }
+    Ok(())
+}
+
+/// Samples new L2 blocks on the status refetch interval and publishes metrics
+/// over the configured window.
+///
+/// Never returns; the monitoring task aborts it on shutdown.
+///
+/// # Panics
+///
+/// Panics if the RPC URL is invalid.
+pub(crate) async fn run_metrics_sampler(context: Arc<NetworkMonitoringContext>) {
+    let config = context.config();
+    let rpc = create_rpc_client(config.rpc_url());
+    let mut window = BlockWindow::new(config.metrics_window_blocks());
+    let mut interval = interval(Duration::from_secs(config.status_refetch_interval().max(1)));
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = sample_new_blocks(&rpc, &mut window).await {
+            warn!(error = %e, "could not sample l2 blocks");
+        }
+        // Blocks sampled before an error are still published.
+        let metrics = window.metrics();
+        info!(?metrics, "updated chain metrics");
+        context.set_chain_metrics(metrics).await;
+    }
+}
+
+/// Handler to get L2 throughput and fee metrics
+pub async fn get_network_metrics(
+    context: Arc<NetworkMonitoringContext>,
+) -> Result<Json<ChainMetrics>, StatusCode> {
+    context
+        .chain_metrics()
+        .await
+        .map(Json)
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn block(number: u64, timestamp: u64, tx_count: u64, base_fee: u64) -> BlockSample {
+        BlockSample {
+            number,
+            timestamp,
+            tx_count,
+            gas_used: 15_000_000,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(base_fee),
+        }
+    }
+
+    #[test]
+    fn block_sample_parses_rpc_block() {
+        let sample = BlockSample::from_rpc(&json!({
+            "number": "0x10",
+            "timestamp": "0x6553f100",
+            "transactions": ["0xaa", "0xbb"],
+            "gasUsed": "0x5208",
+            "gasLimit": "0x1c9c380",
+            "baseFeePerGas": "0x7"
+        }))
+        .expect("block should parse");
+
+        assert_eq!(
+            sample,
+            BlockSample {
+                number: 16,
+                timestamp: 1_700_000_000,
+                tx_count: 2,
+                gas_used: 21_000,
+                gas_limit: 30_000_000,
+                base_fee_per_gas: Some(7),
+            }
+        );
+        assert!(BlockSample::from_rpc(&Value::Null).is_none());
+    }
+
+    #[test]
+    fn metrics_average_over_window() {
+        let mut window = BlockWindow::new(3);
+        window.push(block(1, 100, 9, 1));
+        window.push(block(2, 102, 1, 2));
+        window.push(block(3, 104, 2, 3));
+        window.push(block(4, 110, 3, 4));
+
+        let metrics = window.metrics().expect("window has blocks");
+        assert_eq!(metrics.window_blocks, 3);
+        assert_eq!((metrics.from_block, metrics.to_block), (2, 4));
+        assert_eq!(metrics.avg_block_time_s, Some(4.0));
+        assert_eq!(metrics.avg_txs_per_block, 2.0);
+        assert_eq!(metrics.avg_gas_used_ratio, 0.5);
+        assert_eq!(metrics.latest_base_fee_wei, Some(4));
+        assert_eq!(metrics.avg_base_fee_wei, Some(3.0));
+    }
+
+    #[test]
+    fn blocks_without_gas_limit_do_not_dilute_gas_ratio() {
+        let mut window = BlockWindow::new(10);
+        window.push(block(1, 100, 1, 1));
+        window.push(BlockSample {
+            gas_used: 0,
+            gas_limit: 0,
+            ..block(2, 102, 1, 1)
+        });
+
+        let metrics = window.metrics().expect("window has blocks");
+        assert_eq!(metrics.avg_gas_used_ratio, 0.5);
+    }
+
+    #[test]
+    fn reorged_blocks_are_replaced() {
+        let mut window = BlockWindow::new(10);
+        window.push(block(1, 100, 1, 1));
+        window.push(block(2, 102, 1, 1));
+        window.push(block(3, 104, 1, 1));
+        window.push(block(2, 103, 5, 1));
+
+        assert_eq!(window.last_number(), Some(2));
+        let metrics = window.metrics().expect("window has blocks");
+        assert_eq!(metrics.window_blocks, 2);
+        assert_eq!(metrics.avg_txs_per_block, 3.0);
+    }
+
+    #[test]
+    fn single_block_has_no_block_time() {
+        let mut window = BlockWindow::new(10);
+        assert!(window.metrics().is_none());
+
+        window.push(block(1, 100, 1, 1));
+        assert_eq!(
+            window
+                .metrics()
+                .expect("window has blocks")
+                .avg_block_time_s,
+            None
+        );
+    }
+}
@@ -0,0 +1,567 @@
+//! Configurable health probes for network services.
+
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Context};
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::HttpClient;
+use serde_json::Value;
+use tokio::net::TcpStream;
+use tokio::time::{sleep, timeout, Duration, Instant};
+use tracing::{error, info, warn};
+
+use super::bundler::{BundlerChecks, BundlerReport};
+use super::identity::ExpectedIdentity;
+use status_config::{ProbeConfig, ProbeKind, ProbePredicate};
+use status_utils::{create_rpc_client, ExponentialBackoff};
+
+/// Timeout for a single probe request.
+pub(crate) const PROBE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long a verified chain identity is trusted before it is checked again.
+const IDENTITY_RECHECK_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// A configured probe with its long-lived clients.
+pub(crate) struct Probe {
+    config: ProbeConfig,
+    rpc_client: Option<HttpClient>,
+    /// L2 RPC client a bundler's chain id is compared against.
+    chain_rpc_client: Option<HttpClient>,
+    /// Chain identity `rpc_client` must report, if the probe checks it.
+    identity: Option<ExpectedIdentity>,
+    /// When the identity was last checked, and the mismatch found if any.
+    identity_checked: Mutex<Option<(Instant, Option<String>)>>,
+}
+
+/// Response to a single probe request.
+#[derive(Debug, Clone, PartialEq)]
+enum ProbeResponse {
+    /// JSON-RPC `result`.
+    Json(Value),
+    /// HTTP response body, which may or may not be JSON.
+    Body(String),
+    /// HTTP response with an unexpected status code.
+    UnexpectedStatus(u16),
+    /// Successful TCP connect.
+    Connected,
+    /// Result of the bundler checks.
+    Bundler(BundlerReport),
+}
+
+/// Result of running a probe once.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ProbeOutcome {
+    /// Whether the service responded and satisfied the probe's predicate.
+    ok: bool,
+    /// JSON response, if the service returned one.
+    json: Option<Value>,
+    /// Round trip of the request that got a response, if any.
+    latency: Option<Duration>,
+    /// Why the service is serving the wrong chain or entry point, if it is.
+    misconfiguration: Option<String>,
+}
+
+impl ProbeOutcome {
+    pub(crate) fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    pub(crate) fn json(&self) -> Option<&Value> {
+        self.json.as_ref()
+    }
+
+    pub(crate) fn latency(&self) -> Option<Duration> {
+        self.latency
+    }
+
+    pub(crate) fn misconfiguration(&self) -> Option<&str> {
+        self.misconfiguration.as_deref()
+    }
+
+    /// Reads a head value at `path`, as a JSON number or a decimal or
+    /// `0x`-prefixed hex string.
+    pub(crate) fn head(&self, path: &str) -> Option<u64> {
+        let value = lookup(self.json.as_ref()?, path)?;
+        value.as_u64().or_else(|| {
+            value
+                .as_str()
+                .and_then(|head| parse_hex_quantity(head).or_else(|| head.parse::<u64>().ok()))
+        })
+    }
+
+    fn from_response(predicate: Option<&ProbePredicate>, response: ProbeResponse) -> Self {
+        match response {
+            ProbeResponse::Json(json) => Self {
+                ok: evaluate(predicate, Some(&json), None),
+                json: Some(json),
+                latency: None,
+                misconfiguration: None,
+            },
+            ProbeResponse::Body(body) => {
+                let json = serde_json::from_str(&body).ok();
+                Self {
+                    ok: evaluate(predicate, json.as_ref(), Some(&body)),
+                    json,
+                    latency: None,
+                    misconfiguration: None,
+                }
+            }
+            ProbeResponse::UnexpectedStatus(_) => Self::failed(),
+            ProbeResponse::Connected => Self {
+                ok: true,
+                json: None,
+                latency: None,
+                misconfiguration: None,
+            },
+            ProbeResponse::Bundler(report) => Self {
+                ok: report.is_ok(),
+                json: None,
+                latency: None,
+                misconfiguration: report.misconfiguration(),
+            },
+        }
+    }
+
+    fn failed() -> Self {
+        Self {
+            ok: false,
+            json: None,
+            latency: None,
+            misconfiguration: None,
+        }
+    }
+}
+
+impl Probe {
+    /// Builds a probe from its config. `identity` is only checked if the
+    /// probe is configured to check chain identity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a JSON-RPC URL is invalid.
+    pub(crate) fn new(config: ProbeConfig, identity: Option<ExpectedIdentity>) -> Self {
+        let (rpc_client, chain_rpc_client) = match config.kind() {
+            ProbeKind::JsonRpc { url, .. } => (Some(create_rpc_client(url)), None),
+            ProbeKind::Bundler {
+                rpc_url,
+                chain_rpc_url,
+                ..
+            } => (
+                Some(create_rpc_client(rpc_url)),
+                Some(create_rpc_client(chain_rpc_url)),
+            ),
+            ProbeKind::Http { .. } | ProbeKind::Tcp { .. } => (None, None),
+        };
+        let identity = identity.filter(|_| config.check_chain_identity() && rpc_client.is_some());
+        Self {
+            config,
+            rpc_client,
+            chain_rpc_client,
+            identity,
+            identity_checked: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn config(&self) -> &ProbeConfig {
+        &self.config
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        self.config.name()
+    }
+
+    /// Runs the probe, retrying failed requests according to `retry_policy`.
+    ///
+    /// The outcome's latency is the round trip of the attempt that got a
+    /// response.
+    pub(crate) async fn run(
+        &self,
+        http_client: &reqwest::Client,
+        retry_policy: ExponentialBackoff,
+    ) -> ProbeOutcome {
+        let mut retry_count: u64 = 0;
+
+        loop {
+            let started = Instant::now();
+            match self.request(http_client).await {
+                Ok(response) => {
+                    let latency = started.elapsed();
+                    info!(service = self.name(), ?latency, ?response, "probe response");
+                    let mut outcome = ProbeOutcome::from_response(self.config.expect(), response);
+                    outcome.latency = Some(latency);
+                    if outcome.misconfiguration.is_none() {
+                        outcome.misconfiguration = self.check_identity().await;
+                    }
+                    return outcome;
+                }
+                Err(e) => {
+                    if retry_count < retry_policy.max_retries() {
+                        let delay_seconds = retry_policy.get_delay(retry_count);
+                        if delay_seconds > 0 {
+                            info!(
+                                delay_seconds,
+                                retry_count,
+                                service = self.name(),
+                                "retrying probe request"
+                            );
+                            sleep(Duration::from_secs(delay_seconds)).await;
+                        }
+                        retry_count += 1;
+                    } else {
+                        error!(
+                            error = %e,
+                            service = self.name(),
+                            "could not get network status"
+                        );
+                        return ProbeOutcome::failed();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Compares the endpoint's chain identity against the expected one. Only
+    /// JSON-RPC probes serve blocks, so bundlers are checked for the chain id
+    /// only.
+    ///
+    /// The result is reused for [`IDENTITY_RECHECK_INTERVAL`], so the identity
+    /// is checked on the first poll and then on a slow interval. A failed check
+    /// is logged, not reported as a mismatch and retried on the next poll.
+    async fn check_identity(&self) -> Option<String> {
+        let identity = self.identity.as_ref()?;
+        let client = self.rpc_client.as_ref()?;
+        if let Some(mismatch) = self.cached_identity_check(Instant::now()) {
+            return mismatch;
+        }
+
+        let check_genesis = matches!(self.config.kind(), ProbeKind::JsonRpc { .. });
+        match identity.check(client, check_genesis).await {
+            Ok(mismatch) => {
+                *self
+                    .identity_checked
+                    .lock()
+                    .expect("identity check lock poisoned") =
+                    Some((Instant::now(), mismatch.clone()));
+                mismatch
+            }
+            Err(e) => {
+                warn!(error = %e, service = self.name(), "could not check chain identity");
+                None
+            }
+        }
+    }
+
+    /// Result of the latest identity check, if it is recent enough to reuse.
+    fn cached_identity_check(&self, now: Instant) -> Option<Option<String>> {
+        self.identity_checked
+            .lock()
+            .expect("identity check lock poisoned")
+            .as_ref()
+            .filter(|(checked_at, _)| {
+                now.saturating_duration_since(*checked_at) < IDENTITY_RECHECK_INTERVAL
+            })
+            .map(|(_, mismatch)| mismatch.clone())
+    }
+
+    async fn request(&self, http_client: &reqwest::Client) -> anyhow::Result<ProbeResponse> {
+        match self.config.kind() {
+            ProbeKind::JsonRpc { method, params, .. } => {
+                let client = self
+                    .rpc_client
+                    .as_ref()
+                    .expect("json-rpc probe has an rpc client");
+                let result: Value = client.request(method, params.clone()).await?;
+                Ok(ProbeResponse::Json(result))
+            }
+            ProbeKind::Http {
+                url,
+                expected_status,
+            } => {
+                let response = http_client.get(url).send().await?;
+                let status = response.status();
+                let status_ok = match expected_status {
+                    Some(expected) => status.as_u16() == *expected,
+                    None => status.is_success(),
+                };
+                if !status_ok {
+                    return Ok(ProbeResponse::UnexpectedStatus(status.as_u16()));
+                }
+
+                Ok(ProbeResponse::Body(response.text().await?))
+            }
+            ProbeKind::Tcp { address } => {
+                timeout(PROBE_TIMEOUT, TcpStream::connect(address))
+                    .await
+                    .map_err(|_| anyhow!("tcp connect to {address} timed out"))?
+                    .with_context(|| format!("tcp connect to {address}"))?;
+                Ok(ProbeResponse::Connected)
+            }
+            ProbeKind::Bundler {
+                health_url,
+                entry_point,
+                ..
+            } => {
+                let checks = BundlerChecks {
+                    health_url,
+                    bundler: self
+                        .rpc_client
+                        .as_ref()
+                        .expect("bundler probe has an rpc client"),
+                    chain: self
+                        .chain_rpc_client
+                        .as_ref()
+                        .expect("bundler probe has a chain rpc client"),
+                    entry_point,
+                };
+                Ok(ProbeResponse::Bundler(checks.run(http_client).await?))
+            }
+        }
+    }
+}
+
+/// Resolves a dot-separated path of object keys and array indices.
+fn lookup<'a>(json: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(json, |value, segment| match value {
+            Value::Object(map) => map.get(segment),
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+pub(crate) fn parse_hex_quantity(value: &str) -> Option<u64> {
+    value
+        .strip_prefix("0x")
+        .and_then(|quantity| u64::from_str_radix(quantity, 16).ok())
+}
+
+/// Checks a probe response against `predicate`. Without a predicate, any
+/// response passes.
+fn evaluate(predicate: Option<&ProbePredicate>, json: Option<&Value>, text: Option<&str>) -> bool {
+    let at = |path: &str| json.and_then(|json| lookup(json, path));
+
+    match predicate {
+        None => true,
+        Some(ProbePredicate::Exists { path }) => at(path).is_some_and(|value| !value.is_null()),
+        Some(ProbePredicate::Equals { path, value }) => at(path) == Some(value),
+        Some(ProbePredicate::Contains { path, value }) => match (path, text) {
+            (None, Some(text)) => text.contains(value.as_str()),
+            (path, _) => at(path.as_deref().unwrap_or_default())
+                .and_then(Value::as_str)
+                .is_some_and(|text| text.contains(value.as_str())),
+        },
+        Some(ProbePredicate::HexQuantity { path }) => at(path)
+            .and_then(Value::as_str)
+            .and_then(parse_hex_quantity)
+            .is_some(),
+        Some(ProbePredicate::Healthy) => is_healthy(json, text),
+    }
+}
+
+/// Checks a health report: a JSON `"ok"` string, a JSON object whose `status`
+/// says healthy, or a plain body that does.
+pub(crate) fn is_healthy(json: Option<&Value>, text: Option<&str>) -> bool {
+    let says_healthy = |status: &str| {
+        ["ok", "healthy", "up"]
+            .iter()
+            .any(|healthy| status.trim().eq_ignore_ascii_case(healthy))
+    };
+
+    match json {
+        Some(Value::String(status)) => says_healthy(status),
+        Some(Value::Object(report)) => report
+            .get("status")
+            .and_then(Value::as_str)
+            .is_some_and(says_healthy),
+        Some(_) => false,
+        None => text.is_some_and(says_healthy),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn exists(path: &str) -> ProbePredicate {
+        ProbePredicate::Exists {
+            path: path.to_owned(),
+        }
+    }
+
+    fn hex_quantity() -> ProbePredicate {
+        ProbePredicate::HexQuantity {
+            path: String::new(),
+        }
+    }
+
+    #[test]
+    fn identity_checks_are_reused_until_the_recheck_interval() {
+        let config: status_config::NetworkMonitoringConfig = toml::from_str(
+            r#"
+            sequencer_url = "http://localhost:8545"
+            rpc_url = "http://localhost:8546"
+            bundler_url = "http://localhost:3000/health"
+            retry_policy_max_retries = 1
+            retry_policy_total_time_s = 1
+            status_refetch_interval_s = 1
+
+            [[probes]]
+            name = "rpc"
+            kind = "tcp"
+            address = "127.0.0.1:8546"
+            "#,
+        )
+        .expect("test config should deserialize");
+        let probe = Probe::new(config.probes().remove(0), None);
+        let checked_at = Instant::now();
+        assert_eq!(probe.cached_identity_check(checked_at), None);
+
+        *probe.identity_checked.lock().expect("identity check lock") =
+            Some((checked_at, Some("chain id 1, expected 2".to_owned())));
+        assert_eq!(
+            probe.cached_identity_check(checked_at + IDENTITY_RECHECK_INTERVAL / 2),
+            Some(Some("chain id 1, expected 2".to_owned()))
+        );
+        assert_eq!(
+            probe.cached_identity_check(checked_at + IDENTITY_RECHECK_INTERVAL),
+            None
+        );
+    }
+
+    #[test]
+    fn chain_status_response_with_tip_is_online() {
+        let outcome = ProbeOutcome::from_response(
+            Some(&exists("tip.slot")),
+            ProbeResponse::Json(json!({
+                "tip": {
+                    "blkid": "0x00",
+                    "slot": 1,
+                    "epoch": 0,
+                    "is_terminal": false
+                },
+                "confirmed": {},
+                "finalized": {},
+                "latest": {}
+            })),
+        );
+
+        assert!(outcome.is_ok());
+        assert_eq!(outcome.head("tip.slot"), Some(1));
+    }
+
+    #[test]
+    fn chain_status_response_without_tip_is_offline() {
+        assert!(!ProbeOutcome::from_response(
+            Some(&exists("tip.slot")),
+            ProbeResponse::Json(json!({
+                "confirmed": {},
+                "finalized": {},
+                "latest": {}
+            })),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn eth_block_number_response_with_hex_string_is_online() {
+        let outcome =
+            ProbeOutcome::from_response(Some(&hex_quantity()), ProbeResponse::Json(json!("0x1a")));
+
+        assert!(outcome.is_ok());
+        assert_eq!(outcome.head(""), Some(0x1a));
+    }
+
+    #[test]
+    fn eth_block_number_response_with_non_hex_string_is_offline() {
+        assert!(!ProbeOutcome::from_response(
+            Some(&hex_quantity()),
+            ProbeResponse::Json(json!("latest"))
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn eth_block_number_response_with_object_is_offline() {
+        assert!(!ProbeOutcome::from_response(
+            Some(&hex_quantity()),
+            ProbeResponse::Json(json!({
+                "blockNumber": "0x1a"
+            }))
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn contains_without_path_matches_raw_http_body() {
+        let predicate = ProbePredicate::Contains {
+            path: None,
+            value: "ok".to_owned(),
+        };
+
+        assert!(ProbeOutcome::from_response(
+            Some(&predicate),
+            ProbeResponse::Body("ok".to_owned())
+        )
+        .is_ok());
+        assert!(!ProbeOutcome::from_response(
+            Some(&predicate),
+            ProbeResponse::Body("unavailable".to_owned())
+        )
+        .is_ok());
+        assert!(ProbeOutcome::from_response(
+            Some(&predicate),
+            ProbeResponse::Body(r#"{"status":"ok"}"#.to_owned())
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn healthy_requires_an_ok_health_report() {
+        let healthy = |body: &str| {
+            ProbeOutcome::from_response(
+                Some(&ProbePredicate::Healthy),
+                ProbeResponse::Body(body.to_owned()),
+            )
+            .is_ok()
+        };
+
+        assert!(healthy("ok"));
+        assert!(healthy("OK\n"));
+        assert!(healthy(r#""ok""#));
+        assert!(healthy(r#"{"status":"healthy","uptime":12}"#));
+        assert!(!healthy("not ok"));
+        assert!(!healthy(r#"{"status":"not ok"}"#));
+        assert!(!healthy(r#"{"message":"ok"}"#));
+    }
+
+    #[test]
+    fn equals_compares_value_at_path() {
+        let predicate = ProbePredicate::Equals {
+            path: "checks.1.status".to_owned(),
+            value: json!("up"),
+        };
+        let response = |status: &str| {
+            ProbeResponse::Body(
+                json!({
+                    "checks": [
+                        { "status": "up" },
+                        { "status": status }
+                    ]
+                })
+                .to_string(),
+            )
+        };
+
+        assert!(ProbeOutcome::from_response(Some(&predicate), response("up")).is_ok());
+        assert!(!ProbeOutcome::from_response(Some(&predicate), response("down")).is_ok());
+    }
+
+    #[test]
+    fn unexpected_http_status_is_offline_without_predicate() {
+        assert!(!ProbeOutcome::from_response(None, ProbeResponse::UnexpectedStatus(503)).is_ok());
+        assert!(ProbeOutcome::from_response(None, ProbeResponse::Connected).is_ok());
+    }
+}
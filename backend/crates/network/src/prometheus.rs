@@ -0,0 +1,236 @@
+//! Prometheus metrics for network monitoring.
+
+use status_utils::{MetricKind, PrometheusEncoder};
+
+use super::{
+    checkpoint::CheckpointStatus,
+    l1::L1Status,
+    types::{NetworkMonitoringContext, NetworkStatus, Status},
+};
+
+/// Whether a service in `status` is responding and serving the configured
+/// chain.
+fn is_up(status: Status) -> bool {
+    matches!(status, Status::Online | Status::Degraded)
+}
+
+fn encode_services(status: &NetworkStatus, encoder: &mut PrometheusEncoder) {
+    encoder.family(
+        "status_service_up",
+        MetricKind::Gauge,
+        "Whether the service responded to its latest probe with the configured chain.",
+    );
+    for (service, service_status) in status.services() {
+        encoder.sample(
+            "status_service_up",
+            &[("service", service)],
+            f64::from(u8::from(is_up(service_status.status()))),
+        );
+    }
+
+    encoder.family(
+        "status_service_status",
+        MetricKind::Gauge,
+        "Status of the service, 1 for the current status and 0 otherwise.",
+    );
+    for (service, service_status) in status.services() {
+        for candidate in Status::ALL {
+            encoder.sample(
+                "status_service_status",
+                &[("service", service), ("status", candidate.as_str())],
+                f64::from(u8::from(service_status.status() == candidate)),
+            );
+        }
+    }
+
+    encoder.family(
+        "status_probe_latency_seconds",
+        MetricKind::Gauge,
+        "Probe round-trip time percentiles over recent probes that got a response.",
+    );
+    for (service, service_status) in status.services() {
+        let Some(latency) = service_status.latency() else {
+            continue;
+        };
+        for (quantile, ms) in latency.percentiles_ms() {
+            encoder.sample(
+                "status_probe_latency_seconds",
+                &[("service", service), ("quantile", quantile)],
+                ms as f64 / 1000.0,
+            );
+        }
+    }
+
+    encoder.family(
+        "status_probe_last_latency_seconds",
+        MetricKind::Gauge,
+        "Round-trip time of the latest probe, if it got a response.",
+    );
+    for (service, service_status) in status.services() {
+        if let Some(ms) = service_status
+            .latency()
+            .and_then(|latency| latency.latest_ms())
+        {
+            encoder.sample(
+                "status_probe_last_latency_seconds",
+                &[("service", service)],
+                ms as f64 / 1000.0,
+            );
+        }
+    }
+}
+
+fn encode_l1(l1: &L1Status, encoder: &mut PrometheusEncoder) {
+    let gauges = [
+        (
+            "status_l1_tip_height",
+            "Height of the Bitcoin tip seen by Esplora.",
+            l1.tip_height(),
+        ),
+        (
+            "status_l1_tip_age_seconds",
+            "Seconds since the header time of the Bitcoin tip.",
+            l1.tip_age_s(),
+        ),
+        (
+            "status_l1_mempool_transactions",
+            "Number of transactions in the Bitcoin mempool.",
+            l1.mempool_tx_count(),
+        ),
+        (
+            "status_l1_mempool_vsize_vbytes",
+            "Total virtual size of the Bitcoin mempool.",
+            l1.mempool_vsize(),
+        ),
+    ];
+    for (name, help, value) in gauges {
+        encoder.family(name, MetricKind::Gauge, help);
+        encoder.sample(name, &[], value as f64);
+    }
+
+    if let Some(reader) = l1.reader() {
+        encoder.family(
+            "status_l1_reader_height",
+            MetricKind::Gauge,
+            "Height of the latest L1 block the sequencer has ingested.",
+        );
+        encoder.sample(
+            "status_l1_reader_height",
+            &[],
+            reader.sequencer_l1_height() as f64,
+        );
+        encoder.family(
+            "status_l1_reader_lag_blocks",
+            MetricKind::Gauge,
+            "Number of blocks the sequencer's L1 reader is behind the Esplora tip.",
+        );
+        encoder.sample(
+            "status_l1_reader_lag_blocks",
+            &[],
+            reader.lag_blocks() as f64,
+        );
+    }
+}
+
+fn encode_checkpoint(checkpoint: &CheckpointStatus, encoder: &mut PrometheusEncoder) {
+    encoder.family(
+        "status_checkpoint_status",
+        MetricKind::Gauge,
+        "Status of OL checkpoints, 1 for the current status and 0 otherwise.",
+    );
+    for candidate in Status::ALL {
+        encoder.sample(
+            "status_checkpoint_status",
+            &[("status", candidate.as_str())],
+            f64::from(u8::from(checkpoint.status() == candidate)),
+        );
+    }
+
+    encoder.family(
+        "status_checkpoint_latest_epoch",
+        MetricKind::Gauge,
+        "Epoch of the latest checkpoint the sequencer has produced.",
+    );
+    encoder.sample(
+        "status_checkpoint_latest_epoch",
+        &[],
+        checkpoint.latest_epoch() as f64,
+    );
+
+    let Some(landed) = checkpoint.landed() else {
+        return;
+    };
+    encoder.family(
+        "status_checkpoint_landed_epoch",
+        MetricKind::Gauge,
+        "Epoch of the latest checkpoint posted to L1.",
+    );
+    encoder.sample("status_checkpoint_landed_epoch", &[], landed.epoch() as f64);
+    if let Some(confirmations) = landed.confirmations() {
+        encoder.family(
+            "status_checkpoint_landed_confirmations",
+            MetricKind::Gauge,
+            "Confirmations of the latest checkpoint's commitment transaction.",
+        );
+        encoder.sample(
+            "status_checkpoint_landed_confirmations",
+            &[],
+            confirmations as f64,
+        );
+    }
+    if let Some(since_landed_s) = landed.since_landed_s() {
+        encoder.family(
+            "status_checkpoint_landed_age_seconds",
+            MetricKind::Gauge,
+            "Seconds since the latest checkpoint landed on L1.",
+        );
+        encoder.sample(
+            "status_checkpoint_landed_age_seconds",
+            &[],
+            since_landed_s as f64,
+        );
+    }
+}
+
+/// Writes service, L1 and checkpoint metrics to `encoder`.
+pub async fn encode_network_metrics(
+    context: &NetworkMonitoringContext,
+    encoder: &mut PrometheusEncoder,
+) {
+    let status = context.status().await;
+    encode_services(&status, encoder);
+    if let Some(l1) = status.l1() {
+        encode_l1(l1, encoder);
+    }
+    if let Some(checkpoint) = status.checkpoint() {
+        encode_checkpoint(checkpoint, encoder);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ServiceStatus;
+
+    #[test]
+    fn misconfigured_service_is_down() {
+        let mut status = NetworkStatus::default();
+        status.set_service("rpc", ServiceStatus::new(Status::Online, None));
+        status.set_service(
+            "bundler",
+            ServiceStatus::misconfigured("chain id 1, expected 8150".to_owned(), None),
+        );
+
+        let mut encoder = PrometheusEncoder::new();
+        encode_services(&status, &mut encoder);
+        let metrics = encoder.finish();
+
+        assert!(metrics.contains("status_service_up{service=\"rpc\"} 1\n"));
+        assert!(metrics.contains("status_service_up{service=\"bundler\"} 0\n"));
+        assert!(metrics
+            .contains("status_service_status{service=\"bundler\",status=\"misconfigured\"} 1\n"));
+        assert!(
+            metrics.contains("status_service_status{service=\"bundler\",status=\"online\"} 0\n")
+        );
+    }
+}
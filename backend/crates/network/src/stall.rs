@@ -0,0 +1,145 @@
+//! Detection of chain heads that answer RPC but stop advancing.
+
+use tokio::time::{Duration, Instant};
+
+use status_config::NetworkMonitoringConfig;
+
+/// Thresholds after which an unchanged head counts as stalled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StallPolicy {
+    max_unchanged_polls: u64,
+    max_unchanged_duration: Duration,
+}
+
+impl StallPolicy {
+    pub(crate) fn from_config(config: &NetworkMonitoringConfig) -> Self {
+        Self {
+            max_unchanged_polls: config.stall_threshold_polls(),
+            max_unchanged_duration: Duration::from_secs(config.stall_threshold_s()),
+        }
+    }
+
+    /// Returns `true` if either configured threshold was reached. A zero
+    /// threshold disables that check.
+    pub(crate) fn is_stalled(&self, progress: &HeadProgress) -> bool {
+        (self.max_unchanged_polls > 0 && progress.unchanged_polls >= self.max_unchanged_polls)
+            || self.is_stalled_for_duration(progress)
+    }
+
+    /// Returns `true` if the wall-clock threshold was reached.
+    pub(crate) fn is_stalled_for_duration(&self, progress: &HeadProgress) -> bool {
+        !self.max_unchanged_duration.is_zero()
+            && progress.unchanged_for >= self.max_unchanged_duration
+    }
+}
+
+/// Progress of a head since it last changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HeadProgress {
+    pub(crate) head: u64,
+    /// Polls since the head last changed, excluding the poll that changed it.
+    pub(crate) unchanged_polls: u64,
+    pub(crate) unchanged_for: Duration,
+}
+
+/// Tracks the last observed value of a monotonically advancing head.
+#[derive(Debug, Default)]
+pub(crate) struct HeadTracker {
+    last_change: Option<(u64, Instant)>,
+    unchanged_polls: u64,
+}
+
+impl HeadTracker {
+    /// Records a newly observed head value.
+    ///
+    /// Any change, including a decrease after a node resync, counts as movement.
+    pub(crate) fn observe(&mut self, head: u64, now: Instant) -> HeadProgress {
+        match self.last_change {
+            Some((last_head, changed_at)) if last_head == head => {
+                self.unchanged_polls += 1;
+                HeadProgress {
+                    head,
+                    unchanged_polls: self.unchanged_polls,
+                    unchanged_for: now.saturating_duration_since(changed_at),
+                }
+            }
+            _ => {
+                self.last_change = Some((head, now));
+                self.unchanged_polls = 0;
+                HeadProgress {
+                    head,
+                    unchanged_polls: 0,
+                    unchanged_for: Duration::ZERO,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_unchanged_polls: u64, max_unchanged_s: u64) -> StallPolicy {
+        StallPolicy {
+            max_unchanged_polls,
+            max_unchanged_duration: Duration::from_secs(max_unchanged_s),
+        }
+    }
+
+    #[test]
+    fn advancing_head_resets_progress() {
+        let start = Instant::now();
+        let mut tracker = HeadTracker::default();
+
+        tracker.observe(10, start);
+        let progress = tracker.observe(10, start + Duration::from_secs(5));
+        assert_eq!(progress.unchanged_polls, 1);
+        assert_eq!(progress.unchanged_for, Duration::from_secs(5));
+
+        let progress = tracker.observe(11, start + Duration::from_secs(10));
+        assert_eq!(progress.unchanged_polls, 0);
+        assert_eq!(progress.unchanged_for, Duration::ZERO);
+    }
+
+    #[test]
+    fn unchanged_head_stalls_after_poll_threshold() {
+        let start = Instant::now();
+        let mut tracker = HeadTracker::default();
+        let policy = policy(3, 0);
+
+        tracker.observe(7, start);
+        for _ in 0..2 {
+            assert!(!policy.is_stalled(&tracker.observe(7, start)));
+        }
+
+        let progress = tracker.observe(7, start);
+        assert!(policy.is_stalled(&progress));
+        assert!(!policy.is_stalled_for_duration(&progress));
+    }
+
+    #[test]
+    fn unchanged_head_stalls_after_wall_clock_threshold() {
+        let start = Instant::now();
+        let mut tracker = HeadTracker::default();
+        let policy = policy(0, 60);
+
+        tracker.observe(7, start);
+        assert!(!policy.is_stalled(&tracker.observe(7, start + Duration::from_secs(59))));
+        let progress = tracker.observe(7, start + Duration::from_secs(60));
+        assert!(policy.is_stalled(&progress));
+        assert!(policy.is_stalled_for_duration(&progress));
+    }
+
+    #[test]
+    fn zero_thresholds_disable_stall_detection() {
+        let start = Instant::now();
+        let mut tracker = HeadTracker::default();
+        let policy = policy(0, 0);
+
+        tracker.observe(7, start);
+        for _ in 0..100 {
+            assert!(!policy.is_stalled(&tracker.observe(7, start + Duration::from_secs(3600))));
+        }
+    }
+}
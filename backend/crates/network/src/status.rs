@@ -1,210 +1,349 @@
-use anyhow::Result;
-use axum::http::StatusCode;
+use anyhow::{Context, Result};
 use axum::Json;
-use jsonrpsee::core::client::ClientT;
-use jsonrpsee::http_client::HttpClient;
+use status_alerts::evaluate_rules;
+use status_feed::ComponentState;
+use status_utils::{unix_time_ms, unix_time_s, Component, FreshStatus};
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use strata_tasks::ShutdownGuard;
-use tokio::time::{interval, sleep, timeout, Duration};
-use tracing::{error, info};
+use tokio::task::JoinSet;
+use tokio::time::{interval, timeout, Duration, Instant, MissedTickBehavior};
+use tracing::{info, warn};
 
-use super::types::{NetworkMonitoringContext, NetworkStatus, Status};
-use status_config::NetworkMonitoringConfig;
-use status_utils::{create_rpc_client, ExponentialBackoff};
+use super::{
+    alerts::{network_alerts, rule_samples, ALERT_SOURCE, RULE_ALERT_SOURCE},
+    chain_status::{parse_chain_status, ChainProgress},
+    checkpoint::run_checkpoint_monitor,
+    db::{traits::NetworkHistoryDb, types::DbLatestStatus},
+    identity::ExpectedIdentity,
+    l1::run_l1_monitor,
+    latency::LatencyWindow,
+    metrics::run_metrics_sampler,
+    probe::{Probe, ProbeOutcome, PROBE_TIMEOUT},
+    stall::{HeadTracker, StallPolicy},
+    types::{Degradation, NetworkMonitoringContext, NetworkStatus, ServiceStatus, Status},
+    uptime::record_status,
+};
 
-const STRATA_CHAIN_STATUS_METHOD: &str = "strata_getChainStatus";
-const ETH_BLOCK_NUMBER_METHOD: &str = "eth_blockNumber";
-
-fn is_chain_status_response_online(json: &serde_json::Value) -> bool {
-    json.get("tip").is_some()
+/// Per-probe state carried across polls.
+struct ProbeState {
+    head: HeadTracker,
+    latency: LatencyWindow,
 }
 
-fn is_eth_block_number_response_online(json: &serde_json::Value) -> bool {
-    json.as_str()
-        .and_then(|block_number| block_number.strip_prefix("0x"))
-        .and_then(|block_number| u64::from_str_radix(block_number, 16).ok())
-        .is_some()
-}
+/// Derives a service's status from a probe outcome.
+///
+/// A service serving the wrong chain is [`Status::Misconfigured`] regardless
+/// of the probe's predicate.
+///
+/// An online service is downgraded to [`Status::Degraded`] if its head is
+/// stalled according to `policy`, or if the probe took longer than the
+/// probe's latency threshold.
+fn service_status(
+    probe: &Probe,
+    outcome: &ProbeOutcome,
+    state: &mut ProbeState,
+    policy: &StallPolicy,
+    now: Instant,
+) -> ServiceStatus {
+    let latency = state.latency.record(outcome.latency());
+    if let Some(reason) = outcome.misconfiguration() {
+        return ServiceStatus::misconfigured(reason.to_owned(), latency);
+    }
+    if !outcome.is_ok() {
+        return ServiceStatus::new(Status::Offline, latency);
+    }
 
-async fn call_json_rpc_status(
-    client: &HttpClient,
-    method: &'static str,
-    is_online: impl Fn(&serde_json::Value) -> bool,
-    retry_policy: ExponentialBackoff,
-) -> Status {
-    let mut retry_count: u64 = 0;
+    let stalled = probe
+        .config()
+        .head_path()
+        .and_then(|path| outcome.head(path))
+        .map(|head| state.head.observe(head, now))
+        .filter(|progress| policy.is_stalled(progress))
+        .map(|progress| {
+            Degradation::stalled_head(&progress, policy.is_stalled_for_duration(&progress))
+        });
+    let slow = || {
+        let threshold = Duration::from_millis(probe.config().degraded_latency_ms()?);
+        let latency = outcome.latency()?;
+        (latency > threshold).then(|| Degradation::slow_response(latency, threshold))
+    };
 
-    loop {
-        let response: Result<serde_json::Value, _> = client.request(method, Vec::<()>::new()).await;
-        match response {
-            Ok(json) => {
-                info!(?json, method, "rpc response");
-                if is_online(&json) {
-                    return Status::Online;
-                } else {
-                    return Status::Offline;
-                }
-            }
-            Err(e) => {
-                if retry_count < retry_policy.max_retries() {
-                    let delay_seconds = retry_policy.get_delay(retry_count);
-                    if delay_seconds > 0 {
-                        info!(
-                            delay_seconds,
-                            retry_count, method, "retrying rpc status request"
-                        );
-                        sleep(Duration::from_secs(delay_seconds)).await;
-                    }
-                    retry_count += 1;
-                } else {
-                    error!(
-                        error = %e,
-                        method,
-                        "could not get network status"
-                    );
-                    return Status::Offline;
-                }
-            }
-        }
+    match stalled.or_else(slow) {
+        Some(degradation) => ServiceStatus::degraded(degradation, latency),
+        None => ServiceStatus::new(Status::Online, latency),
     }
 }
 
-/// Calls the OL `strata_getChainStatus` method.
-async fn call_sequencer_status(client: &HttpClient, retry_policy: ExponentialBackoff) -> Status {
-    call_json_rpc_status(
-        client,
-        STRATA_CHAIN_STATUS_METHOD,
-        is_chain_status_response_online,
-        retry_policy,
-    )
-    .await
-}
+/// Runs one probe on its own schedule and publishes each result.
+///
+/// Never returns; the monitoring task aborts it on shutdown.
+async fn run_probe(
+    context: Arc<NetworkMonitoringContext>,
+    probe: Probe,
+    http_client: reqwest::Client,
+    service_count: usize,
+) {
+    let config = context.config();
+    let mut interval = interval(Duration::from_secs(
+        config.probe_interval_s(probe.config()).max(1),
+    ));
+    // A probe retrying past its interval resumes on schedule instead of
+    // catching up with a burst of runs.
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let retry_policy = config.probe_retry_policy(probe.config());
+    let stall_policy = StallPolicy::from_config(config);
+    let mut state = ProbeState {
+        head: HeadTracker::default(),
+        latency: LatencyWindow::new(config.latency_window_polls()),
+    };
 
-/// Calls the EVM `eth_blockNumber` method.
-async fn call_rpc_endpoint_status(client: &HttpClient, retry_policy: ExponentialBackoff) -> Status {
-    call_json_rpc_status(
-        client,
-        ETH_BLOCK_NUMBER_METHOD,
-        is_eth_block_number_response_online,
-        retry_policy,
-    )
-    .await
-}
+    loop {
+        interval.tick().await;
 
-/// Checks bundler health (`/health`)
-async fn check_bundler_health(
-    client: &reqwest::Client,
-    config: &NetworkMonitoringConfig,
-) -> Status {
-    let url = config.bundler_url();
-    if let Ok(resp) = client.get(url).send().await {
-        let body = resp.text().await.unwrap_or_default();
-        if body.contains("ok") {
-            return Status::Online;
-        }
+        let outcome = probe.run(&http_client, retry_policy).await;
+        let chain = probe.config().is_chain_status().then(|| {
+            outcome
+                .json()
+                .filter(|_| outcome.is_ok())
+                .and_then(parse_chain_status)
+                .as_ref()
+                .map(ChainProgress::from)
+        });
+        let status = service_status(&probe, &outcome, &mut state, &stall_policy, Instant::now());
+        let responded = status.status() != Status::Offline;
+
+        info!(service = probe.name(), ?status, "updated service status");
+
+        context
+            .update_status(|network_status| {
+                if let Some(chain) = chain {
+                    network_status.set_chain(chain);
+                }
+                network_status.set_service(probe.name(), status);
+            })
+            .await;
+        context
+            .record_probe_result(probe.name(), responded, unix_time_s(), service_count)
+            .await;
     }
-    Status::Offline
 }
 
-/// Periodically polls configured network service endpoints and updates status state.
+/// Runs the configured probes, the L1 and checkpoint monitors and the L2
+/// metrics sampler concurrently, and periodically records the combined
+/// status in the history database and evaluates the network alerts.
 pub async fn network_monitoring_task(
     context: Arc<NetworkMonitoringContext>,
     shutdown: ShutdownGuard,
 ) -> Result<()> {
     info!("fetching network statuses");
-    let mut interval = interval(Duration::from_secs(
-        context.config().status_refetch_interval(),
-    ));
-    let sequencer_client = create_rpc_client(context.config().sequencer_url());
-    let rpc_client = create_rpc_client(context.config().rpc_url());
-    let http_client = reqwest::Client::new();
+    let probes = context.probes().to_vec();
+    let identity = ExpectedIdentity::from_config(context.config());
+    let service_count = probes
+        .iter()
+        .map(|probe| probe.name())
+        .collect::<BTreeSet<_>>()
+        .len();
+    let http_client = reqwest::Client::builder().timeout(PROBE_TIMEOUT).build()?;
+
+    let mut probe_tasks = JoinSet::new();
+    for probe in probes {
+        probe_tasks.spawn(run_probe(
+            Arc::clone(&context),
+            Probe::new(probe, identity.clone()),
+            http_client.clone(),
+            service_count,
+        ));
+    }
+    probe_tasks.spawn(run_l1_monitor(Arc::clone(&context)));
+    probe_tasks.spawn(run_checkpoint_monitor(Arc::clone(&context)));
+    probe_tasks.spawn(run_metrics_sampler(Arc::clone(&context)));
 
+    let mut history_interval = interval(Duration::from_secs(
+        context.config().status_refetch_interval().max(1),
+    ));
     loop {
         tokio::select! {
             _ = shutdown.wait_for_shutdown() => break,
-            _ = interval.tick() => {}
-        }
-
-        let sequencer =
-            call_sequencer_status(&sequencer_client, context.config().sequencer_retry_policy())
-                .await;
-        let rpc_endpoint =
-            call_rpc_endpoint_status(&rpc_client, context.config().rpc_retry_policy()).await;
-        let bundler_endpoint = check_bundler_health(&http_client, context.config()).await;
-
-        let new_status = NetworkStatus::new(sequencer, rpc_endpoint, bundler_endpoint);
-
-        info!(?new_status, "updated network status");
+            Some(result) = probe_tasks.join_next() => {
+                result.context("network probe task failed")?;
+            }
+            _ = history_interval.tick() => {
+                if !context.is_status_available() {
+                    continue;
+                }
 
-        context.set_status(new_status).await;
-        context.mark_status_available();
+                let status = context.status().await;
+                let now_ms = unix_time_ms();
+                if let Err(e) = record_status(context.history_db(), now_ms, &status) {
+                    warn!(error = %e, "failed to persist network status history");
+                }
+                if let Some(polled_at) = context.last_successful_poll_at() {
+                    let latest = DbLatestStatus {
+                        polled_at,
+                        status: status.clone(),
+                    };
+                    if let Err(e) = context.history_db().put_latest_status(&latest) {
+                        warn!(error = %e, "failed to persist latest network status");
+                    }
+                }
+                record_transitions(&context, &status, now_ms / 1_000);
+                context
+                    .alerts()
+                    .reconcile(ALERT_SOURCE, network_alerts(&status))
+                    .await;
+                let rule_alerts = evaluate_rules(context.alerts().config().rules(), |metric| {
+                    rule_samples(&status, metric)
+                });
+                context
+                    .alerts()
+                    .reconcile(RULE_ALERT_SOURCE, rule_alerts)
+                    .await;
+            }
+        }
     }
 
+    probe_tasks.shutdown().await;
     Ok(())
 }
 
+/// Records the services that went offline or came back online since the last
+/// tick, for the status feeds.
+fn record_transitions(context: &NetworkMonitoringContext, status: &NetworkStatus, now_s: u64) {
+    for (service, service_status) in status.services() {
+        let state = match service_status.status() {
+            Status::Offline => ComponentState::Offline,
+            _ => ComponentState::Online,
+        };
+        context
+            .events()
+            .observe(Component::Service(service.clone()), service, state, now_s);
+    }
+}
+
 /// Handler to get the current network status
+///
+/// The status hydrated from the database is served right away. Only before
+/// any probe and Esplora poll have ever succeeded does the handler wait for
+/// the first complete status, and it serves the status marked stale if none
+/// arrives in time.
 pub async fn get_network_status(
     context: Arc<NetworkMonitoringContext>,
-) -> std::result::Result<Json<NetworkStatus>, StatusCode> {
-    let initial_status_wait_timeout = context.initial_status_wait_timeout();
-    if timeout(
-        initial_status_wait_timeout,
-        context.wait_until_initial_status(),
-    )
-    .await
-    .is_err()
-    {
-        return Err(StatusCode::SERVICE_UNAVAILABLE);
+) -> Json<FreshStatus<NetworkStatus>> {
+    if context.last_successful_poll_at().is_none() {
+        let _ = timeout(
+            context.initial_status_wait_timeout(),
+            context.wait_until_initial_status(),
+        )
+        .await;
     }
 
-    Ok(Json(context.status().await))
+    let mut status = context.status().await;
+    status.set_maintenance(context.maintenance().active_for_services());
+    Json(FreshStatus::new(
+        status,
+        context.last_successful_poll_at(),
+        context.config().status_refetch_interval(),
+    ))
 }
 
 #[cfg(test)]
 mod tests {
-    use serde_json::json;
-
-    use super::{is_chain_status_response_online, is_eth_block_number_response_online};
-
-    #[test]
-    fn chain_status_response_with_tip_is_online() {
-        assert!(is_chain_status_response_online(&json!({
-            "tip": {
-                "blkid": "0x00",
-                "slot": 1,
-                "epoch": 0,
-                "is_terminal": false
-            },
-            "confirmed": {},
-            "finalized": {},
-            "latest": {}
-        })));
-    }
+    use tokio::net::TcpListener;
 
-    #[test]
-    fn chain_status_response_without_tip_is_offline() {
-        assert!(!is_chain_status_response_online(&json!({
-            "confirmed": {},
-            "finalized": {},
-            "latest": {}
-        })));
-    }
+    use super::*;
+    use crate::db::NetworkHistoryDbSled;
+    use status_alerts::AlertEngine;
+    use status_config::{AlertsConfig, NetworkMonitoringConfig};
+    use status_feed::{StatusEventDbSled, StatusEventLog};
+    use status_maintenance::{MaintenanceDbSled, MaintenanceSchedule};
+    use status_utils::EsploraClient;
 
-    #[test]
-    fn eth_block_number_response_with_hex_string_is_online() {
-        assert!(is_eth_block_number_response_online(&json!("0x1a")));
-    }
+    #[tokio::test]
+    async fn retrying_probe_does_not_delay_other_services() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind listener");
+        let live_address = listener.local_addr().expect("listener address");
+        let dead_address = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind listener")
+            .local_addr()
+            .expect("listener address");
 
-    #[test]
-    fn eth_block_number_response_with_non_hex_string_is_offline() {
-        assert!(!is_eth_block_number_response_online(&json!("latest")));
-    }
+        let config: NetworkMonitoringConfig = toml::from_str(&format!(
+            r#"
+            sequencer_url = "http://localhost:8545"
+            rpc_url = "http://localhost:8546"
+            bundler_url = "http://localhost:3000/health"
+            retry_policy_max_retries = 1
+            retry_policy_total_time_s = 1
+            status_refetch_interval_s = 1
+
+            [[probes]]
+            name = "live"
+            kind = "tcp"
+            address = "{live_address}"
+
+            [[probes]]
+            name = "dead"
+            kind = "tcp"
+            address = "{dead_address}"
+            retry_policy_max_retries = 3
+            retry_policy_total_time_s = 60
+            "#
+        ))
+        .expect("test config should deserialize");
+        let history_db = Arc::new(NetworkHistoryDbSled::open_temporary().expect("open history db"));
+        let probes = config.probes();
+        let esplora = EsploraClient::new("http://localhost:3002", 1);
+        let maintenance = Arc::new(
+            MaintenanceSchedule::load(
+                &[],
+                Vec::new(),
+                MaintenanceDbSled::open_temporary().expect("open maintenance db"),
+            )
+            .expect("load maintenance schedule"),
+        );
+        let alerts =
+            Arc::new(AlertEngine::new(AlertsConfig::default(), Arc::clone(&maintenance)).0);
+        let context = Arc::new(NetworkMonitoringContext::new(
+            config,
+            probes,
+            esplora,
+            history_db,
+            alerts,
+            maintenance,
+            Arc::new(
+                StatusEventLog::load(
+                    StatusEventDbSled::open_temporary().expect("open status event db"),
+                )
+                .expect("load status events"),
+            ),
+        ));
+
+        let mut probe_tasks = JoinSet::new();
+        for probe in context.probes().to_vec() {
+            probe_tasks.spawn(run_probe(
+                Arc::clone(&context),
+                Probe::new(probe, None),
+                reqwest::Client::new(),
+                2,
+            ));
+        }
+
+        timeout(Duration::from_secs(5), async {
+            while context.status().await.services().is_empty() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("live service should report while the dead one retries");
 
-    #[test]
-    fn eth_block_number_response_with_object_is_offline() {
-        assert!(!is_eth_block_number_response_online(&json!({
-            "blockNumber": "0x1a"
-        })));
+        assert_eq!(
+            context.status().await.service_statuses(),
+            [("live".to_owned(), Status::Online)].into()
+        );
+        assert!(!context.is_status_available());
     }
 }
@@ -1,59 +1,304 @@
 use serde::{Deserialize, Serialize};
-use std::sync::atomic::{AtomicBool, Ordering};
-use tokio::sync::{Notify, RwLock};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{
+    atomic::{AtomicBool, AtomicU64, Ordering},
+    Arc,
+};
+use tokio::sync::{Mutex, Notify, RwLock};
 use tokio::time::Duration;
+use tracing::warn;
 
-use status_config::NetworkMonitoringConfig;
+use super::chain_status::ChainProgress;
+use super::checkpoint::CheckpointStatus;
+use super::db::{traits::NetworkHistoryDb, NetworkHistoryDbSled};
+use super::l1::L1Status;
+use super::latency::ServiceLatency;
+use super::metrics::ChainMetrics;
+use super::stall::HeadProgress;
+use status_alerts::AlertEngine;
+use status_config::{NetworkMonitoringConfig, ProbeConfig};
+use status_feed::StatusEventLog;
+use status_maintenance::{MaintenanceSchedule, MaintenanceWindow};
+use status_utils::{ComponentHealth, EsploraClient};
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum Status {
     Online,
+    /// Responding, but not making progress.
+    Degraded,
     Offline,
+    /// Responding, but serving a chain other than the configured one.
+    Misconfigured,
 }
 
-#[derive(Serialize, Clone, Debug)]
-pub struct NetworkStatus {
-    sequencer: Status,
-    rpc_endpoint: Status,
-    bundler_endpoint: Status,
+impl Status {
+    pub(crate) const ALL: [Self; 4] = [
+        Self::Online,
+        Self::Degraded,
+        Self::Offline,
+        Self::Misconfigured,
+    ];
+
+    /// Health of a service in the status.
+    pub(crate) fn health(self) -> ComponentHealth {
+        match self {
+            Self::Online => ComponentHealth::Operational,
+            Self::Degraded => ComponentHealth::Degraded,
+            Self::Misconfigured => ComponentHealth::PartialOutage,
+            Self::Offline => ComponentHealth::MajorOutage,
+        }
+    }
+
+    /// Returns the serialized name of the status.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Degraded => "degraded",
+            Self::Offline => "offline",
+            Self::Misconfigured => "misconfigured",
+        }
+    }
 }
 
-impl Default for NetworkStatus {
-    fn default() -> Self {
+/// Why a responsive service is reported as degraded.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Degradation {
+    reason: String,
+    /// How long the service's head has been stalled, for stall degradations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stalled_for_s: Option<u64>,
+}
+
+impl Degradation {
+    /// Describes the stall by elapsed time if `by_duration` is set, which is
+    /// when the wall-clock threshold triggered it, and by polls otherwise.
+    pub(crate) fn stalled_head(progress: &HeadProgress, by_duration: bool) -> Self {
+        let stalled_for_s = progress.unchanged_for.as_secs();
+        let reason = if by_duration {
+            format!(
+                "head {} has not advanced for {stalled_for_s}s",
+                progress.head
+            )
+        } else {
+            format!(
+                "head {} has not advanced for {} polls",
+                progress.head, progress.unchanged_polls
+            )
+        };
+        Self {
+            reason,
+            stalled_for_s: Some(stalled_for_s),
+        }
+    }
+
+    pub(crate) fn no_checkpoint() -> Self {
+        Self {
+            reason: "no recent checkpoint has been posted to L1".to_owned(),
+            stalled_for_s: None,
+        }
+    }
+
+    pub(crate) fn unmined_checkpoint() -> Self {
+        Self {
+            reason: "no recent checkpoint commitment has been mined on L1".to_owned(),
+            stalled_for_s: None,
+        }
+    }
+
+    pub(crate) fn stale_checkpoint(since_landed_s: u64) -> Self {
         Self {
-            sequencer: Status::Offline,
-            rpc_endpoint: Status::Offline,
-            bundler_endpoint: Status::Offline,
+            reason: format!("no checkpoint has landed on L1 for {since_landed_s}s"),
+            stalled_for_s: Some(since_landed_s),
+        }
+    }
+
+    pub(crate) fn slow_response(latency: Duration, threshold: Duration) -> Self {
+        Self {
+            reason: format!(
+                "response took {} ms, above the {} ms threshold",
+                latency.as_millis(),
+                threshold.as_millis()
+            ),
+            stalled_for_s: None,
         }
     }
 }
 
-impl NetworkStatus {
-    pub(crate) fn new(sequencer: Status, rpc_endpoint: Status, bundler_endpoint: Status) -> Self {
+/// Latest probe result for one service.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ServiceStatus {
+    status: Status,
+    /// Why the service is reported as [`Status::Degraded`], if it is.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    degradation: Option<Degradation>,
+    /// How the service's chain differs from the configured one, if it is
+    /// [`Status::Misconfigured`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    misconfiguration: Option<String>,
+    /// Probe round-trip times, once the service has responded.
+    latency: Option<ServiceLatency>,
+}
+
+impl ServiceStatus {
+    pub(crate) fn new(status: Status, latency: Option<ServiceLatency>) -> Self {
+        Self {
+            status,
+            degradation: None,
+            misconfiguration: None,
+            latency,
+        }
+    }
+
+    pub(crate) fn degraded(degradation: Degradation, latency: Option<ServiceLatency>) -> Self {
+        Self {
+            status: Status::Degraded,
+            degradation: Some(degradation),
+            misconfiguration: None,
+            latency,
+        }
+    }
+
+    pub(crate) fn misconfigured(reason: String, latency: Option<ServiceLatency>) -> Self {
         Self {
-            sequencer,
-            rpc_endpoint,
-            bundler_endpoint,
+            status: Status::Misconfigured,
+            degradation: None,
+            misconfiguration: Some(reason),
+            latency,
         }
     }
+
+    pub(crate) fn status(&self) -> Status {
+        self.status
+    }
+
+    pub(crate) fn latency(&self) -> Option<ServiceLatency> {
+        self.latency
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct NetworkStatus {
+    /// Probe results keyed by service name.
+    services: BTreeMap<String, ServiceStatus>,
+    /// OL chain progress from the latest chain-status probe response, if any.
+    chain: Option<ChainProgress>,
+    /// Bitcoin L1 progress, if the latest Esplora poll succeeded.
+    l1: Option<L1Status>,
+    /// OL checkpoint progress, if the latest checkpoint poll succeeded.
+    checkpoint: Option<CheckpointStatus>,
+    /// Active maintenance windows covering a network service.
+    maintenance: Vec<MaintenanceWindow>,
+}
+
+impl NetworkStatus {
+    pub(crate) fn set_service(&mut self, service: &str, status: ServiceStatus) {
+        self.services.insert(service.to_owned(), status);
+    }
+
+    pub(crate) fn set_chain(&mut self, chain: Option<ChainProgress>) {
+        self.chain = chain;
+    }
+
+    pub(crate) fn set_l1(&mut self, l1: Option<L1Status>) {
+        self.l1 = l1;
+    }
+
+    pub(crate) fn set_checkpoint(&mut self, checkpoint: Option<CheckpointStatus>) {
+        self.checkpoint = checkpoint;
+    }
+
+    pub(crate) fn set_maintenance(&mut self, maintenance: Vec<MaintenanceWindow>) {
+        self.maintenance = maintenance;
+    }
+
+    /// Drops the services `keep` returns `false` for, such as probes removed
+    /// from the config since the status was persisted.
+    pub(crate) fn retain_services(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.services.retain(|service, _| keep(service));
+    }
+
+    pub(crate) fn services(&self) -> &BTreeMap<String, ServiceStatus> {
+        &self.services
+    }
+
+    pub(crate) fn l1(&self) -> Option<&L1Status> {
+        self.l1.as_ref()
+    }
+
+    pub(crate) fn checkpoint(&self) -> Option<&CheckpointStatus> {
+        self.checkpoint.as_ref()
+    }
+
+    /// Returns the status of each monitored service, keyed by service name.
+    pub(crate) fn service_statuses(&self) -> BTreeMap<String, Status> {
+        self.services
+            .iter()
+            .map(|(service, status)| (service.clone(), status.status()))
+            .collect()
+    }
 }
 
 /// Network monitoring context
 pub struct NetworkMonitoringContext {
     config: NetworkMonitoringConfig,
+    probes: Vec<ProbeConfig>,
+    esplora: EsploraClient,
+    history_db: Arc<NetworkHistoryDbSled>,
+    alerts: Arc<AlertEngine>,
+    maintenance: Arc<MaintenanceSchedule>,
+    events: Arc<StatusEventLog>,
     status_available: AtomicBool,
     initial_status_query_complete: Notify,
+    /// Unix timestamp in seconds of the latest probe that got a response, `0`
+    /// if none.
+    probe_polled_at: AtomicU64,
+    /// Unix timestamp in seconds of the latest successful Esplora poll, `0` if
+    /// none.
+    l1_polled_at: AtomicU64,
+    /// Services that have reported since startup, as opposed to hydrated ones.
+    reported_services: Mutex<BTreeSet<String>>,
     network_status: RwLock<NetworkStatus>,
+    chain_metrics: RwLock<Option<ChainMetrics>>,
 }
 
 impl NetworkMonitoringContext {
-    pub fn new(config: NetworkMonitoringConfig) -> Self {
+    pub fn new(
+        config: NetworkMonitoringConfig,
+        probes: Vec<ProbeConfig>,
+        esplora: EsploraClient,
+        history_db: Arc<NetworkHistoryDbSled>,
+        alerts: Arc<AlertEngine>,
+        maintenance: Arc<MaintenanceSchedule>,
+        events: Arc<StatusEventLog>,
+    ) -> Self {
+        // The persisted status is only a cache of the last run, so one that no
+        // longer decodes after an upgrade is dropped rather than fatal.
+        let latest = history_db.get_latest_status().unwrap_or_else(|e| {
+            warn!(error = %e, "discarding persisted network status");
+            None
+        });
+        let (mut network_status, last_successful_poll_at) = latest
+            .map(|latest| (latest.status, latest.polled_at))
+            .unwrap_or_default();
+        network_status
+            .retain_services(|service| probes.iter().any(|probe| probe.name() == service));
+
         Self {
             config,
+            probes,
+            esplora,
+            history_db,
+            alerts,
+            maintenance,
+            events,
             status_available: AtomicBool::new(false),
             initial_status_query_complete: Notify::new(),
-            network_status: RwLock::new(NetworkStatus::default()),
+            probe_polled_at: AtomicU64::new(last_successful_poll_at),
+            l1_polled_at: AtomicU64::new(last_successful_poll_at),
+            reported_services: Mutex::new(BTreeSet::new()),
+            network_status: RwLock::new(network_status),
+            chain_metrics: RwLock::new(None),
         }
     }
 
@@ -61,16 +306,115 @@ impl NetworkMonitoringContext {
         &self.config
     }
 
-    pub(crate) async fn set_status(&self, status: NetworkStatus) {
+    pub(crate) fn probes(&self) -> &[ProbeConfig] {
+        &self.probes
+    }
+
+    pub(crate) fn esplora(&self) -> &EsploraClient {
+        &self.esplora
+    }
+
+    /// Number of failed Esplora requests made by the network monitors.
+    pub fn esplora_error_count(&self) -> u64 {
+        self.esplora.error_count()
+    }
+
+    pub(crate) fn history_db(&self) -> &NetworkHistoryDbSled {
+        self.history_db.as_ref()
+    }
+
+    pub(crate) fn alerts(&self) -> &AlertEngine {
+        self.alerts.as_ref()
+    }
+
+    pub(crate) fn maintenance(&self) -> &MaintenanceSchedule {
+        self.maintenance.as_ref()
+    }
+
+    pub(crate) fn events(&self) -> &StatusEventLog {
+        self.events.as_ref()
+    }
+
+    /// Applies `update` to the current status under the write lock.
+    pub(crate) async fn update_status<R>(&self, update: impl FnOnce(&mut NetworkStatus) -> R) -> R {
         let mut locked_status = self.network_status.write().await;
-        *locked_status = status;
+        update(&mut locked_status)
     }
 
     pub(crate) async fn status(&self) -> NetworkStatus {
         self.network_status.read().await.clone()
     }
 
-    pub(crate) fn mark_status_available(&self) {
+    /// Health of each probed service keyed by service name, or [`None`]
+    /// until the first status has been collected.
+    pub async fn service_health(&self) -> Option<BTreeMap<String, ComponentHealth>> {
+        if !self.is_status_available() {
+            return None;
+        }
+        let status = self.network_status.read().await;
+        Some(
+            status
+                .services()
+                .iter()
+                .map(|(service, service_status)| {
+                    (service.clone(), service_status.status().health())
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) async fn set_chain_metrics(&self, metrics: Option<ChainMetrics>) {
+        *self.chain_metrics.write().await = metrics;
+    }
+
+    pub(crate) async fn chain_metrics(&self) -> Option<ChainMetrics> {
+        self.chain_metrics.read().await.clone()
+    }
+
+    /// Records that `service` published a probe result at `polled_at`, in
+    /// unix seconds, and whether the service responded.
+    ///
+    /// The status becomes available once all `service_count` services have
+    /// reported since startup, so results hydrated from the database are not
+    /// recorded as history.
+    pub(crate) async fn record_probe_result(
+        &self,
+        service: &str,
+        responded: bool,
+        polled_at: u64,
+        service_count: usize,
+    ) {
+        if responded {
+            self.probe_polled_at.store(polled_at, Ordering::Release);
+        }
+        let mut reported_services = self.reported_services.lock().await;
+        reported_services.insert(service.to_owned());
+        if reported_services.len() >= service_count {
+            self.mark_status_available();
+        }
+    }
+
+    /// Records an L1 status fetched from Esplora at `polled_at`, in unix
+    /// seconds.
+    pub(crate) fn record_l1_poll(&self, polled_at: u64) {
+        self.l1_polled_at.store(polled_at, Ordering::Release);
+    }
+
+    /// Unix timestamp in seconds up to which both a probed service and Esplora
+    /// have answered, including polls persisted before a restart.
+    ///
+    /// This falls behind while every probe goes unanswered or Esplora is down.
+    pub(crate) fn last_successful_poll_at(&self) -> Option<u64> {
+        let probe_polled_at = self.probe_polled_at.load(Ordering::Acquire);
+        let l1_polled_at = self.l1_polled_at.load(Ordering::Acquire);
+        Some(probe_polled_at.min(l1_polled_at)).filter(|at| *at > 0)
+    }
+
+    pub(crate) fn is_status_available(&self) -> bool {
+        self.status_available.load(Ordering::Acquire)
+    }
+
+    fn mark_status_available(&self) {
         if self
             .status_available
             .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
@@ -103,7 +447,13 @@ impl NetworkMonitoringContext {
 
 #[cfg(test)]
 mod tests {
+    use axum::Json;
+    use status_config::AlertsConfig;
+    use status_feed::StatusEventDbSled;
+    use status_maintenance::MaintenanceDbSled;
+
     use super::*;
+    use crate::{db::types::DbLatestStatus, status::get_network_status};
 
     fn test_config() -> NetworkMonitoringConfig {
         toml::from_str(
@@ -119,9 +469,41 @@ mod tests {
         .expect("test config should deserialize")
     }
 
+    fn test_esplora() -> EsploraClient {
+        EsploraClient::new("http://localhost:3002", 1)
+    }
+
+    fn test_history_db() -> Arc<NetworkHistoryDbSled> {
+        Arc::new(NetworkHistoryDbSled::open_temporary().expect("open history db"))
+    }
+
+    fn test_maintenance() -> Arc<MaintenanceSchedule> {
+        let db = MaintenanceDbSled::open_temporary().expect("open maintenance db");
+        Arc::new(MaintenanceSchedule::load(&[], Vec::new(), db).expect("load maintenance schedule"))
+    }
+
+    fn test_alerts(maintenance: &Arc<MaintenanceSchedule>) -> Arc<AlertEngine> {
+        Arc::new(AlertEngine::new(AlertsConfig::default(), Arc::clone(maintenance)).0)
+    }
+
+    fn test_events() -> Arc<StatusEventLog> {
+        let db = StatusEventDbSled::open_temporary().expect("open status event db");
+        Arc::new(StatusEventLog::load(db).expect("load status events"))
+    }
+
     #[tokio::test]
     async fn wait_for_initial_status_times_out_when_unavailable() {
-        let context = NetworkMonitoringContext::new(test_config());
+        let config = test_config();
+        let maintenance = test_maintenance();
+        let context = NetworkMonitoringContext::new(
+            config.clone(),
+            config.probes(),
+            test_esplora(),
+            test_history_db(),
+            test_alerts(&maintenance),
+            maintenance,
+            test_events(),
+        );
 
         assert!(tokio::time::timeout(
             Duration::from_millis(1),
@@ -133,7 +515,17 @@ mod tests {
 
     #[tokio::test]
     async fn wait_for_initial_status_returns_when_available() {
-        let context = NetworkMonitoringContext::new(test_config());
+        let config = test_config();
+        let maintenance = test_maintenance();
+        let context = NetworkMonitoringContext::new(
+            config.clone(),
+            config.probes(),
+            test_esplora(),
+            test_history_db(),
+            test_alerts(&maintenance),
+            maintenance,
+            test_events(),
+        );
 
         context.mark_status_available();
 
@@ -144,4 +536,77 @@ mod tests {
         .await
         .is_ok());
     }
+
+    #[tokio::test]
+    async fn hydrated_status_is_served_but_not_recorded_until_probes_report() {
+        let config = test_config();
+        let maintenance = test_maintenance();
+        let history_db = test_history_db();
+        let mut status = NetworkStatus::default();
+        status.set_service("sequencer", ServiceStatus::new(Status::Online, None));
+        status.set_service("removed", ServiceStatus::new(Status::Offline, None));
+        history_db
+            .put_latest_status(&DbLatestStatus {
+                polled_at: 1_000,
+                status,
+            })
+            .expect("put latest status");
+        let context = Arc::new(NetworkMonitoringContext::new(
+            config.clone(),
+            config.probes(),
+            test_esplora(),
+            history_db,
+            test_alerts(&maintenance),
+            maintenance,
+            test_events(),
+        ));
+
+        assert_eq!(context.last_successful_poll_at(), Some(1_000));
+        let Json(status) = tokio::time::timeout(
+            Duration::from_millis(100),
+            get_network_status(Arc::clone(&context)),
+        )
+        .await
+        .expect("persisted status is served without waiting");
+        let status = serde_json::to_value(status).expect("serialize network status");
+        assert_eq!(status["services"]["sequencer"]["status"], "online");
+        assert!(status["services"].get("removed").is_none());
+        assert_eq!(status["last_successful_poll_at"], 1_000);
+        assert_eq!(status["stale"], true);
+
+        context
+            .record_probe_result("sequencer", true, 2_000, 2)
+            .await;
+        context
+            .record_probe_result("sequencer", true, 2_001, 2)
+            .await;
+        assert!(!context.is_status_available());
+        context.record_probe_result("rpc", false, 2_002, 2).await;
+        assert!(context.is_status_available());
+        // Esplora has not answered since the restart.
+        assert_eq!(context.last_successful_poll_at(), Some(1_000));
+        context.record_l1_poll(2_003);
+        assert_eq!(context.last_successful_poll_at(), Some(2_001));
+    }
+
+    #[test]
+    fn stalled_head_reports_the_threshold_that_triggered() {
+        let progress = HeadProgress {
+            head: 42,
+            unchanged_polls: 2,
+            unchanged_for: Duration::from_secs(90),
+        };
+
+        assert_eq!(
+            Degradation::stalled_head(&progress, false).reason,
+            "head 42 has not advanced for 2 polls"
+        );
+        let by_duration = Degradation::stalled_head(&progress, true);
+        assert_eq!(by_duration.reason, "head 42 has not advanced for 90s");
+        assert_eq!(by_duration.stalled_for_s, Some(90));
+        assert_eq!(
+            Degradation::stale_checkpoint(7_200).reason,
+            "no checkpoint has landed on L1 for 7200s"
+        );
+    }
 }
@@ -0,0 +1,302 @@
+//! Uptime history built from the persisted network status time series.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use status_utils::unix_time_ms;
+use tracing::error;
+
+use super::{
+    db::{
+        error::DbResult,
+        traits::NetworkHistoryDb,
+        types::{DbStatusCounts, DbStatusSample},
+    },
+    types::{NetworkMonitoringContext, NetworkStatus, Status},
+};
+
+const HOUR_MS: u64 = 60 * 60 * 1000;
+const DAY_MS: u64 = 24 * HOUR_MS;
+
+/// Width of the aggregated uptime buckets.
+const BUCKET_MS: u64 = HOUR_MS;
+
+/// Samples and buckets older than this are pruned.
+const HISTORY_RETENTION_MS: u64 = 30 * DAY_MS;
+
+/// Trailing windows reported in [`UptimeWindows`]: 24h, 7d and 30d.
+const UPTIME_WINDOWS_MS: [u64; 3] = [DAY_MS, 7 * DAY_MS, HISTORY_RETENTION_MS];
+
+/// Number of samples per service returned when the request sets no `limit`.
+const DEFAULT_SAMPLE_LIMIT: usize = 100;
+
+/// Upper bound on the `limit` query parameter.
+const MAX_SAMPLE_LIMIT: usize = 10_000;
+
+/// Persists one poll result and prunes rows past the retention window.
+pub(crate) fn record_status(
+    db: &impl NetworkHistoryDb,
+    now_ms: u64,
+    status: &NetworkStatus,
+) -> DbResult<()> {
+    let sample = DbStatusSample {
+        services: status.service_statuses(),
+    };
+    db.put_sample(now_ms, now_ms - now_ms % BUCKET_MS, &sample)?;
+    db.prune_before(now_ms.saturating_sub(HISTORY_RETENTION_MS))
+}
+
+/// Query parameters for `/api/status/history`.
+#[derive(Debug, Default, Deserialize)]
+pub struct HistoryQuery {
+    /// Maximum number of most recent samples returned per service.
+    limit: Option<usize>,
+}
+
+/// Status history of every monitored service, keyed by service name.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+pub struct NetworkStatusHistory {
+    services: BTreeMap<String, ServiceHistory>,
+}
+
+/// Status history of one service.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+struct ServiceHistory {
+    uptime: UptimeWindows,
+    /// Uptime per UTC day, oldest first. Days without samples are omitted.
+    daily: Vec<DailyUptime>,
+    /// Most recent poll results, oldest first.
+    samples: Vec<StatusSample>,
+}
+
+/// Uptime percentages over trailing windows.
+///
+/// Degraded polls count as available. A window without samples is `null`.
+#[derive(Serialize, Clone, Debug, Default, PartialEq)]
+struct UptimeWindows {
+    #[serde(rename = "24h")]
+    last_24h: Option<f64>,
+    #[serde(rename = "7d")]
+    last_7d: Option<f64>,
+    #[serde(rename = "30d")]
+    last_30d: Option<f64>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+struct DailyUptime {
+    day_start_ms: u64,
+    uptime_pct: f64,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+struct StatusSample {
+    timestamp_ms: u64,
+    status: Status,
+}
+
+/// Percentage of polls in which the service was available. Degraded services
+/// count as available; misconfigured ones do not.
+fn uptime_pct(counts: &DbStatusCounts) -> Option<f64> {
+    let total = counts.total();
+    (total > 0).then(|| (counts.online + counts.degraded) as f64 * 100.0 / total as f64)
+}
+
+/// Uptime of `service` over the retention window ending at `now_ms`, or
+/// [`None`] without samples.
+pub(crate) fn service_uptime_pct(
+    db: &impl NetworkHistoryDb,
+    service: &str,
+    now_ms: u64,
+) -> DbResult<Option<f64>> {
+    let mut counts = DbStatusCounts::default();
+    for (_, bucket) in db.get_buckets_since(now_ms.saturating_sub(HISTORY_RETENTION_MS))? {
+        if let Some(service_counts) = bucket.services.get(service) {
+            counts.merge(service_counts);
+        }
+    }
+    Ok(uptime_pct(&counts))
+}
+
+/// Builds the status history as of `now_ms`.
+fn build_history(
+    db: &impl NetworkHistoryDb,
+    now_ms: u64,
+    sample_limit: usize,
+) -> DbResult<NetworkStatusHistory> {
+    let since_ms = now_ms.saturating_sub(HISTORY_RETENTION_MS);
+    let mut services = BTreeMap::<String, ServiceHistory>::new();
+
+    let mut windows = BTreeMap::<&str, [DbStatusCounts; 3]>::new();
+    let mut days = BTreeMap::<&str, BTreeMap<u64, DbStatusCounts>>::new();
+    let buckets = db.get_buckets_since(since_ms)?;
+    for (start_ms, bucket) in &buckets {
+        let age_ms = now_ms.saturating_sub(*start_ms);
+        for (service, counts) in &bucket.services {
+            let service_windows = windows.entry(service).or_default();
+            for (window, window_ms) in service_windows.iter_mut().zip(UPTIME_WINDOWS_MS) {
+                if age_ms < window_ms {
+                    window.merge(counts);
+                }
+            }
+            days.entry(service)
+                .or_default()
+                .entry(start_ms - start_ms % DAY_MS)
+                .or_default()
+                .merge(counts);
+        }
+    }
+
+    for (service, [last_24h, last_7d, last_30d]) in windows {
+        let history = services.entry(service.to_owned()).or_default();
+        history.uptime = UptimeWindows {
+            last_24h: uptime_pct(&last_24h),
+            last_7d: uptime_pct(&last_7d),
+            last_30d: uptime_pct(&last_30d),
+        };
+        history.daily = days
+            .remove(service)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(day_start_ms, counts)| {
+                uptime_pct(&counts).map(|uptime_pct| DailyUptime {
+                    day_start_ms,
+                    uptime_pct,
+                })
+            })
+            .collect();
+    }
+
+    for (timestamp_ms, sample) in db.get_latest_samples(since_ms, sample_limit)? {
+        for (service, status) in sample.services {
+            services
+                .entry(service)
+                .or_default()
+                .samples
+                .push(StatusSample {
+                    timestamp_ms,
+                    status,
+                });
+        }
+    }
+
+    Ok(NetworkStatusHistory { services })
+}
+
+/// Handler to get the persisted network status history
+pub async fn get_network_status_history(
+    context: Arc<NetworkMonitoringContext>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<NetworkStatusHistory>, StatusCode> {
+    let sample_limit = query
+        .limit
+        .unwrap_or(DEFAULT_SAMPLE_LIMIT)
+        .min(MAX_SAMPLE_LIMIT);
+
+    build_history(context.history_db(), unix_time_ms(), sample_limit)
+        .map(Json)
+        .map_err(|e| {
+            error!(error = %e, "could not read network status history");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::history::mock::MockNetworkHistoryDb;
+
+    fn sample(status: Status) -> DbStatusSample {
+        DbStatusSample {
+            services: [("sequencer".to_owned(), status)].into(),
+        }
+    }
+
+    fn put(db: &MockNetworkHistoryDb, timestamp_ms: u64, status: Status) {
+        db.put_sample(
+            timestamp_ms,
+            timestamp_ms - timestamp_ms % BUCKET_MS,
+            &sample(status),
+        )
+        .expect("put sample");
+    }
+
+    #[test]
+    fn uptime_windows_count_degraded_as_available() {
+        let db = MockNetworkHistoryDb::default();
+        let now_ms = 40 * DAY_MS;
+
+        // Within the last 24h: 3 of 4 polls available.
+        put(&db, now_ms - HOUR_MS, Status::Online);
+        put(&db, now_ms - HOUR_MS + 1, Status::Degraded);
+        put(&db, now_ms - 2 * HOUR_MS, Status::Online);
+        put(&db, now_ms - 2 * HOUR_MS + 1, Status::Offline);
+        // Three days ago: 0 of 4 polls available.
+        for i in 0..4 {
+            put(&db, now_ms - 3 * DAY_MS + i, Status::Offline);
+        }
+
+        let history = build_history(&db, now_ms, 2).expect("history");
+        let sequencer = &history.services["sequencer"];
+
+        assert_eq!(
+            sequencer.uptime,
+            UptimeWindows {
+                last_24h: Some(75.0),
+                last_7d: Some(37.5),
+                last_30d: Some(37.5),
+            }
+        );
+        assert_eq!(
+            service_uptime_pct(&db, "sequencer", now_ms).expect("uptime"),
+            Some(37.5)
+        );
+        assert_eq!(
+            service_uptime_pct(&db, "bundler", now_ms).expect("uptime"),
+            None
+        );
+        assert_eq!(sequencer.daily.len(), 2);
+        assert_eq!(sequencer.daily[0].uptime_pct, 0.0);
+        assert_eq!(sequencer.daily[1].uptime_pct, 75.0);
+        assert_eq!(
+            sequencer.samples,
+            vec![
+                StatusSample {
+                    timestamp_ms: now_ms - HOUR_MS,
+                    status: Status::Online,
+                },
+                StatusSample {
+                    timestamp_ms: now_ms - HOUR_MS + 1,
+                    status: Status::Degraded,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_history_has_no_services() {
+        let db = MockNetworkHistoryDb::default();
+
+        assert_eq!(
+            build_history(&db, 40 * DAY_MS, DEFAULT_SAMPLE_LIMIT).expect("history"),
+            NetworkStatusHistory::default()
+        );
+    }
+
+    #[test]
+    fn recording_prunes_rows_past_retention() {
+        let db = MockNetworkHistoryDb::default();
+        let now_ms = 40 * DAY_MS;
+        put(&db, now_ms - HISTORY_RETENTION_MS - DAY_MS, Status::Offline);
+
+        record_status(&db, now_ms, &NetworkStatus::default()).expect("record status");
+
+        let samples = db.get_latest_samples(0, 10).expect("samples");
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].0, now_ms);
+        assert_eq!(db.get_buckets_since(0).expect("buckets").len(), 1);
+    }
+}
@@ -0,0 +1,106 @@
+//! Handlers of the Statuspage-compatible endpoints.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use axum::{http::StatusCode, Json};
+use status_bridge::BridgeMonitoringContext;
+use status_config::{Config, StatusPageConfig};
+use status_incidents::IncidentLog;
+use status_maintenance::MaintenanceSchedule;
+use status_network::NetworkMonitoringContext;
+use status_utils::{unix_time_s, Component};
+use tracing::warn;
+
+use super::{
+    summary::{summary, ComponentDirectory, PageState},
+    types::{StatusResponse, Summary},
+};
+
+/// Sources of the Statuspage summary.
+pub struct StatusPage {
+    config: StatusPageConfig,
+    directory: ComponentDirectory,
+    network: Arc<NetworkMonitoringContext>,
+    bridge: Arc<BridgeMonitoringContext>,
+    incidents: Arc<IncidentLog>,
+    maintenance: Arc<MaintenanceSchedule>,
+}
+
+impl StatusPage {
+    /// Lists the configured network services, then the bridge operators.
+    pub fn new(
+        config: &Config,
+        network: Arc<NetworkMonitoringContext>,
+        bridge: Arc<BridgeMonitoringContext>,
+        incidents: Arc<IncidentLog>,
+        maintenance: Arc<MaintenanceSchedule>,
+    ) -> Self {
+        let services = config.network_probes().into_iter().map(|probe| {
+            let name = probe.name().to_owned();
+            (Component::Service(name.clone()), name)
+        });
+        let operators = config.bridge().operators().iter().map(|operator| {
+            (
+                Component::Operator(operator.public_key().to_string()),
+                operator.name().to_owned(),
+            )
+        });
+
+        Self {
+            config: config.statuspage().clone(),
+            directory: ComponentDirectory::new(services.chain(operators)),
+            network,
+            bridge,
+            incidents,
+            maintenance,
+        }
+    }
+
+    async fn summary(&self) -> Result<Summary, StatusCode> {
+        let now = unix_time_s();
+
+        let mut health = BTreeMap::new();
+        if let Some(services) = self.network.service_health().await {
+            health.extend(
+                services
+                    .into_iter()
+                    .map(|(service, health)| (Component::Service(service), health)),
+            );
+        }
+        if let Some(operators) = self.bridge.operator_health().await {
+            health.extend(
+                operators
+                    .into_iter()
+                    .map(|(operator, health)| (Component::Operator(operator), health)),
+            );
+        }
+        let incidents = self.incidents.list(now).map_err(|e| {
+            warn!(error = %e, "failed to read incidents for the status page");
+            StatusCode::INTERNAL_SERVER_ERROR
+        })?;
+        let maintenance = self.maintenance.upcoming(now);
+
+        Ok(summary(
+            &self.config,
+            &self.directory,
+            PageState {
+                health: &health,
+                incidents: incidents.active(),
+                maintenance: &maintenance,
+            },
+            now,
+        ))
+    }
+}
+
+/// Handler to get the Statuspage `/api/v2/summary.json` document
+pub async fn get_statuspage_summary(page: Arc<StatusPage>) -> Result<Json<Summary>, StatusCode> {
+    page.summary().await.map(Json)
+}
+
+/// Handler to get the Statuspage `/api/v2/status.json` document
+pub async fn get_statuspage_status(
+    page: Arc<StatusPage>,
+) -> Result<Json<StatusResponse>, StatusCode> {
+    page.summary().await.map(|summary| Json(summary.into()))
+}
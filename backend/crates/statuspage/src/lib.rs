@@ -0,0 +1,6 @@
+mod api;
+mod summary;
+mod types;
+
+pub use api::{get_statuspage_status, get_statuspage_summary, StatusPage};
+pub use types::{StatusResponse, Summary};
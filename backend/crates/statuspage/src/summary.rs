@@ -0,0 +1,445 @@
+//! Maps the monitored components, incidents and maintenance windows to the
+//! Statuspage schema.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use status_config::StatusPageConfig;
+use status_incidents::{Incident, IncidentStatus};
+use status_maintenance::MaintenanceWindow;
+use status_utils::{rfc3339, Component, ComponentHealth};
+
+use super::types::{
+    ComponentStatus, Impact, IncidentState, Indicator, Page, PageComponent, PageIncident,
+    PageIncidentUpdate, Summary,
+};
+
+/// Component listed on the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DirectoryEntry {
+    /// Id derived from the configured name, e.g. `service-rpc`.
+    id: String,
+    name: String,
+    component: Component,
+}
+
+/// Components listed on the page, in configuration order.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ComponentDirectory {
+    entries: Vec<DirectoryEntry>,
+}
+
+impl ComponentDirectory {
+    /// Lists `components` under their display names.
+    pub(crate) fn new(components: impl IntoIterator<Item = (Component, String)>) -> Self {
+        let mut taken = BTreeSet::new();
+        let entries = components
+            .into_iter()
+            .map(|(component, name)| {
+                let kind = match component {
+                    Component::Service(_) => "service",
+                    Component::Operator(_) => "operator",
+                };
+                let base = format!("{kind}-{}", slug(&name));
+                // Names that only differ in punctuation share a slug.
+                let id = (1..)
+                    .map(|n| match n {
+                        1 => base.clone(),
+                        n => format!("{base}-{n}"),
+                    })
+                    .find(|id| !taken.contains(id))
+                    .expect("unbounded suffixes");
+                taken.insert(id.clone());
+                DirectoryEntry {
+                    id,
+                    name,
+                    component,
+                }
+            })
+            .collect();
+        Self { entries }
+    }
+}
+
+/// Lowercase alphanumeric words of `name` joined by dashes.
+fn slug(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn health_status(health: ComponentHealth) -> ComponentStatus {
+    match health {
+        ComponentHealth::Operational => ComponentStatus::Operational,
+        ComponentHealth::Degraded => ComponentStatus::DegradedPerformance,
+        ComponentHealth::PartialOutage => ComponentStatus::PartialOutage,
+        ComponentHealth::MajorOutage => ComponentStatus::MajorOutage,
+    }
+}
+
+/// Status an unresolved incident implies for its components.
+fn incident_status(incident: &Incident) -> ComponentStatus {
+    match Impact::from(incident.severity()) {
+        Impact::Minor => ComponentStatus::DegradedPerformance,
+        Impact::Major => ComponentStatus::PartialOutage,
+        _ => ComponentStatus::MajorOutage,
+    }
+}
+
+fn component_indicator(status: ComponentStatus) -> Indicator {
+    match status {
+        ComponentStatus::Operational => Indicator::None,
+        ComponentStatus::UnderMaintenance => Indicator::Maintenance,
+        ComponentStatus::DegradedPerformance => Indicator::Minor,
+        ComponentStatus::PartialOutage => Indicator::Major,
+        ComponentStatus::MajorOutage => Indicator::Critical,
+    }
+}
+
+/// Current state of the page's sources.
+pub(crate) struct PageState<'a> {
+    /// Health reported by the monitors. Components not reported yet are
+    /// left out unless an incident or maintenance window sets their status.
+    pub(crate) health: &'a BTreeMap<Component, ComponentHealth>,
+    /// Unresolved incidents.
+    pub(crate) incidents: &'a [Incident],
+    /// Windows that have not ended.
+    pub(crate) maintenance: &'a [MaintenanceWindow],
+}
+
+/// Builds the summary of the page at `now`.
+pub(crate) fn summary(
+    config: &StatusPageConfig,
+    directory: &ComponentDirectory,
+    state: PageState<'_>,
+    now: u64,
+) -> Summary {
+    let updated_at = rfc3339(now);
+    let page_component = |entry: &DirectoryEntry, position: usize| {
+        let maintained = state
+            .maintenance
+            .iter()
+            .any(|window| window.is_active(now) && window.covers(&entry.component));
+        let status = if maintained {
+            Some(ComponentStatus::UnderMaintenance)
+        } else {
+            state
+                .incidents
+                .iter()
+                .filter(|incident| incident.components().contains(&entry.component))
+                .map(incident_status)
+                .chain(
+                    state
+                        .health
+                        .get(&entry.component)
+                        .copied()
+                        .map(health_status),
+                )
+                .max()
+        };
+        // Component history is not tracked, so both times are the page's.
+        status.map(|status| PageComponent {
+            id: entry.id.clone(),
+            name: entry.name.clone(),
+            status,
+            created_at: updated_at.clone(),
+            updated_at: updated_at.clone(),
+            position,
+            description: None,
+            showcase: false,
+            group_id: None,
+            page_id: config.page_id().to_owned(),
+            group: false,
+            only_show_if_degraded: false,
+        })
+    };
+    let components: Vec<_> = directory
+        .entries
+        .iter()
+        .enumerate()
+        .filter_map(|(index, entry)| page_component(entry, index + 1))
+        .collect();
+    let affected = |covers: &dyn Fn(&Component) -> bool| {
+        directory
+            .entries
+            .iter()
+            .filter(|entry| covers(&entry.component))
+            .filter_map(|entry| {
+                components
+                    .iter()
+                    .find(|component| component.id == entry.id)
+                    .cloned()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let incidents: Vec<_> = state
+        .incidents
+        .iter()
+        .map(|incident| {
+            let incident_id = incident.id().to_string();
+            let monitoring_at = incident
+                .updates()
+                .iter()
+                .find(|update| update.status() == IncidentStatus::Monitoring)
+                .map(|update| rfc3339(update.at()));
+            let incident_updates = incident
+                .updates()
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(index, update)| PageIncidentUpdate {
+                    id: format!("{incident_id}-{index}"),
+                    status: update.status().into(),
+                    body: update.message().to_owned(),
+                    incident_id: incident_id.clone(),
+                    created_at: rfc3339(update.at()),
+                    updated_at: rfc3339(update.at()),
+                    display_at: rfc3339(update.at()),
+                })
+                .collect();
+            PageIncident {
+                name: incident.title().to_owned(),
+                status: incident.status().into(),
+                created_at: rfc3339(incident.created_at()),
+                updated_at: rfc3339(incident.updated_at()),
+                monitoring_at,
+                resolved_at: incident.resolved_at().map(rfc3339),
+                impact: incident.severity().into(),
+                shortlink: config.url().map(str::to_owned),
+                started_at: rfc3339(incident.created_at()),
+                page_id: config.page_id().to_owned(),
+                incident_updates,
+                components: affected(&|component| incident.components().contains(component)),
+                scheduled_for: None,
+                scheduled_until: None,
+                id: incident_id,
+            }
+        })
+        .collect();
+
+    let scheduled_maintenances: Vec<_> = state
+        .maintenance
+        .iter()
+        .map(|window| {
+            // Windows declared in the config have no id, but a position in
+            // the configured list.
+            let id = match window.id() {
+                Some(id) => format!("maintenance-{id}"),
+                None => format!(
+                    "maintenance-config-{}",
+                    window.config_index().unwrap_or_default()
+                ),
+            };
+            let status = if window.is_active(now) {
+                IncidentState::InProgress
+            } else {
+                IncidentState::Scheduled
+            };
+            let starts_at = rfc3339(window.starts_at());
+            PageIncident {
+                name: window.message().to_owned(),
+                status,
+                created_at: starts_at.clone(),
+                updated_at: starts_at.clone(),
+                monitoring_at: None,
+                resolved_at: None,
+                impact: Impact::Maintenance,
+                shortlink: config.url().map(str::to_owned),
+                started_at: starts_at.clone(),
+                page_id: config.page_id().to_owned(),
+                incident_updates: vec![PageIncidentUpdate {
+                    id: format!("{id}-0"),
+                    status,
+                    body: window.message().to_owned(),
+                    incident_id: id.clone(),
+                    created_at: starts_at.clone(),
+                    updated_at: starts_at.clone(),
+                    display_at: starts_at.clone(),
+                }],
+                components: affected(&|component| window.covers(component)),
+                scheduled_for: Some(starts_at),
+                scheduled_until: Some(rfc3339(window.ends_at())),
+                id,
+            }
+        })
+        .collect();
+
+    let indicator = components
+        .iter()
+        .map(|component| component_indicator(component.status))
+        .chain(
+            state
+                .incidents
+                .iter()
+                .map(|incident| match Impact::from(incident.severity()) {
+                    Impact::Minor => Indicator::Minor,
+                    Impact::Major => Indicator::Major,
+                    _ => Indicator::Critical,
+                }),
+        )
+        .max()
+        .unwrap_or(Indicator::None);
+
+    Summary {
+        page: Page {
+            id: config.page_id().to_owned(),
+            name: config.name().to_owned(),
+            url: config.url().map(str::to_owned),
+            time_zone: "Etc/UTC",
+            updated_at,
+        },
+        components,
+        incidents,
+        scheduled_maintenances,
+        status: indicator.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const OPERATOR_PK: &str = "02157d08fa2eb6071cef0750bcf1c0c9e94c2f05f629cdbdb3982851dce9472cd0";
+
+    fn directory() -> ComponentDirectory {
+        ComponentDirectory::new([
+            (Component::Service("rpc".to_owned()), "rpc".to_owned()),
+            (
+                Component::Service("bundler".to_owned()),
+                "bundler".to_owned(),
+            ),
+            (Component::Service("prover".to_owned()), "prover".to_owned()),
+            (
+                Component::Operator(OPERATOR_PK.to_owned()),
+                "Alpen Labs #1".to_owned(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn component_ids_derive_from_names() {
+        let directory = ComponentDirectory::new([
+            (Component::Service("rpc".to_owned()), "rpc".to_owned()),
+            (Component::Service("RPC".to_owned()), "RPC".to_owned()),
+            (
+                Component::Operator(OPERATOR_PK.to_owned()),
+                "Alpen Labs #1".to_owned(),
+            ),
+        ]);
+        let ids: Vec<_> = directory.entries.iter().map(|entry| &entry.id).collect();
+
+        assert_eq!(
+            ids,
+            ["service-rpc", "service-rpc-2", "operator-alpen-labs-1"]
+        );
+    }
+
+    #[test]
+    fn summary_combines_health_incidents_and_maintenance() {
+        let health = BTreeMap::from([
+            (
+                Component::Service("rpc".to_owned()),
+                ComponentHealth::Operational,
+            ),
+            (
+                Component::Service("bundler".to_owned()),
+                ComponentHealth::Degraded,
+            ),
+            (
+                Component::Operator(OPERATOR_PK.to_owned()),
+                ComponentHealth::Operational,
+            ),
+        ]);
+        let incident: Incident = serde_json::from_value(json!({
+            "id": 3,
+            "title": "RPC errors",
+            "severity": "major",
+            "status": "identified",
+            "components": [{ "service": "rpc" }],
+            "created_at": 100,
+            "updated_at": 200,
+            "resolved_at": null,
+            "updates": [
+                { "at": 100, "status": "investigating", "message": "Looking into it" },
+                { "at": 200, "status": "identified", "message": "Found it" },
+            ],
+        }))
+        .expect("incident should deserialize");
+        let window: MaintenanceWindow = serde_json::from_value(json!({
+            "id": 7,
+            "starts_at": 0,
+            "ends_at": 1_000,
+            "message": "Upgrading the bundler",
+            "services": ["bundler"],
+            "operators": [],
+        }))
+        .expect("window should deserialize");
+
+        let summary = summary(
+            &StatusPageConfig::default(),
+            &directory(),
+            PageState {
+                health: &health,
+                incidents: &[incident],
+                maintenance: &[window],
+            },
+            300,
+        );
+        let summary = serde_json::to_value(summary).expect("summary should serialize");
+
+        let statuses: Vec<_> = summary["components"]
+            .as_array()
+            .expect("components")
+            .iter()
+            .map(|component| (component["id"].clone(), component["status"].clone()))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                (json!("service-rpc"), json!("partial_outage")),
+                (json!("service-bundler"), json!("under_maintenance")),
+                (json!("operator-alpen-labs-1"), json!("operational")),
+            ]
+        );
+        assert_eq!(summary["incidents"][0]["id"], "3");
+        assert_eq!(summary["incidents"][0]["impact"], "major");
+        assert_eq!(summary["incidents"][0]["incident_updates"][0]["id"], "3-1");
+        assert_eq!(
+            summary["incidents"][0]["components"][0]["id"],
+            "service-rpc"
+        );
+        assert_eq!(summary["scheduled_maintenances"][0]["id"], "maintenance-7");
+        assert_eq!(
+            summary["scheduled_maintenances"][0]["status"],
+            "in_progress"
+        );
+        assert_eq!(
+            summary["scheduled_maintenances"][0]["scheduled_until"],
+            "1970-01-01T00:16:40Z"
+        );
+        assert_eq!(summary["status"]["indicator"], "major");
+        assert_eq!(summary["status"]["description"], "Partial System Outage");
+    }
+
+    #[test]
+    fn quiet_page_is_operational() {
+        let summary = summary(
+            &StatusPageConfig::default(),
+            &directory(),
+            PageState {
+                health: &BTreeMap::new(),
+                incidents: &[],
+                maintenance: &[],
+            },
+            0,
+        );
+
+        assert!(summary.components.is_empty());
+        assert_eq!(summary.status.indicator, Indicator::None);
+        assert_eq!(summary.status.description, "All Systems Operational");
+    }
+}
@@ -0,0 +1,188 @@
+//! Response shapes of the Statuspage `/api/v2` endpoints.
+//!
+//! Timestamps are RFC 3339 strings and ids are strings, as in Statuspage.
+
+use serde::Serialize;
+use status_incidents::{IncidentSeverity, IncidentStatus};
+
+/// Page the summary describes.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Page {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) url: Option<String>,
+    pub(crate) time_zone: &'static str,
+    pub(crate) updated_at: String,
+}
+
+/// Status of a component, from best to worst except for maintenance.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    Operational,
+    DegradedPerformance,
+    PartialOutage,
+    MajorOutage,
+    UnderMaintenance,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PageComponent {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) status: ComponentStatus,
+    pub(crate) created_at: String,
+    pub(crate) updated_at: String,
+    pub(crate) position: usize,
+    pub(crate) description: Option<String>,
+    pub(crate) showcase: bool,
+    pub(crate) group_id: Option<String>,
+    pub(crate) page_id: String,
+    pub(crate) group: bool,
+    pub(crate) only_show_if_degraded: bool,
+}
+
+/// Impact of an incident or maintenance.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Impact {
+    Minor,
+    Major,
+    Critical,
+    Maintenance,
+}
+
+impl From<IncidentSeverity> for Impact {
+    fn from(severity: IncidentSeverity) -> Self {
+        match severity {
+            IncidentSeverity::Minor => Self::Minor,
+            IncidentSeverity::Major => Self::Major,
+            IncidentSeverity::Critical => Self::Critical,
+        }
+    }
+}
+
+/// Progress of an incident or maintenance.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IncidentState {
+    Investigating,
+    Identified,
+    Monitoring,
+    Resolved,
+    Scheduled,
+    InProgress,
+}
+
+impl From<IncidentStatus> for IncidentState {
+    fn from(status: IncidentStatus) -> Self {
+        match status {
+            IncidentStatus::Investigating => Self::Investigating,
+            IncidentStatus::Identified => Self::Identified,
+            IncidentStatus::Monitoring => Self::Monitoring,
+            IncidentStatus::Resolved => Self::Resolved,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PageIncidentUpdate {
+    pub(crate) id: String,
+    pub(crate) status: IncidentState,
+    pub(crate) body: String,
+    pub(crate) incident_id: String,
+    pub(crate) created_at: String,
+    pub(crate) updated_at: String,
+    pub(crate) display_at: String,
+}
+
+/// Incident or scheduled maintenance.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PageIncident {
+    pub(crate) id: String,
+    pub(crate) name: String,
+    pub(crate) status: IncidentState,
+    pub(crate) created_at: String,
+    pub(crate) updated_at: String,
+    pub(crate) monitoring_at: Option<String>,
+    pub(crate) resolved_at: Option<String>,
+    pub(crate) impact: Impact,
+    pub(crate) shortlink: Option<String>,
+    pub(crate) started_at: String,
+    pub(crate) page_id: String,
+    pub(crate) incident_updates: Vec<PageIncidentUpdate>,
+    /// Affected components.
+    pub(crate) components: Vec<PageComponent>,
+    /// Start of a scheduled maintenance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scheduled_for: Option<String>,
+    /// End of a scheduled maintenance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) scheduled_until: Option<String>,
+}
+
+/// Overall status indicator.
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Indicator {
+    None,
+    Maintenance,
+    Minor,
+    Major,
+    Critical,
+}
+
+impl Indicator {
+    fn description(self) -> &'static str {
+        match self {
+            Self::None => "All Systems Operational",
+            Self::Maintenance => "Service Under Maintenance",
+            Self::Minor => "Minor Service Outage",
+            Self::Major => "Partial System Outage",
+            Self::Critical => "Major System Outage",
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Status {
+    pub(crate) indicator: Indicator,
+    pub(crate) description: &'static str,
+}
+
+impl From<Indicator> for Status {
+    fn from(indicator: Indicator) -> Self {
+        Self {
+            indicator,
+            description: indicator.description(),
+        }
+    }
+}
+
+/// Body of `/api/v2/status.json`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct StatusResponse {
+    pub(crate) page: Page,
+    pub(crate) status: Status,
+}
+
+/// Body of `/api/v2/summary.json`.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub(crate) page: Page,
+    pub(crate) components: Vec<PageComponent>,
+    /// Unresolved incidents.
+    pub(crate) incidents: Vec<PageIncident>,
+    /// Active and upcoming maintenance.
+    pub(crate) scheduled_maintenances: Vec<PageIncident>,
+    pub(crate) status: Status,
+}
+
+impl From<Summary> for StatusResponse {
+    fn from(summary: Summary) -> Self {
+        Self {
+            page: summary.page,
+            status: summary.status,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Bearer-token authorization of the admin API.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+
+/// Checks that `headers` carry `Authorization: Bearer <admin_token>`.
+///
+/// Without a configured token the admin API is disabled and every request is
+/// rejected as not found.
+pub fn authorize_admin(headers: &HeaderMap, admin_token: Option<&str>) -> Result<(), StatusCode> {
+    let Some(admin_token) = admin_token else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+    if constant_time_eq(presented.as_bytes(), admin_token.as_bytes()) {
+        Ok(())
+    } else {
+        Err(StatusCode::UNAUTHORIZED)
+    }
+}
+
+/// Middleware rejecting requests without the admin token, to be layered on
+/// the admin routes.
+pub async fn require_admin(
+    State(admin_token): State<Option<Arc<str>>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    authorize_admin(request.headers(), admin_token.as_deref())?;
+    Ok(next.run(request).await)
+}
+
+/// Compares without short-circuiting on the first differing byte, so the
+/// response time does not leak how much of the token matched.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn headers(authorization: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(authorization).expect("valid header"),
+        );
+        headers
+    }
+
+    #[test]
+    fn admin_requests_need_the_configured_token() {
+        assert_eq!(
+            authorize_admin(&headers("Bearer secret"), None),
+            Err(StatusCode::NOT_FOUND)
+        );
+        assert_eq!(
+            authorize_admin(&HeaderMap::new(), Some("secret")),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            authorize_admin(&headers("Bearer secreT"), Some("secret")),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            authorize_admin(&headers("secret"), Some("secret")),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            authorize_admin(&headers("Bearer secret"), Some("secret")),
+            Ok(())
+        );
+    }
+}
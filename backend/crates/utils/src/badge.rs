@@ -0,0 +1,166 @@
+//! Shields-style SVG status badges.
+
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+};
+
+use super::xml::escape_xml;
+
+/// Content type of rendered badges.
+pub const SVG_CONTENT_TYPE: &str = "image/svg+xml; charset=utf-8";
+
+/// Height of a badge in pixels.
+const HEIGHT: usize = 20;
+
+/// Horizontal padding around each text in pixels.
+const PADDING: usize = 10;
+
+/// Background color of the message half of a badge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BadgeColor {
+    Green,
+    Yellow,
+    Orange,
+    Red,
+    Grey,
+}
+
+impl BadgeColor {
+    fn hex(self) -> &'static str {
+        match self {
+            Self::Green => "#4c1",
+            Self::Yellow => "#dfb317",
+            Self::Orange => "#fe7d37",
+            Self::Red => "#e05d44",
+            Self::Grey => "#9f9f9f",
+        }
+    }
+}
+
+/// Badge with a label on grey and a message on the status color.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Badge {
+    label: String,
+    message: String,
+    color: BadgeColor,
+}
+
+/// Approximate width in pixels of `text` in 11px Verdana.
+fn text_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            'i' | 'j' | 'l' | 'I' | '.' | ',' | ':' | '|' | '\'' | ' ' => 4,
+            'f' | 'r' | 't' | '1' => 5,
+            'm' | 'w' | 'M' | 'W' | '%' => 10,
+            c if c.is_ascii_uppercase() => 8,
+            _ => 7,
+        })
+        .sum()
+}
+
+impl Badge {
+    pub fn new(label: impl Into<String>, message: impl Into<String>, color: BadgeColor) -> Self {
+        Self {
+            label: label.into(),
+            message: message.into(),
+            color,
+        }
+    }
+
+    /// Appends an uptime percentage to the message, if known.
+    pub fn with_uptime(mut self, uptime_pct: Option<f64>) -> Self {
+        if let Some(uptime_pct) = uptime_pct {
+            self.message = format!("{} | {uptime_pct:.2}%", self.message);
+        }
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn color(&self) -> BadgeColor {
+        self.color
+    }
+
+    /// Renders the badge in the shields.io "flat" style.
+    pub fn render(&self) -> String {
+        let label_width = text_width(&self.label) + PADDING;
+        let message_width = text_width(&self.message) + PADDING;
+        let width = label_width + message_width;
+        let label = escape_xml(&self.label);
+        let message = escape_xml(&self.message);
+        let color = self.color.hex();
+        let label_x = label_width / 2;
+        let message_x = label_width + message_width / 2;
+
+        format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{HEIGHT}" role="img" aria-label="{label}: {message}">
+  <title>{label}: {message}</title>
+  <linearGradient id="s" x2="0" y2="100%">
+    <stop offset="0" stop-color="#bbb" stop-opacity=".1"/>
+    <stop offset="1" stop-opacity=".1"/>
+  </linearGradient>
+  <clipPath id="r"><rect width="{width}" height="{HEIGHT}" rx="3" fill="#fff"/></clipPath>
+  <g clip-path="url(#r)">
+    <rect width="{label_width}" height="{HEIGHT}" fill="#555"/>
+    <rect x="{label_width}" width="{message_width}" height="{HEIGHT}" fill="{color}"/>
+    <rect width="{width}" height="{HEIGHT}" fill="url(#s)"/>
+  </g>
+  <g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">
+    <text x="{label_x}" y="15" fill="#010101" fill-opacity=".3">{label}</text>
+    <text x="{label_x}" y="14">{label}</text>
+    <text x="{message_x}" y="15" fill="#010101" fill-opacity=".3">{message}</text>
+    <text x="{message_x}" y="14">{message}</text>
+  </g>
+</svg>
+"##
+        )
+    }
+
+    /// Serves the badge, cacheable for `max_age_s` seconds.
+    pub fn into_response(self, max_age_s: u64) -> Response {
+        (
+            [
+                (header::CONTENT_TYPE, SVG_CONTENT_TYPE.to_owned()),
+                (
+                    header::CACHE_CONTROL,
+                    format!("public, max-age={max_age_s}"),
+                ),
+            ],
+            self.render(),
+        )
+            .into_response()
+    }
+}
+
+/// Returns the name in a badge file name such as `sequencer.svg`.
+pub fn badge_name(file_name: &str) -> Option<&str> {
+    file_name
+        .strip_suffix(".svg")
+        .filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_escaped_label_and_uptime() {
+        let badge = Badge::new("a&b", "online", BadgeColor::Green).with_uptime(Some(99.954));
+        assert_eq!(badge.message(), "online | 99.95%");
+
+        let svg = badge.render();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg""#));
+        assert!(svg.contains(r#"aria-label="a&amp;b: online | 99.95%""#));
+        assert!(svg.contains(r##"fill="#4c1""##));
+    }
+
+    #[test]
+    fn badge_names_need_the_svg_extension() {
+        assert_eq!(badge_name("sequencer.svg"), Some("sequencer"));
+        assert_eq!(badge_name("sequencer"), None);
+        assert_eq!(badge_name(".svg"), None);
+    }
+}
@@ -0,0 +1,26 @@
+//! Monitored components that alerts, maintenance windows and incidents refer
+//! to.
+
+use serde::{Deserialize, Serialize};
+
+/// A network service or bridge operator shown on the status page.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Component {
+    /// Network service, by probe name.
+    Service(String),
+    /// Bridge operator, by hex-encoded public key.
+    Operator(String),
+}
+
+/// Health of a component as reported by its monitor, from best to worst.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentHealth {
+    Operational,
+    /// Available, but slow or not making progress.
+    Degraded,
+    /// Available, but not serving its purpose.
+    PartialOutage,
+    MajorOutage,
+}
@@ -0,0 +1,148 @@
+//! Helpers shared by the sled-backed databases.
+
+use std::{fmt::Display, path::Path};
+
+use anyhow::Context;
+use typed_sled::{Schema, SledTree};
+
+/// Errors of the databases that store records under increasing ids.
+#[derive(Debug, thiserror::Error)]
+pub enum DbError {
+    #[error("{0} id overflow")]
+    IdOverflow(&'static str),
+
+    #[error(transparent)]
+    Sled(#[from] typed_sled::error::Error),
+}
+
+impl From<sled::Error> for DbError {
+    fn from(value: sled::Error) -> Self {
+        Self::Sled(value.into())
+    }
+}
+
+pub type DbResult<T> = Result<T, DbError>;
+
+/// Opens the sled database at `path`, creating the directory, and initializes
+/// its trees with `init`.
+///
+/// `name` describes the database in error messages, e.g. `"incident"`.
+pub fn open_sled_db<T, E: Display>(
+    path: impl AsRef<Path>,
+    name: &str,
+    init: impl FnOnce(sled::Db) -> Result<T, E>,
+) -> anyhow::Result<T> {
+    let path = path.as_ref();
+    std::fs::create_dir_all(path)
+        .with_context(|| format!("create {name} db dir {}", path.display()))?;
+    let sled_db =
+        sled::open(path).with_context(|| format!("open {name} sled db at {}", path.display()))?;
+    // typed-sled codec errors are not Send + Sync, so anyhow::Context cannot preserve them.
+    init(sled_db).map_err(|e| anyhow::anyhow!("initialize {name} trees: {e}"))
+}
+
+/// Opens a temporary sled database deleted on drop and initializes its trees
+/// with `init`.
+pub fn open_temporary_sled_db<T, E: Display>(
+    name: &str,
+    init: impl FnOnce(sled::Db) -> Result<T, E>,
+) -> anyhow::Result<T> {
+    let sled_db = sled::Config::new()
+        .temporary(true)
+        .open()
+        .with_context(|| format!("open temporary {name} sled db"))?;
+    init(sled_db).map_err(|e| anyhow::anyhow!("initialize temporary {name} trees: {e}"))
+}
+
+/// Returns the id after the highest one stored in `tree`.
+///
+/// `name` describes the records in the overflow error.
+pub fn next_id<S>(tree: &SledTree<S>, name: &'static str) -> DbResult<u64>
+where
+    S: Schema<Key = u64>,
+{
+    match tree.last()? {
+        Some((last_id, _)) => last_id.checked_add(1).ok_or(DbError::IdOverflow(name)),
+        None => Ok(0),
+    }
+}
+
+/// Implements a JSON `ValueCodec` of a typed-sled schema for a serde type.
+#[macro_export]
+macro_rules! impl_json_value_codec {
+    ($schema:ty, $value:ty) => {
+        impl $crate::db::__private::typed_sled::codec::ValueCodec<$schema> for $value {
+            type Decoded = Self;
+
+            fn encode_value(
+                &self,
+            ) -> Result<Vec<u8>, $crate::db::__private::typed_sled::codec::CodecError> {
+                $crate::db::__private::serde_json::to_vec(self).map_err(|e| {
+                    $crate::db::__private::typed_sled::codec::CodecError::SerializationFailed {
+                        schema: <$schema as $crate::db::__private::typed_sled::Schema>::TREE_NAME.0,
+                        source: e.into(),
+                    }
+                })
+            }
+
+            fn decode_value(
+                data: $crate::db::__private::sled::IVec,
+            ) -> Result<Self::Decoded, $crate::db::__private::typed_sled::codec::CodecError> {
+                $crate::db::__private::serde_json::from_slice(data.as_ref()).map_err(|e| {
+                    $crate::db::__private::typed_sled::codec::CodecError::DeserializationFailed {
+                        schema: <$schema as $crate::db::__private::typed_sled::Schema>::TREE_NAME.0,
+                        source: e.into(),
+                    }
+                })
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+    pub use sled;
+    pub use typed_sled;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use typed_sled::{schema::TreeName, SledDb};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Record {
+        name: String,
+    }
+
+    #[derive(Debug)]
+    struct RecordSchema;
+
+    impl Schema for RecordSchema {
+        const TREE_NAME: TreeName = TreeName("record");
+        type Key = u64;
+        type Value = Record;
+    }
+
+    crate::impl_json_value_codec!(RecordSchema, Record);
+
+    #[test]
+    fn json_records_round_trip_under_increasing_ids() {
+        let tree = open_temporary_sled_db("record", |sled_db| {
+            SledDb::new(sled_db)?.get_tree::<RecordSchema>()
+        })
+        .expect("open record db");
+
+        assert_eq!(next_id(&tree, "record").expect("next id"), 0);
+        let record = Record {
+            name: "rpc".to_owned(),
+        };
+        tree.insert(&0, &record).expect("insert record");
+
+        assert_eq!(tree.get(&0).expect("get record"), Some(record));
+        assert_eq!(next_id(&tree, "record").expect("next id"), 1);
+    }
+}
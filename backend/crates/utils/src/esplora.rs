@@ -0,0 +1,121 @@
+use serde::de::DeserializeOwned;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// HTTP client for an Esplora API.
+pub struct EsploraClient {
+    base_url: String,
+    client: reqwest::Client,
+    /// Number of failed requests since the client was created.
+    errors: AtomicU64,
+}
+
+impl EsploraClient {
+    /// Creates a client for `esplora_url` whose requests time out after
+    /// `request_timeout_s` seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client cannot be built.
+    pub fn new(esplora_url: &str, request_timeout_s: u64) -> Self {
+        Self {
+            base_url: esplora_url.trim_end_matches('/').to_string(),
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(request_timeout_s))
+                .build()
+                .expect("failed to create Esplora HTTP client"),
+            errors: AtomicU64::new(0),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    async fn get(&self, path: &str) -> reqwest::Result<reqwest::Response> {
+        self.client
+            .get(self.url(path))
+            .send()
+            .await?
+            .error_for_status()
+    }
+
+    /// Fetches `path` and returns the response body as text.
+    ///
+    /// Fails on error statuses, so an error page is never returned as a body.
+    pub async fn get_text(&self, path: &str) -> reqwest::Result<String> {
+        let result = async { self.get(path).await?.text().await }.await;
+        self.count_error(result)
+    }
+
+    /// Fetches `path` and parses the response body as JSON.
+    pub async fn get_json<T: DeserializeOwned>(&self, path: &str) -> reqwest::Result<T> {
+        let result = async { self.get(path).await?.json().await }.await;
+        self.count_error(result)
+    }
+
+    /// Fetches the chain tip height as returned by Esplora.
+    pub async fn get_tip_height(&self) -> reqwest::Result<String> {
+        self.get_text("/blocks/tip/height").await
+    }
+
+    /// Returns the number of failed requests since the client was created.
+    pub fn error_count(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    fn count_error<T>(&self, result: reqwest::Result<T>) -> reqwest::Result<T> {
+        if result.is_err() {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{io::AsyncWriteExt, net::TcpListener};
+
+    use super::*;
+
+    #[test]
+    fn esplora_client_normalizes_base_url_once() {
+        let client = EsploraClient::new("http://localhost:3002///", 5);
+
+        assert_eq!(
+            client.url("/blocks/tip/height"),
+            "http://localhost:3002/blocks/tip/height"
+        );
+        assert_eq!(
+            client.url("/tx/abc/status"),
+            "http://localhost:3002/tx/abc/status"
+        );
+    }
+
+    #[tokio::test]
+    async fn error_statuses_count_as_failed_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind listener");
+        let address = listener.local_addr().expect("listener address");
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let _ = stream
+                    .write_all(
+                        b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 4\r\nconnection: close\r\n\r\nbusy",
+                    )
+                    .await;
+            }
+        });
+        let client = EsploraClient::new(&format!("http://{address}"), 5);
+
+        let status = client
+            .get_tip_height()
+            .await
+            .expect_err("error page is not a height")
+            .status();
+
+        assert_eq!(status, Some(reqwest::StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(client.error_count(), 1);
+    }
+}
@@ -0,0 +1,90 @@
+//! Freshness of status snapshots served while their monitor may be failing.
+
+use serde::Serialize;
+
+use super::time::unix_time_s;
+
+/// Number of refetch intervals without a successful poll after which a
+/// snapshot is reported as stale.
+const STALE_AFTER_INTERVALS: u64 = 2;
+
+/// A status snapshot with when it was served and last refreshed by its monitor.
+#[derive(Serialize, Clone, Debug)]
+pub struct FreshStatus<T> {
+    #[serde(flatten)]
+    status: T,
+    /// Unix timestamp in seconds at which the response was generated.
+    generated_at: u64,
+    /// Unix timestamp in seconds of the monitor's latest successful poll, if
+    /// it ever completed one.
+    last_successful_poll_at: Option<u64>,
+    /// Whether the monitor has not refreshed the snapshot for more than two
+    /// refetch intervals.
+    stale: bool,
+}
+
+impl<T> FreshStatus<T> {
+    /// Annotates `status` as served now.
+    pub fn new(status: T, last_successful_poll_at: Option<u64>, refetch_interval_s: u64) -> Self {
+        Self::at(
+            status,
+            last_successful_poll_at,
+            refetch_interval_s,
+            unix_time_s(),
+        )
+    }
+
+    fn at(
+        status: T,
+        last_successful_poll_at: Option<u64>,
+        refetch_interval_s: u64,
+        generated_at: u64,
+    ) -> Self {
+        let stale_after_s = refetch_interval_s.max(1) * STALE_AFTER_INTERVALS;
+        let stale = last_successful_poll_at
+            .is_none_or(|polled_at| generated_at.saturating_sub(polled_at) > stale_after_s);
+        Self {
+            status,
+            generated_at,
+            last_successful_poll_at,
+            stale,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.stale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_after_two_refetch_intervals_without_a_poll() {
+        assert!(!FreshStatus::at((), Some(1_000), 10, 1_020).is_stale());
+        assert!(FreshStatus::at((), Some(1_000), 10, 1_021).is_stale());
+        assert!(FreshStatus::at((), None, 10, 1_000).is_stale());
+    }
+
+    #[test]
+    fn serializes_metadata_next_to_the_status() {
+        #[derive(Serialize)]
+        struct Status {
+            online: bool,
+        }
+
+        let json = serde_json::to_value(FreshStatus::at(Status { online: true }, None, 10, 7))
+            .expect("serialize status");
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "online": true,
+                "generated_at": 7,
+                "last_successful_poll_at": null,
+                "stale": true,
+            })
+        );
+    }
+}
@@ -1,5 +1,22 @@
+mod admin;
+mod badge;
+mod component;
+pub mod db;
+mod esplora;
+mod freshness;
+mod prometheus;
 mod retry_policy;
 mod rpc_client;
+mod time;
+mod xml;
 
+pub use admin::{authorize_admin, require_admin};
+pub use badge::{badge_name, Badge, BadgeColor, SVG_CONTENT_TYPE};
+pub use component::{Component, ComponentHealth};
+pub use esplora::EsploraClient;
+pub use freshness::FreshStatus;
+pub use prometheus::{MetricKind, PrometheusEncoder, PROMETHEUS_CONTENT_TYPE};
 pub use retry_policy::ExponentialBackoff;
 pub use rpc_client::{create_rpc_client, execute_with_retries};
+pub use time::{rfc3339, rfc822, unix_time_ms, unix_time_s};
+pub use xml::escape_xml;
@@ -0,0 +1,121 @@
+//! Minimal encoder for the Prometheus text exposition format.
+
+use std::fmt::Write;
+
+/// Content type of the Prometheus text exposition format.
+pub const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Prometheus metric type of a family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricKind {
+    Gauge,
+    Counter,
+}
+
+impl MetricKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Gauge => "gauge",
+            Self::Counter => "counter",
+        }
+    }
+}
+
+/// Builds a Prometheus text exposition.
+///
+/// Each family is declared with [`family`](Self::family) before its samples
+/// are added with [`sample`](Self::sample).
+#[derive(Debug, Default)]
+pub struct PrometheusEncoder {
+    buf: String,
+}
+
+impl PrometheusEncoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes the `HELP` and `TYPE` lines of a metric family.
+    pub fn family(&mut self, name: &str, kind: MetricKind, help: &str) {
+        let help = help.replace('\\', r"\\").replace('\n', r"\n");
+        let _ = writeln!(self.buf, "# HELP {name} {help}");
+        let _ = writeln!(self.buf, "# TYPE {name} {}", kind.as_str());
+    }
+
+    /// Writes one sample of the family `name`.
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        self.buf.push_str(name);
+        if !labels.is_empty() {
+            self.buf.push('{');
+            for (i, (label, label_value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.buf.push(',');
+                }
+                let _ = write!(self.buf, "{label}=\"{}\"", escape_label_value(label_value));
+            }
+            self.buf.push('}');
+        }
+        let _ = writeln!(self.buf, " {}", format_value(value));
+    }
+
+    /// Returns the encoded exposition.
+    pub fn finish(self) -> String {
+        self.buf
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', r#"\""#)
+        .replace('\n', r"\n")
+}
+
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_owned()
+    } else if value.is_infinite() {
+        if value > 0.0 { "+Inf" } else { "-Inf" }.to_owned()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_families_and_samples() {
+        let mut encoder = PrometheusEncoder::new();
+        encoder.family("status_up", MetricKind::Gauge, "Whether the service is up.");
+        encoder.sample("status_up", &[("service", "rpc")], 1.0);
+        encoder.sample("status_up", &[("service", "bundler")], 0.0);
+        encoder.family("status_errors_total", MetricKind::Counter, "Errors.");
+        encoder.sample("status_errors_total", &[], 3.0);
+
+        assert_eq!(
+            encoder.finish(),
+            "# HELP status_up Whether the service is up.\n\
+             # TYPE status_up gauge\n\
+             status_up{service=\"rpc\"} 1\n\
+             status_up{service=\"bundler\"} 0\n\
+             # HELP status_errors_total Errors.\n\
+             # TYPE status_errors_total counter\n\
+             status_errors_total 3\n"
+        );
+    }
+
+    #[test]
+    fn escapes_label_values_and_special_floats() {
+        let mut encoder = PrometheusEncoder::new();
+        encoder.sample("m", &[("a", "x\"y\\z\nw"), ("b", "c")], f64::NAN);
+        encoder.sample("m", &[], f64::INFINITY);
+        encoder.sample("m", &[], 0.25);
+
+        assert_eq!(
+            encoder.finish(),
+            "m{a=\"x\\\"y\\\\z\\nw\",b=\"c\"} NaN\nm +Inf\nm 0.25\n"
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Current unix time and the timestamp formats of feeds and JSON APIs.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Current unix time in seconds.
+pub fn unix_time_s() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+/// Current unix time in milliseconds.
+pub fn unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// UTC calendar date and time of a unix timestamp.
+struct DateTime {
+    year: u64,
+    month: u64,
+    day: u64,
+    hour: u64,
+    minute: u64,
+    second: u64,
+    weekday: usize,
+}
+
+impl DateTime {
+    fn from_unix_s(unix_s: u64) -> Self {
+        let days = unix_s / SECONDS_PER_DAY;
+        let seconds = unix_s % SECONDS_PER_DAY;
+
+        // Civil-from-days conversion on the proleptic Gregorian calendar, with
+        // years starting in March so the leap day ends the year.
+        let z = days + 719_468;
+        let era = z / 146_097;
+        let day_of_era = z % 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: seconds / 3_600,
+            minute: seconds % 3_600 / 60,
+            second: seconds % 60,
+            // 1970-01-01 was a Thursday.
+            weekday: ((days + 4) % 7) as usize,
+        }
+    }
+}
+
+/// Formats `unix_s` as an RFC 3339 timestamp in UTC, e.g. `2024-02-29T12:34:56Z`.
+pub fn rfc3339(unix_s: u64) -> String {
+    let t = DateTime::from_unix_s(unix_s);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        t.year, t.month, t.day, t.hour, t.minute, t.second
+    )
+}
+
+/// Formats `unix_s` as an RFC 822 date, as RSS expects.
+pub fn rfc822(unix_s: u64) -> String {
+    let t = DateTime::from_unix_s(unix_s);
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+        WEEKDAYS[t.weekday],
+        t.day,
+        MONTHS[(t.month - 1) as usize],
+        t.year,
+        t.hour,
+        t.minute,
+        t.second
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_feed_timestamps() {
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc822(0), "Thu, 01 Jan 1970 00:00:00 GMT");
+        // Leap day.
+        assert_eq!(rfc3339(1_709_210_096), "2024-02-29T12:34:56Z");
+        assert_eq!(rfc822(1_709_210_096), "Thu, 29 Feb 2024 12:34:56 GMT");
+        assert_eq!(rfc3339(1_767_225_599), "2025-12-31T23:59:59Z");
+        assert_eq!(rfc822(1_767_225_599), "Wed, 31 Dec 2025 23:59:59 GMT");
+    }
+}
@@ -0,0 +1,44 @@
+import { useNetworkMetrics } from '../hooks/useNetworkMetrics';
+
+const GWEI = 1_000_000_000;
+
+const formatGwei = (wei: number | null) =>
+  wei === null ? '-' : `${(wei / GWEI).toFixed(3)} gwei`;
+
+const NetworkActivity = () => {
+  const { data, error } = useNetworkMetrics();
+
+  if (error || !data) {
+    return null;
+  }
+
+  const rows: [string, string][] = [
+    [
+      'Average block time',
+      data.avg_block_time_s === null
+        ? '-'
+        : `${data.avg_block_time_s.toFixed(2)} s`,
+    ],
+    ['Transactions per block', data.avg_txs_per_block.toFixed(2)],
+    ['Gas used', `${(data.avg_gas_used_ratio * 100).toFixed(1)}%`],
+    ['Base fee', formatGwei(data.latest_base_fee_wei)],
+  ];
+
+  return (
+    <div className="network-activity">
+      <div className="title">
+        NETWORK ACTIVITY (BLOCKS {data.from_block}-{data.to_block})
+      </div>
+      <div className="status-cards">
+        {rows.map(([label, value]) => (
+          <div key={label} className="status-section">
+            <div className="status-title">{label.toUpperCase()}</div>
+            <div className="status-value">{value}</div>
+          </div>
+        ))}
+      </div>
+    </div>
+  );
+};
+
+export default NetworkActivity;
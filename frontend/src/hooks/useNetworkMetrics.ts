@@ -0,0 +1,33 @@
+import { useQuery } from '@tanstack/react-query';
+import { useConfig } from './useConfig';
+
+export type NetworkMetrics = {
+  window_blocks: number;
+  from_block: number;
+  to_block: number;
+  avg_block_time_s: number | null;
+  avg_txs_per_block: number;
+  avg_gas_used_ratio: number;
+  latest_base_fee_wei: number | null;
+  avg_base_fee_wei: number | null;
+};
+
+const fetchNetworkMetrics = async (
+  baseUrl: string
+): Promise<NetworkMetrics> => {
+  const response = await fetch(`${baseUrl}/api/network/metrics`);
+  if (!response.ok) {
+    throw new Error('Failed to fetch network metrics');
+  }
+  return response.json();
+};
+
+export const useNetworkMetrics = () => {
+  const { apiBaseUrl, networkStatusRefetchIntervalS } = useConfig();
+
+  return useQuery({
+    queryKey: ['networkMetrics'],
+    queryFn: () => fetchNetworkMetrics(apiBaseUrl),
+    refetchInterval: networkStatusRefetchIntervalS * 1000, // convert to ms
+  });
+};
@@ -1,10 +1,55 @@
 import { useQuery } from '@tanstack/react-query';
 import { useConfig } from './useConfig';
 
+export type ServiceStatus = {
+  status: string;
+  degradation?: {
+    reason: string;
+    stalled_for_s?: number;
+  };
+  misconfiguration?: string;
+  latency: {
+    latest_ms: number | null;
+    p50_ms: number;
+    p95_ms: number;
+    p99_ms: number;
+  } | null;
+};
+
+export type L1Status = {
+  tip_height: number;
+  tip_hash: string;
+  tip_timestamp: number;
+  tip_age_s: number;
+  mempool_tx_count: number;
+  mempool_vsize: number;
+  no_new_block: boolean;
+  reader: {
+    sequencer_l1_height: number;
+    lag_blocks: number;
+    lagging: boolean;
+  } | null;
+};
+
+export type CheckpointStatus = {
+  status: string;
+  degradation?: {
+    reason: string;
+    stalled_for_s?: number;
+  };
+  latest_epoch: number;
+  landed: {
+    epoch: number;
+    l1_txid: string;
+    confirmations: number | null;
+    since_landed_s: number | null;
+  } | null;
+};
+
 export type NetworkStatus = {
-  sequencer: string;
-  rpc_endpoint: string;
-  bundler_endpoint: string;
+  services: Record<string, ServiceStatus>;
+  l1: L1Status | null;
+  checkpoint: CheckpointStatus | null;
 };
 
 const fetchNetworkStatus = async (baseUrl: string): Promise<NetworkStatus> => {
@@ -1,11 +1,56 @@
 import { lazy, Suspense, useState } from 'react';
 import { Link, useLocation } from 'react-router-dom';
-import { useNetworkStatus } from '../hooks/useNetworkStatus';
+import {
+  CheckpointStatus,
+  L1Status,
+  useNetworkStatus,
+} from '../hooks/useNetworkStatus';
 import '../styles/network.css';
 
 const StatusCard = lazy(() => import('../components/StatusCard'));
+const NetworkActivity = lazy(() => import('../components/NetworkActivity'));
 const Bridge = lazy(() => import('./Bridge'));
 
+const SERVICE_TITLES: Record<string, string> = {
+  sequencer: 'Sequencer status',
+  rpc_endpoint: 'RPC endpoint status',
+  bundler_endpoint: 'Bundler endpoint status',
+};
+
+const serviceTitle = (service: string) =>
+  SERVICE_TITLES[service] ?? `${service.replace(/_/g, ' ')} status`;
+
+const l1Title = (l1: L1Status | null) => {
+  if (!l1) {
+    return 'Bitcoin L1 status';
+  }
+  const tipAgeMin = Math.floor(l1.tip_age_s / 60);
+  const mempoolTxs = l1.mempool_tx_count;
+  const readerLag = l1.reader
+    ? `, sequencer ${l1.reader.lag_blocks} blocks behind`
+    : '';
+  return `Bitcoin L1 status (block ${l1.tip_height}, ${tipAgeMin} min ago, ${mempoolTxs} mempool txs${readerLag})`;
+};
+
+const l1Status = (l1: L1Status | null) => {
+  if (!l1) {
+    return 'OFFLINE';
+  }
+  return l1.no_new_block || l1.reader?.lagging ? 'DEGRADED' : 'ONLINE';
+};
+
+const checkpointTitle = (checkpoint: CheckpointStatus | null) => {
+  const landed = checkpoint?.landed;
+  if (!landed) {
+    return 'Checkpoint status';
+  }
+  const landedAgo =
+    landed.since_landed_s === null
+      ? 'unconfirmed'
+      : `${Math.floor(landed.since_landed_s / 60)} min ago`;
+  return `Checkpoint status (epoch ${landed.epoch}, ${landedAgo})`;
+};
+
 export default function Dashboard() {
   const [isMenuOpen, setMenuOpen] = useState(false);
   const toggleMenu = () => {
@@ -69,20 +114,28 @@ export default function Dashboard() {
                 <p className="loading-text">Loading...</p>
               ) : (
                 <div className="status-cards">
+                  {Object.entries(data?.services ?? {}).map(
+                    ([service, serviceStatus]) => (
+                      <StatusCard
+                        key={service}
+                        title={serviceTitle(service)}
+                        status={serviceStatus.status.toUpperCase()}
+                      />
+                    )
+                  )}
                   <StatusCard
-                    title="Sequencer status"
-                    status={data?.sequencer.toUpperCase() ?? 'Unknown'}
-                  />
-                  <StatusCard
-                    title="RPC endpoint status"
-                    status={data?.rpc_endpoint.toUpperCase() ?? 'Unknown'}
+                    title={l1Title(data?.l1 ?? null)}
+                    status={l1Status(data?.l1 ?? null)}
                   />
                   <StatusCard
-                    title="Bundler endpoint status"
-                    status={data?.bundler_endpoint.toUpperCase() ?? 'Unknown'}
+                    title={checkpointTitle(data?.checkpoint ?? null)}
+                    status={(
+                      data?.checkpoint?.status ?? 'offline'
+                    ).toUpperCase()}
                   />
                 </div>
               )}
+              <NetworkActivity />
             </Suspense>
           </div>
         )}